@@ -1,9 +1,10 @@
-import React, { useState } from 'react';
+import React, { useEffect, useRef, useState } from 'react';
 import { useQuery, useMutation, useQueryClient } from '@tanstack/react-query';
 import { invoke } from '@tauri-apps/api/tauri';
 import { open } from '@tauri-apps/api/dialog';
 import { downloadDir } from '@tauri-apps/api/path';
 import { save } from '@tauri-apps/api/dialog';
+import { listen } from '@tauri-apps/api/event';
 
 interface UploadedFile {
   id: number;
@@ -17,8 +18,21 @@ interface UploadedFile {
 interface ExportOptions {
   format: 'excel' | 'csv';
   fileIds: number[];
+  downloadId: string;
 }
 
+// Rust download_and_save_file 이 보내는 진행 이벤트
+interface DownloadProgress {
+  id: string;
+  received: number;
+  total: number | null;
+  done: boolean;
+  status: 'downloading' | 'completed' | 'cancelled' | 'failed';
+  error: string | null;
+}
+
+const formatBytes = (bytes: number) => `${(bytes / 1024).toFixed(0)} KB`;
+
 // API 호출 함수
 const fetchFiles = async (): Promise<UploadedFile[]> => {
   const res = await fetch('/api/files/files');
@@ -28,7 +42,7 @@ const fetchFiles = async (): Promise<UploadedFile[]> => {
   return res.json();
 };
 
-const exportFiles = async ({ format, fileIds }: ExportOptions): Promise<string> => {
+const exportFiles = async ({ format, fileIds, downloadId }: ExportOptions): Promise<string> => {
   try {
     // Tauri API를 통해 저장 대화상자 열기
     const savePath = await save({
@@ -43,17 +57,19 @@ const exportFiles = async ({ format, fileIds }: ExportOptions): Promise<string>
     // 서버 API 호출을 위한 URL 생성
     const endpoint = format === 'excel' ? '/api/files/export/excel' : '/api/files/export/csv';
     
-    // Tauri invoke로 파일 다운로드 및 저장 처리
-    // Rust 함수 호출 (아직 구현되지 않음)
-    const result = await invoke('download_and_save_file', {
+    // Tauri invoke로 파일 다운로드 및 저장 처리 (진행 상황은 download-progress 이벤트로 받음)
+    await invoke('download_and_save_file', {
       url: `${window.location.origin}${endpoint}`,
       savePath,
       fileIds,
-      method: 'POST'
+      method: 'POST',
+      id: downloadId
     });
     
     return savePath;
   } catch (error: any) {
+    // 사용자가 다운로드를 취소함
+    if (error?.code === 'CANCELLED') return '';
     console.error('파일 내보내기 오류:', error);
     throw new Error(`파일 내보내기 실패: ${error.message}`);
   }
@@ -64,9 +80,23 @@ export const FileList: React.FC = () => {
   const [exportFormat, setExportFormat] = useState<'excel' | 'csv'>('excel');
   const [error, setError] = useState<string | null>(null);
   const [exportSuccess, setExportSuccess] = useState<string | null>(null);
+  const [download, setDownload] = useState<DownloadProgress | null>(null);
+  const downloadId = useRef<string | null>(null);
   
   const queryClient = useQueryClient();
   
+  // 현재 내보내기의 다운로드 진행 이벤트만 반영
+  useEffect(() => {
+    const unlisten = listen<DownloadProgress>('download-progress', (event) => {
+      if (event.payload.id === downloadId.current) {
+        setDownload(event.payload.done ? null : event.payload);
+      }
+    });
+    return () => {
+      unlisten.then((stop) => stop());
+    };
+  }, []);
+  
   // 파일 목록 조회
   const { data: files, isLoading, isError } = useQuery({
     queryKey: ['files'],
@@ -76,6 +106,10 @@ export const FileList: React.FC = () => {
   // 파일 내보내기 뮤테이션
   const exportMutation = useMutation({
     mutationFn: exportFiles,
+    onSettled: () => {
+      downloadId.current = null;
+      setDownload(null);
+    },
     onSuccess: (data) => {
       if (data) {
         setExportSuccess(`파일이 성공적으로 저장되었습니다: ${data}`);
@@ -115,12 +149,25 @@ export const FileList: React.FC = () => {
       return;
     }
     
+    const id = crypto.randomUUID();
+    downloadId.current = id;
     exportMutation.mutate({
       format: exportFormat,
-      fileIds: selectedFiles
+      fileIds: selectedFiles,
+      downloadId: id
     });
   };
   
+  // 진행 중인 다운로드 취소
+  const handleCancelExport = async () => {
+    if (!download) return;
+    try {
+      await invoke('cancel_download', { id: download.id });
+    } catch (err: any) {
+      setError(`내보내기 취소 실패: ${err.message}`);
+    }
+  };
+  
   // 로컬 폴더 열기 - Tauri 네이티브 대화상자 사용
   const handleOpenFolder = async () => {
     try {
@@ -187,6 +234,15 @@ export const FileList: React.FC = () => {
           {exportMutation.isPending ? '내보내는 중...' : '내보내기'}
         </button>
         
+        {download && (
+          <button
+            onClick={handleCancelExport}
+            className="px-4 py-2 bg-red-600 text-white rounded hover:bg-red-700 flex items-center"
+          >
+            취소
+          </button>
+        )}
+        
         <button
           onClick={handleOpenFolder}
           className="px-4 py-2 bg-gray-600 text-white rounded hover:bg-gray-700 flex items-center"
@@ -195,6 +251,22 @@ export const FileList: React.FC = () => {
         </button>
       </div>
       
+      {download && (
+        <div className="mb-4">
+          <div className="w-full h-2 bg-gray-200 rounded dark:bg-gray-700 overflow-hidden">
+            <div
+              className={`h-2 bg-blue-600 ${download.total ? '' : 'animate-pulse w-full'}`}
+              style={download.total ? { width: `${Math.min(100, (download.received / download.total) * 100)}%` } : undefined}
+            />
+          </div>
+          <p className="mt-1 text-sm text-gray-500 dark:text-gray-300">
+            {download.total
+              ? `${formatBytes(download.received)} / ${formatBytes(download.total)}`
+              : `${formatBytes(download.received)} 받음`}
+          </p>
+        </div>
+      )}
+      
       <div className="overflow-x-auto">
         <table className="min-w-full divide-y divide-gray-200 dark:divide-gray-700">
           <thead className="bg-gray-50 dark:bg-gray-700">
@@ -118,7 +118,7 @@ export const TemplateEditor: React.FC<{
           name: "견적ID",
           sourceColumn: "A",  // Excel/CSV의 열 (A, B, C...) 또는 PDF의 정규식 패턴
           required: true,     // 필수 여부
-          dataType: "string"  // 데이터 타입 (string, number, date)
+          dataType: "string"  // 데이터 타입 (string, number, date, boolean, period)
         },
         {
           name: "솔루션",
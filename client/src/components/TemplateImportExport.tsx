@@ -126,9 +126,9 @@ export const TemplateImportExport: React.FC<TemplateImportExportProps> = ({
       
       if (selected && typeof selected === 'string') {
         // 선택한 파일 내용 읽기
-        const content = await invoke('read_file_content', {
+        const { content } = await invoke('read_file_content', {
           path: selected
-        }) as string;
+        }) as { content: string; encoding: string };
         
         // 템플릿 가져오기
         importMutation.mutate(content);
@@ -0,0 +1,361 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::{HashMap, HashSet};
+use tauri::{command, State};
+
+use crate::error::AppError;
+use crate::storage::quotations::Quotation;
+use crate::storage::Database;
+use crate::text_encoding::normalize_key;
+
+// 비교할 견적 하나 (견적번호 단위)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparedQuotation {
+    pub quotation_id: String,
+    pub vendor: Option<String>,
+    pub partner: Option<String>,
+    pub version: i64,
+    pub lines: usize,
+    pub consumer_price: Decimal,
+    pub contract_amount: Decimal,
+    // 계약금액 합계가 가장 낮은 견적 (제품 구성이 모두 같을 때만)
+    pub lowest: bool,
+}
+
+// 한 제품에 대한 견적 하나의 조건. 같은 제품이 여러 행이면 수량/금액을 합침
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonCell {
+    pub ids: Vec<i64>,
+    pub quantity: Option<i64>,
+    pub consumer_price: Option<Decimal>,
+    pub contract_amount: Option<Decimal>,
+    // 계약금액 / 수량 (수량이 없으면 계약금액)
+    pub unit_price: Option<Decimal>,
+    pub free_maintenance_period: Option<String>,
+    pub free_maintenance_months: Option<i64>,
+    // 단가가 가장 낮은 견적 (같으면 모두). 가격이 있는 견적이 둘 이상일 때만
+    pub lowest: bool,
+}
+
+impl ComparisonCell {
+    fn new(line: &Quotation) -> Self {
+        ComparisonCell {
+            ids: vec![line.id],
+            quantity: line.quantity,
+            consumer_price: line.consumer_price,
+            contract_amount: line.contract_amount,
+            unit_price: None,
+            free_maintenance_period: line.free_maintenance_period.clone(),
+            free_maintenance_months: line.free_maintenance_months,
+            lowest: false,
+        }
+    }
+
+    fn merge(&mut self, line: &Quotation) {
+        let sum = |a: Option<Decimal>, b: Option<Decimal>| match (a, b) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or_default() + b.unwrap_or_default()),
+        };
+        self.ids.push(line.id);
+        self.quantity = match (self.quantity, line.quantity) {
+            (None, None) => None,
+            (a, b) => Some(a.unwrap_or(0) + b.unwrap_or(0)),
+        };
+        self.consumer_price = sum(self.consumer_price, line.consumer_price);
+        self.contract_amount = sum(self.contract_amount, line.contract_amount);
+        if self.free_maintenance_period.is_none() {
+            self.free_maintenance_period = line.free_maintenance_period.clone();
+            self.free_maintenance_months = line.free_maintenance_months;
+        }
+    }
+
+    fn unit_price(&self) -> Option<Decimal> {
+        let amount = self.contract_amount?;
+        match self.quantity {
+            Some(quantity) if quantity > 0 => amount.checked_div(Decimal::from(quantity)),
+            _ => Some(amount),
+        }
+    }
+}
+
+// 제품 하나의 행. cells는 quotations와 같은 순서 (해당 제품이 없는 견적은 None)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ComparisonRow {
+    pub key: String,
+    // 처음 나온 주요제품 이름
+    pub product: String,
+    pub cells: Vec<Option<ComparisonCell>>,
+    pub lowest_unit_price: Option<Decimal>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Comparison {
+    pub solution: Option<String>,
+    pub quotations: Vec<ComparedQuotation>,
+    pub rows: Vec<ComparisonRow>,
+}
+
+// 품목 행들을 견적번호별로 나누고 주요제품으로 맞춰 정렬. quotation_ids 순서대로 열을 만듦
+pub fn compare(quotation_ids: &[String], lines: &[Quotation]) -> Result<Comparison, AppError> {
+    let mut ids: Vec<&String> = Vec::new();
+    for id in quotation_ids {
+        if !ids.contains(&id) {
+            ids.push(id);
+        }
+    }
+    if ids.len() < 2 {
+        return Err(AppError::invalid("비교할 견적을 두 개 이상 선택하세요."));
+    }
+    let present: HashSet<&str> = lines.iter().map(|line| line.quotation_id.as_str()).collect();
+    let missing: Vec<&str> = ids
+        .iter()
+        .filter(|id| !present.contains(id.as_str()))
+        .map(|id| id.as_str())
+        .collect();
+    if !missing.is_empty() {
+        return Err(AppError::invalid(format!("견적을 찾을 수 없습니다: {}", missing.join(", "))));
+    }
+
+    // 솔루션 키는 한 번만 계산 (처음 나온 표기를 이름으로 사용)
+    let mut solution_keys: HashSet<String> = HashSet::new();
+    let mut solutions: Vec<String> = Vec::new();
+    for line in lines {
+        if let Some(solution) = line.solution.as_deref().map(str::trim).filter(|s| !s.is_empty()) {
+            if solution_keys.insert(normalize_key(solution)) {
+                solutions.push(solution.to_string());
+            }
+        }
+    }
+    if solutions.len() > 1 {
+        return Err(AppError::invalid(format!(
+            "같은 솔루션의 견적만 비교할 수 있습니다: {}",
+            solutions.join(", ")
+        )));
+    }
+
+    let column: HashMap<&str, usize> = ids.iter().enumerate().map(|(i, id)| (id.as_str(), i)).collect();
+    let mut rows: Vec<ComparisonRow> = Vec::new();
+    let mut row_index: HashMap<String, usize> = HashMap::new();
+    let mut quotations: Vec<ComparedQuotation> = ids
+        .iter()
+        .map(|id| ComparedQuotation {
+            quotation_id: id.to_string(),
+            vendor: None,
+            partner: None,
+            version: 0,
+            lines: 0,
+            consumer_price: Decimal::ZERO,
+            contract_amount: Decimal::ZERO,
+            lowest: false,
+        })
+        .collect();
+
+    for line in lines {
+        let Some(&index) = column.get(line.quotation_id.as_str()) else {
+            continue;
+        };
+        let quotation = &mut quotations[index];
+        quotation.vendor = quotation.vendor.take().or_else(|| line.vendor.clone());
+        quotation.partner = quotation.partner.take().or_else(|| line.partner.clone());
+        quotation.version = quotation.version.max(line.version);
+        quotation.lines += 1;
+        quotation.consumer_price += line.consumer_price.unwrap_or_default();
+        quotation.contract_amount += line.contract_amount.unwrap_or_default();
+
+        let product = line
+            .main_product
+            .as_deref()
+            .map(str::trim)
+            .filter(|p| !p.is_empty())
+            .unwrap_or("(제품 미지정)");
+        let key = normalize_key(product);
+        let position = *row_index.entry(key.clone()).or_insert_with(|| {
+            rows.push(ComparisonRow {
+                key,
+                product: product.to_string(),
+                cells: vec![None; ids.len()],
+                lowest_unit_price: None,
+            });
+            rows.len() - 1
+        });
+        match &mut rows[position].cells[index] {
+            Some(cell) => cell.merge(line),
+            cell @ None => *cell = Some(ComparisonCell::new(line)),
+        }
+    }
+
+    for row in &mut rows {
+        for cell in row.cells.iter_mut().flatten() {
+            cell.unit_price = cell.unit_price();
+        }
+        let prices: Vec<Decimal> = row.cells.iter().flatten().filter_map(|c| c.unit_price).collect();
+        if prices.len() < 2 {
+            continue;
+        }
+        let lowest = prices.iter().copied().min();
+        row.lowest_unit_price = lowest;
+        for cell in row.cells.iter_mut().flatten() {
+            cell.lowest = cell.unit_price.is_some() && cell.unit_price == lowest;
+        }
+    }
+
+    // 모든 견적이 모든 제품을 포함할 때만 합계를 비교
+    let complete = rows.iter().all(|row| row.cells.iter().all(Option::is_some));
+    if let Some(lowest) = quotations.iter().map(|q| q.contract_amount).min().filter(|_| complete) {
+        for quotation in &mut quotations {
+            quotation.lowest = quotation.contract_amount == lowest;
+        }
+    }
+
+    Ok(Comparison {
+        solution: solutions.into_iter().next(),
+        quotations,
+        rows,
+    })
+}
+
+pub fn load_comparison(db: &Database, quotation_ids: &[String]) -> Result<Comparison, AppError> {
+    let lines = db.list_quotation_lines(quotation_ids)?;
+    compare(quotation_ids, &lines)
+}
+
+// 같은 솔루션의 여러 견적을 제품별로 나란히 비교 (UI용)
+#[command]
+pub fn compare_quotations(
+    db: State<'_, Database>,
+    quotation_ids: Vec<String>,
+) -> Result<Comparison, AppError> {
+    load_comparison(&db, &quotation_ids)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn line(id: i64, quotation_id: &str, product: &str, quantity: i64, contract: i64) -> Quotation {
+        serde_json::from_value(json!({
+            "id": id,
+            "quotationId": quotation_id,
+            "solution": "DB 보안",
+            "vendor": format!("{}사", quotation_id),
+            "mainProduct": product,
+            "quantity": quantity,
+            "consumerPrice": Decimal::from(contract * 2),
+            "contractAmount": Decimal::from(contract),
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    fn ids(ids: &[&str]) -> Vec<String> {
+        ids.iter().map(|id| id.to_string()).collect()
+    }
+
+    fn unit_prices(row: &ComparisonRow) -> Vec<Option<(i64, bool)>> {
+        row.cells
+            .iter()
+            .map(|cell| cell.as_ref().map(|c| (c.unit_price.unwrap().try_into().unwrap(), c.lowest)))
+            .collect()
+    }
+
+    #[test]
+    fn aligns_products_by_normalized_key() {
+        let lines = [
+            line(1, "A", "Oracle DB (Enterprise)", 2, 1_000),
+            line(2, "A", "ＳＱＬ　Ｓｅｒｖｅｒ", 1, 300),
+            // 공백/괄호/전각 표기만 다른 같은 제품
+            line(3, "B", "oracle  db enterprise", 4, 1_600),
+            line(4, "B", "SQL Server", 1, 400),
+        ];
+        let comparison = compare(&ids(&["A", "B"]), &lines).unwrap();
+        assert_eq!(comparison.solution.as_deref(), Some("DB 보안"));
+
+        let rows: Vec<(&str, &str)> = comparison
+            .rows
+            .iter()
+            .map(|row| (row.key.as_str(), row.product.as_str()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                ("oracledbenterprise", "Oracle DB (Enterprise)"),
+                ("sqlserver", "ＳＱＬ　Ｓｅｒｖｅｒ"),
+            ]
+        );
+        assert_eq!(unit_prices(&comparison.rows[0]), vec![Some((500, false)), Some((400, true))]);
+        assert_eq!(unit_prices(&comparison.rows[1]), vec![Some((300, true)), Some((400, false))]);
+        assert_eq!(comparison.rows[0].lowest_unit_price, Some(Decimal::from(400)));
+
+        // 모든 견적이 같은 구성이면 합계 최저가 표시
+        let lowest: Vec<(&str, bool)> = comparison
+            .quotations
+            .iter()
+            .map(|q| (q.quotation_id.as_str(), q.lowest))
+            .collect();
+        assert_eq!(lowest, vec![("A", true), ("B", false)]);
+    }
+
+    #[test]
+    fn product_quoted_by_one_vendor_has_empty_cells() {
+        let lines = [
+            line(1, "A", "Oracle DB", 1, 1_000),
+            line(2, "B", "Oracle DB", 1, 900),
+            line(3, "B", "기술지원", 1, 100),
+            line(4, "C", "Oracle DB", 1, 1_100),
+        ];
+        let comparison = compare(&ids(&["A", "B", "C"]), &lines).unwrap();
+        let support = &comparison.rows[1];
+        assert_eq!(support.product, "기술지원");
+        assert_eq!(unit_prices(support), vec![None, Some((100, false)), None]);
+        // 가격이 하나뿐이면 최저가를 표시하지 않음
+        assert_eq!(support.lowest_unit_price, None);
+        assert_eq!(
+            unit_prices(&comparison.rows[0]),
+            vec![Some((1_000, false)), Some((900, true)), Some((1_100, false))]
+        );
+
+        // 구성이 다르면 합계 최저가도 표시하지 않음
+        assert!(comparison.quotations.iter().all(|q| !q.lowest));
+        assert_eq!(comparison.quotations[1].contract_amount, Decimal::from(1_000));
+        assert_eq!(comparison.quotations[1].lines, 2);
+    }
+
+    #[test]
+    fn merges_duplicate_keys_within_a_quotation() {
+        let lines = [
+            line(1, "A", "Oracle DB", 2, 1_000),
+            line(2, "A", "oracle-db", 3, 2_000),
+            line(3, "B", "Oracle DB", 4, 2_400),
+        ];
+        let comparison = compare(&ids(&["A", "B", "A"]), &lines).unwrap();
+        assert_eq!(comparison.quotations.len(), 2);
+        assert_eq!(comparison.rows.len(), 1);
+
+        let cell = comparison.rows[0].cells[0].as_ref().unwrap();
+        assert_eq!(cell.ids, vec![1, 2]);
+        assert_eq!(cell.quantity, Some(5));
+        assert_eq!(cell.contract_amount, Some(Decimal::from(3_000)));
+        assert_eq!(cell.consumer_price, Some(Decimal::from(6_000)));
+        // 같은 단가는 모두 최저가
+        assert_eq!(unit_prices(&comparison.rows[0]), vec![Some((600, true)), Some((600, true))]);
+    }
+
+    #[test]
+    fn rejects_invalid_selection() {
+        let lines = [line(1, "A", "Oracle DB", 1, 1_000), line(2, "B", "Oracle DB", 1, 900)];
+        assert_eq!(compare(&ids(&["A", "A"]), &lines).unwrap_err().code(), "INVALID_INPUT");
+        let err = compare(&ids(&["A", "C"]), &lines).unwrap_err();
+        assert!(err.to_string().contains('C'), "{}", err);
+
+        let mut other = line(3, "C", "Oracle DB", 1, 800);
+        other.solution = Some("백업".into());
+        let lines = [lines[0].clone(), other];
+        let err = compare(&ids(&["A", "C"]), &lines).unwrap_err();
+        assert!(err.to_string().contains("백업"), "{}", err);
+    }
+}
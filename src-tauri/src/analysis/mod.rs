@@ -0,0 +1,4 @@
+pub mod comparison;
+pub mod savings;
+pub mod totals;
+pub mod trends;
@@ -0,0 +1,347 @@
+use rust_decimal::Decimal;
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use tauri::{command, State};
+
+use super::totals::{rate, GroupBy};
+use crate::error::AppError;
+use crate::storage::history::HistoryEntry;
+use crate::storage::quotations::{Quotation, QuotationFilter};
+use crate::storage::Database;
+
+// 기준 금액 대비 절감. amount = baseline - current, rate는 baseline 대비 %
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Saving {
+    pub baseline: Decimal,
+    pub current: Decimal,
+    pub amount: Decimal,
+    pub rate: Option<Decimal>,
+}
+
+impl Saving {
+    fn new(baseline: Decimal, current: Decimal) -> Self {
+        let amount = baseline - current;
+        Saving {
+            baseline,
+            current,
+            amount,
+            rate: rate(amount, baseline),
+        }
+    }
+}
+
+// 견적 품목 하나의 절감 내역
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct LineSavings {
+    pub id: i64,
+    pub quotation_id: String,
+    pub vendor: Option<String>,
+    pub category: Option<String>,
+    pub solution: Option<String>,
+    pub main_product: Option<String>,
+    pub version: i64,
+    // 비교 기준 차수 (이력이 남아 있는 가장 오래된 차수 / 직전 차수)
+    pub first_version: i64,
+    pub previous_version: Option<i64>,
+    // 최초 차수 계약금액 대비 (현재가 최초 차수이면 0)
+    pub vs_first: Option<Saving>,
+    // 직전 차수 계약금액 대비 (직전 차수가 없으면 None)
+    pub vs_previous: Option<Saving>,
+    // 소비자가격 대비 계약금액 할인
+    pub discount: Option<Saving>,
+}
+
+// 절감 합계. 기준 금액이 있는 품목만 더함
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavingSum {
+    pub count: usize,
+    pub baseline: Decimal,
+    pub current: Decimal,
+    pub amount: Decimal,
+    pub rate: Option<Decimal>,
+}
+
+impl SavingSum {
+    fn add(&mut self, saving: Option<&Saving>) {
+        let Some(saving) = saving else {
+            return;
+        };
+        self.count += 1;
+        self.baseline += saving.baseline;
+        self.current += saving.current;
+        self.amount += saving.amount;
+        self.rate = rate(self.amount, self.baseline);
+    }
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavingsTotals {
+    pub count: usize,
+    pub vs_first: SavingSum,
+    pub vs_previous: SavingSum,
+    pub discount: SavingSum,
+}
+
+impl SavingsTotals {
+    fn add(&mut self, line: &LineSavings) {
+        self.count += 1;
+        self.vs_first.add(line.vs_first.as_ref());
+        self.vs_previous.add(line.vs_previous.as_ref());
+        self.discount.add(line.discount.as_ref());
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupSavings {
+    // 값이 비어 있는 견적은 null 그룹
+    pub key: Option<String>,
+    #[serde(flatten)]
+    pub totals: SavingsTotals,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct SavingsReport {
+    pub lines: Vec<LineSavings>,
+    pub total: SavingsTotals,
+    pub by_vendor: Vec<GroupSavings>,
+    pub by_category: Vec<GroupSavings>,
+    pub by_solution: Vec<GroupSavings>,
+}
+
+// 현재 레코드와 이력(차수 오름차순)으로 품목의 절감 내역 계산
+pub fn line_savings(quotation: &Quotation, history: &[HistoryEntry]) -> LineSavings {
+    let earlier: Vec<&Quotation> = history
+        .iter()
+        .filter(|entry| entry.version < quotation.version)
+        .map(|entry| &entry.record)
+        .collect();
+    let first = earlier.first().copied().unwrap_or(quotation);
+    let previous = earlier.last().copied();
+
+    let versus = |baseline: &Quotation| match (baseline.contract_amount, quotation.contract_amount) {
+        (Some(baseline), Some(current)) => Some(Saving::new(baseline, current)),
+        _ => None,
+    };
+    // 합계/내보내기의 절감액(Quotation::savings)과 같은 계산
+    let discount = match (quotation.consumer_price, quotation.contract_amount) {
+        (Some(consumer), Some(contract)) => Some(Saving::new(consumer, contract)),
+        _ => None,
+    };
+
+    LineSavings {
+        id: quotation.id,
+        quotation_id: quotation.quotation_id.clone(),
+        vendor: quotation.vendor.clone(),
+        category: quotation.category.clone(),
+        solution: quotation.solution.clone(),
+        main_product: quotation.main_product.clone(),
+        version: quotation.version,
+        first_version: first.version,
+        previous_version: previous.map(|q| q.version),
+        vs_first: versus(first),
+        vs_previous: previous.and_then(versus),
+        discount,
+    }
+}
+
+fn group(quotations: &[Quotation], lines: &[LineSavings], group_by: GroupBy) -> Vec<GroupSavings> {
+    let mut groups: BTreeMap<Option<String>, SavingsTotals> = BTreeMap::new();
+    for (quotation, line) in quotations.iter().zip(lines) {
+        groups.entry(group_by.key(quotation)).or_default().add(line);
+    }
+    groups
+        .into_iter()
+        .map(|(key, totals)| GroupSavings { key, totals })
+        .collect()
+}
+
+pub fn compute(quotations: &[Quotation], history: &HashMap<i64, Vec<HistoryEntry>>) -> SavingsReport {
+    let lines: Vec<LineSavings> = quotations
+        .iter()
+        .map(|q| line_savings(q, history.get(&q.id).map(Vec::as_slice).unwrap_or_default()))
+        .collect();
+
+    let mut total = SavingsTotals::default();
+    for line in &lines {
+        total.add(line);
+    }
+
+    SavingsReport {
+        by_vendor: group(quotations, &lines, GroupBy::Vendor),
+        by_category: group(quotations, &lines, GroupBy::Category),
+        by_solution: group(quotations, &lines, GroupBy::Solution),
+        total,
+        lines,
+    }
+}
+
+#[command]
+pub fn quotation_savings(
+    db: State<'_, Database>,
+    filter: Option<QuotationFilter>,
+) -> Result<SavingsReport, AppError> {
+    let quotations = db.list_quotations(&filter.unwrap_or_default())?;
+    let history = db.quotation_history_map()?;
+    Ok(compute(&quotations, &history))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::totals::MoneyTotals;
+    use serde_json::json;
+
+    fn quotation(id: i64, consumer: Option<i64>, contract: Option<i64>, typed: Option<i64>) -> Quotation {
+        serde_json::from_value(json!({
+            "id": id,
+            "quotationId": format!("Q-{}", id),
+            "consumerPrice": consumer.map(Decimal::from),
+            "contractAmount": contract.map(Decimal::from),
+            "savingsAmount": typed.map(Decimal::from),
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn totals_use_computed_savings() {
+        let quotations = [
+            // 입력된 절감액이 계산값과 다르면 계산값을 사용
+            quotation(1, Some(1_000), Some(800), Some(500)),
+            quotation(2, Some(2_000), Some(1_500), None),
+            // 계산할 수 없는 견적은 입력값이 있어도 합계에서 제외
+            quotation(3, None, Some(700), Some(300)),
+            quotation(4, Some(900), None, None),
+        ];
+        assert_eq!(quotations[0].savings(), Some(Decimal::from(200)));
+        assert_eq!(quotations[2].savings(), None);
+
+        let mut totals = MoneyTotals::default();
+        for q in &quotations {
+            totals.add(q);
+        }
+        let report = compute(&quotations, &HashMap::new());
+
+        assert_eq!(totals.savings_amount, Decimal::from(700));
+        assert_eq!(totals.savings_amount, report.total.discount.amount);
+        assert_eq!(totals.savings_rate, report.total.discount.rate);
+    }
+
+    fn revision(version: i64, contract: Option<i64>) -> Quotation {
+        let mut q = quotation(1, Some(1_000), contract, None);
+        q.version = version;
+        q
+    }
+
+    fn history(revisions: &[Quotation]) -> Vec<HistoryEntry> {
+        revisions
+            .iter()
+            .map(|record| HistoryEntry {
+                id: record.version,
+                quotation_id: record.id,
+                version: record.version,
+                record: record.clone(),
+                changes: Vec::new(),
+                source_file: None,
+                restored_from: None,
+                created_at: None,
+            })
+            .collect()
+    }
+
+    fn amounts(saving: Option<&Saving>) -> Option<(i64, i64, i64)> {
+        saving.map(|s| {
+            (
+                s.baseline.try_into().unwrap(),
+                s.current.try_into().unwrap(),
+                s.amount.try_into().unwrap(),
+            )
+        })
+    }
+
+    #[test]
+    fn single_revision_compares_with_itself() {
+        let line = line_savings(&revision(1, Some(900)), &[]);
+        assert_eq!((line.first_version, line.previous_version), (1, None));
+        assert_eq!(amounts(line.vs_first.as_ref()), Some((900, 900, 0)));
+        assert_eq!(line.vs_first.unwrap().rate, Some(Decimal::ZERO));
+        assert!(line.vs_previous.is_none());
+        assert_eq!(amounts(line.discount.as_ref()), Some((1_000, 900, 100)));
+    }
+
+    #[test]
+    fn two_revisions_share_first_and_previous() {
+        let line = line_savings(&revision(2, Some(800)), &history(&[revision(1, Some(1_000))]));
+        assert_eq!((line.first_version, line.previous_version), (1, Some(1)));
+        assert_eq!(amounts(line.vs_first.as_ref()), Some((1_000, 800, 200)));
+        assert_eq!(amounts(line.vs_previous.as_ref()), Some((1_000, 800, 200)));
+        assert_eq!(line.vs_previous.unwrap().rate, Some(Decimal::from(20)));
+    }
+
+    #[test]
+    fn three_revisions_compare_with_first_and_previous() {
+        let entries = history(&[revision(1, Some(1_000)), revision(2, Some(800))]);
+        let line = line_savings(&revision(3, Some(600)), &entries);
+        assert_eq!((line.first_version, line.previous_version), (1, Some(2)));
+        assert_eq!(amounts(line.vs_first.as_ref()), Some((1_000, 600, 400)));
+        assert_eq!(amounts(line.vs_previous.as_ref()), Some((800, 600, 200)));
+        assert_eq!(line.vs_previous.unwrap().rate, Some(Decimal::from(25)));
+
+        // 복원 등으로 현재보다 뒤의 차수가 섞여 있어도 이전 차수만 기준으로 삼음
+        let line = line_savings(&revision(2, Some(800)), &entries);
+        assert_eq!((line.first_version, line.previous_version), (1, Some(1)));
+    }
+
+    #[test]
+    fn missing_previous_version_or_amount_has_no_saving() {
+        // 이력이 남기 전의 차수: 현재 차수가 2여도 비교할 이전 차수가 없음
+        let line = line_savings(&revision(2, Some(800)), &[]);
+        assert_eq!((line.first_version, line.previous_version), (2, None));
+        assert_eq!(amounts(line.vs_first.as_ref()), Some((800, 800, 0)));
+        assert!(line.vs_previous.is_none());
+
+        // 이전 차수에 계약금액이 없으면 그 차수 대비 절감은 계산하지 않음
+        let entries = history(&[revision(1, None), revision(2, Some(800))]);
+        let line = line_savings(&revision(3, Some(600)), &entries);
+        assert_eq!((line.first_version, line.previous_version), (1, Some(2)));
+        assert!(line.vs_first.is_none());
+        assert_eq!(amounts(line.vs_previous.as_ref()), Some((800, 600, 200)));
+
+        // 현재 계약금액이 없으면 어느 기준으로도 계산하지 않음
+        let line = line_savings(&revision(2, None), &history(&[revision(1, Some(1_000))]));
+        assert!(line.vs_first.is_none() && line.vs_previous.is_none() && line.discount.is_none());
+
+        let report = compute(&[revision(2, Some(800))], &HashMap::new());
+        assert_eq!((report.total.count, report.total.vs_previous.count), (1, 0));
+        assert_eq!(report.total.vs_first.count, 1);
+    }
+
+    #[test]
+    fn computes_from_stored_history() {
+        use crate::storage::quotations::NewQuotation;
+
+        let db = Database::open_in_memory().unwrap();
+        let input = |contract: i64| NewQuotation {
+            quotation_id: "Q-1".into(),
+            consumer_price: Some(Decimal::from(1_000)),
+            contract_amount: Some(Decimal::from(contract)),
+            ..Default::default()
+        };
+        let created = db.create_quotation(&input(1_000)).unwrap();
+        db.update_quotation(created.id, &input(800), None).unwrap();
+        db.update_quotation(created.id, &input(600), None).unwrap();
+
+        let quotations = db.list_quotations(&QuotationFilter::default()).unwrap();
+        let report = compute(&quotations, &db.quotation_history_map().unwrap());
+        let line = &report.lines[0];
+        assert_eq!((line.version, line.first_version, line.previous_version), (3, 1, Some(2)));
+        assert_eq!(report.total.vs_first.amount, Decimal::from(400));
+        assert_eq!(report.total.vs_previous.amount, Decimal::from(200));
+    }
+}
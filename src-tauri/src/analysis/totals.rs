@@ -0,0 +1,225 @@
+use rust_decimal::{Decimal, RoundingStrategy};
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::{command, State};
+
+use crate::error::AppError;
+use crate::storage::quotations::{Quotation, QuotationFilter};
+use crate::storage::Database;
+
+// 합계를 묶을 기준 컬럼
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum GroupBy {
+    Vendor,
+    Category,
+    Solution,
+    Partner,
+}
+
+impl GroupBy {
+    pub fn key(&self, quotation: &Quotation) -> Option<String> {
+        let value = match self {
+            GroupBy::Vendor => &quotation.vendor,
+            GroupBy::Category => &quotation.category,
+            GroupBy::Solution => &quotation.solution,
+            GroupBy::Partner => &quotation.partner,
+        };
+        value
+            .as_deref()
+            .map(str::trim)
+            .filter(|v| !v.is_empty())
+            .map(str::to_string)
+    }
+}
+
+// 금액 합계. 모든 금액은 decimal로 계산하고 문자열로 직렬화
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MoneyTotals {
+    pub count: usize,
+    pub quantity: i64,
+    pub consumer_price: Decimal,
+    pub contract_amount: Decimal,
+    pub savings_amount: Decimal,
+    // 소비자가격 대비 절감률 (%, 소수 둘째 자리 반올림). 절감액을 계산한 견적의 소비자가격 합계가 0이면 None
+    pub savings_rate: Option<Decimal>,
+    // 절감액을 계산할 수 있었던 견적의 소비자가격 합계 (절감률 기준)
+    #[serde(skip)]
+    savings_baseline: Decimal,
+}
+
+impl MoneyTotals {
+    pub fn add(&mut self, quotation: &Quotation) {
+        self.count += 1;
+        self.quantity += quotation.quantity.unwrap_or(0);
+        self.consumer_price += quotation.consumer_price.unwrap_or_default();
+        self.contract_amount += quotation.contract_amount.unwrap_or_default();
+        if let (Some(savings), Some(consumer)) = (quotation.savings(), quotation.consumer_price) {
+            self.savings_amount += savings;
+            self.savings_baseline += consumer;
+        }
+        self.savings_rate = rate(self.savings_amount, self.savings_baseline);
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct GroupTotals {
+    // 값이 비어 있는 견적은 null 그룹
+    pub key: Option<String>,
+    #[serde(flatten)]
+    pub totals: MoneyTotals,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TotalsReport {
+    pub total: MoneyTotals,
+    pub groups: Vec<GroupTotals>,
+}
+
+// part / whole * 100 (%), 엑셀 ROUND와 같은 사사오입
+pub fn rate(part: Decimal, whole: Decimal) -> Option<Decimal> {
+    if whole.is_zero() {
+        return None;
+    }
+    part.checked_div(whole).map(|r| {
+        (r * Decimal::ONE_HUNDRED)
+            .round_dp_with_strategy(2, RoundingStrategy::MidpointAwayFromZero)
+            .normalize()
+    })
+}
+
+pub fn summarize(quotations: &[Quotation], group_by: Option<GroupBy>) -> TotalsReport {
+    let mut report = TotalsReport::default();
+    let mut groups: BTreeMap<Option<String>, MoneyTotals> = BTreeMap::new();
+
+    for quotation in quotations {
+        report.total.add(quotation);
+        if let Some(group_by) = group_by {
+            groups
+                .entry(group_by.key(quotation))
+                .or_default()
+                .add(quotation);
+        }
+    }
+
+    report.groups = groups
+        .into_iter()
+        .map(|(key, totals)| GroupTotals { key, totals })
+        .collect();
+    report
+}
+
+#[command]
+pub fn quotation_totals(
+    db: State<'_, Database>,
+    filter: Option<QuotationFilter>,
+    group_by: Option<GroupBy>,
+) -> Result<TotalsReport, AppError> {
+    let quotations = db.list_quotations(&filter.unwrap_or_default())?;
+    Ok(summarize(&quotations, group_by))
+}
+
+// 분류에 속한 항목 중 선택한 항목만 합계 (quotation_ids가 없으면 분류 전체)
+#[command]
+pub fn category_totals(
+    db: State<'_, Database>,
+    category_id: i64,
+    quotation_ids: Option<Vec<i64>>,
+    include_descendants: Option<bool>,
+    group_by: Option<GroupBy>,
+) -> Result<TotalsReport, AppError> {
+    let quotations = db.select_category_items(
+        category_id,
+        quotation_ids.as_deref(),
+        include_descendants.unwrap_or(false),
+    )?;
+    Ok(summarize(&quotations, group_by))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+    use std::str::FromStr;
+
+    fn quotation(vendor: Option<&str>, consumer: Option<&str>, contract: Option<&str>) -> Quotation {
+        serde_json::from_value(json!({
+            "id": 1,
+            "quotationId": "Q-1",
+            "vendor": vendor,
+            "quantity": 1,
+            "consumerPrice": consumer,
+            "contractAmount": contract,
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    fn dec(text: &str) -> Decimal {
+        Decimal::from_str(text).unwrap()
+    }
+
+    #[test]
+    fn sums_exact_decimals() {
+        let quotations = [
+            quotation(Some("가나상사"), Some("0.3"), Some("0.1")),
+            quotation(Some("가나상사"), Some("0.3"), Some("0.2")),
+            quotation(Some("다라전자"), Some("9999999999999.99"), Some("0.01")),
+        ];
+        let report = summarize(&quotations, None);
+        assert_eq!(report.total.count, 3);
+        assert_eq!(report.total.quantity, 3);
+        assert_eq!(report.total.consumer_price, dec("10000000000000.59"));
+        assert_eq!(report.total.contract_amount, dec("0.31"));
+        assert_eq!(report.total.savings_amount, dec("10000000000000.28"));
+        assert!(report.groups.is_empty());
+
+        // 금액은 부동소수점이 아니라 정확한 문자열로 직렬화
+        let serialized = serde_json::to_value(&report.total).unwrap();
+        assert_eq!(serialized["contractAmount"], "0.31");
+        assert!(serialized.get("savingsBaseline").is_none());
+    }
+
+    #[test]
+    fn rate_rounds_half_away_from_zero_and_skips_zero_denominator() {
+        assert_eq!(rate(dec("1"), dec("3")), Some(dec("33.33")));
+        assert_eq!(rate(dec("2"), dec("3")), Some(dec("66.67")));
+        assert_eq!(rate(dec("1"), dec("8")), Some(dec("12.5")));
+        assert_eq!(rate(dec("0.00005"), dec("1")), Some(dec("0.01")));
+        assert_eq!(rate(dec("-1"), dec("3")), Some(dec("-33.33")));
+        assert_eq!(rate(dec("100"), dec("0")), None);
+        assert_eq!(rate(Decimal::ZERO, Decimal::ZERO), None);
+    }
+
+    #[test]
+    fn groups_totals_and_rates() {
+        let quotations = [
+            quotation(Some("가나상사"), Some("1000"), Some("800")),
+            quotation(Some(" 가나상사 "), Some("0"), Some("0")),
+            quotation(None, Some("500"), None),
+            quotation(Some("다라전자"), Some("0"), Some("0")),
+        ];
+        let report = summarize(&quotations, Some(GroupBy::Vendor));
+        let groups: Vec<(Option<&str>, usize, Option<Decimal>)> = report
+            .groups
+            .iter()
+            .map(|g| (g.key.as_deref(), g.totals.count, g.totals.savings_rate))
+            .collect();
+        assert_eq!(
+            groups,
+            vec![
+                // 값이 없는 견적은 null 그룹, 절감액을 계산할 수 없으니 절감률도 없음
+                (None, 1, None),
+                (Some("가나상사"), 2, Some(dec("20"))),
+                // 소비자가격 합계가 0이면 절감률 없음
+                (Some("다라전자"), 1, None),
+            ]
+        );
+        // 절감률 기준에서 계약금액이 없는 견적의 소비자가격은 제외
+        assert_eq!(report.total.consumer_price, dec("1500"));
+        assert_eq!(report.total.savings_rate, Some(dec("20")));
+    }
+}
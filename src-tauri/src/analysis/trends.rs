@@ -0,0 +1,363 @@
+use chrono::{Datelike, NaiveDate};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use tauri::{command, State};
+
+use crate::dates::{parse_date, DateOrder};
+use crate::error::AppError;
+use crate::storage::quotations::{Quotation, QuotationFilter};
+use crate::storage::Database;
+
+// 추이를 묶을 기간 단위
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Interval {
+    #[default]
+    Month,
+    Quarter,
+    Year,
+}
+
+// 기간 하나. index는 월(1~12), 분기(1~4), 연 단위이면 0
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+struct Bucket {
+    year: i32,
+    index: u32,
+}
+
+impl Bucket {
+    fn of(date: NaiveDate, interval: Interval) -> Self {
+        let index = match interval {
+            Interval::Month => date.month(),
+            Interval::Quarter => (date.month() - 1) / 3 + 1,
+            Interval::Year => 0,
+        };
+        Bucket {
+            year: date.year(),
+            index,
+        }
+    }
+
+    fn next(self, interval: Interval) -> Self {
+        let last = match interval {
+            Interval::Month => 12,
+            Interval::Quarter => 4,
+            Interval::Year => 0,
+        };
+        if self.index < last {
+            Bucket {
+                index: self.index + 1,
+                ..self
+            }
+        } else {
+            Bucket {
+                year: self.year + 1,
+                index: last.min(1),
+            }
+        }
+    }
+
+    fn key(&self, interval: Interval) -> String {
+        match interval {
+            Interval::Month => format!("{}-{:02}", self.year, self.index),
+            Interval::Quarter => format!("{}-Q{}", self.year, self.index),
+            Interval::Year => self.year.to_string(),
+        }
+    }
+
+    fn label(&self, interval: Interval) -> String {
+        match interval {
+            Interval::Month => format!("{}년 {}월", self.year, self.index),
+            Interval::Quarter => format!("{}년 {}분기", self.year, self.index),
+            Interval::Year => format!("{}년", self.year),
+        }
+    }
+}
+
+// 차트 한 점. 금액은 차트에서 바로 쓰도록 숫자로 직렬화
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendPoint {
+    // "2025-06", "2025-Q2", "2025"
+    pub period: String,
+    pub label: String,
+    pub count: usize,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub consumer_price: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub contract_amount: Decimal,
+    #[serde(with = "rust_decimal::serde::float")]
+    pub savings_amount: Decimal,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendSeries {
+    pub interval: Interval,
+    // 빈 기간은 0으로 채운 연속 구간
+    pub points: Vec<TrendPoint>,
+    // 견적일과 등록일을 모두 해석할 수 없어 제외한 견적 수
+    pub skipped: usize,
+}
+
+// 추이 조회 조건. 기간은 견적일(quoteDate), 없으면 등록일(createdAt) 기준, 양 끝 포함
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TrendQuery {
+    #[serde(default)]
+    pub interval: Interval,
+    #[serde(default)]
+    pub filter: QuotationFilter,
+    pub from: Option<String>,
+    pub to: Option<String>,
+}
+
+// 견적일(계약일)을 해석할 수 없으면 등록일. 등록일은 SQLite CURRENT_TIMESTAMP 형식 ("2025-06-26 09:30:00")
+fn quoted_on(quotation: &Quotation) -> Option<NaiveDate> {
+    [&quotation.quote_date, &quotation.created_at]
+        .into_iter()
+        .filter_map(|date| date.as_deref())
+        .find_map(|date| parse_date(date, DateOrder::Ymd).ok())
+}
+
+pub fn trend(
+    quotations: &[Quotation],
+    interval: Interval,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+) -> TrendSeries {
+    trend_until(quotations, interval, from, to, chrono::Local::now().date_naive())
+}
+
+// today는 시작일만 지정하고 데이터가 없을 때 구간의 끝
+fn trend_until(
+    quotations: &[Quotation],
+    interval: Interval,
+    from: Option<NaiveDate>,
+    to: Option<NaiveDate>,
+    today: NaiveDate,
+) -> TrendSeries {
+    let mut buckets: BTreeMap<Bucket, TrendPoint> = BTreeMap::new();
+    let mut skipped = 0;
+    let empty = |bucket: Bucket| TrendPoint {
+        period: bucket.key(interval),
+        label: bucket.label(interval),
+        count: 0,
+        consumer_price: Decimal::ZERO,
+        contract_amount: Decimal::ZERO,
+        savings_amount: Decimal::ZERO,
+    };
+
+    for quotation in quotations {
+        let Some(date) = quoted_on(quotation) else {
+            skipped += 1;
+            continue;
+        };
+        if from.is_some_and(|from| date < from) || to.is_some_and(|to| date > to) {
+            continue;
+        }
+        let bucket = Bucket::of(date, interval);
+        let point = buckets.entry(bucket).or_insert_with(|| empty(bucket));
+        point.count += 1;
+        point.consumer_price += quotation.consumer_price.unwrap_or_default();
+        point.contract_amount += quotation.contract_amount.unwrap_or_default();
+        point.savings_amount += quotation.savings().unwrap_or_default();
+    }
+
+    // 조회 기간이 지정되면 그 구간 전체, 아니면 데이터가 있는 첫 기간부터 마지막 기간까지.
+    // 시작일만 있으면 마지막 데이터 기간(없으면 오늘)까지, 종료일만 있고 데이터가 없으면 종료 기간 하나
+    let first = from
+        .map(|date| Bucket::of(date, interval))
+        .or_else(|| buckets.keys().next().copied())
+        .or_else(|| to.map(|date| Bucket::of(date, interval)));
+    let last = to
+        .map(|date| Bucket::of(date, interval))
+        .or_else(|| buckets.keys().next_back().copied())
+        .or_else(|| from.map(|_| Bucket::of(today, interval)));
+
+    let mut points = Vec::new();
+    if let (Some(mut bucket), Some(last)) = (first, last) {
+        while bucket <= last {
+            points.push(buckets.remove(&bucket).unwrap_or_else(|| empty(bucket)));
+            bucket = bucket.next(interval);
+        }
+    }
+
+    TrendSeries {
+        interval,
+        points,
+        skipped,
+    }
+}
+
+// 조회 기간 해석. 시작일이 종료일보다 늦으면 오류
+fn query_range(query: &TrendQuery) -> Result<(Option<NaiveDate>, Option<NaiveDate>), AppError> {
+    let parse = |text: &Option<String>| {
+        text.as_deref()
+            .map(|text| parse_date(text, DateOrder::Ymd))
+            .transpose()
+            .map_err(AppError::invalid)
+    };
+    let (from, to) = (parse(&query.from)?, parse(&query.to)?);
+    if let (Some(from), Some(to)) = (from, to) {
+        if from > to {
+            return Err(AppError::invalid("조회 시작일이 종료일보다 늦습니다."));
+        }
+    }
+    Ok((from, to))
+}
+
+#[command]
+pub fn quotation_trends(
+    db: State<'_, Database>,
+    query: Option<TrendQuery>,
+) -> Result<TrendSeries, AppError> {
+    let query = query.unwrap_or_default();
+    let (from, to) = query_range(&query)?;
+    let quotations = db.list_quotations(&query.filter)?;
+    Ok(trend(&quotations, query.interval, from, to))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn quotation(quote_date: Option<&str>, created_at: Option<&str>) -> Quotation {
+        serde_json::from_value(json!({
+            "id": 1,
+            "quotationId": "Q-1",
+            "contractAmount": "1000",
+            "quoteDate": quote_date,
+            "createdAt": created_at,
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    #[test]
+    fn buckets_by_quote_date_before_created_at() {
+        let quotations = [
+            // 견적일 기준
+            quotation(Some("2025.03.15"), Some("2025-06-26 09:30:00")),
+            // 견적일이 없거나 해석할 수 없으면 등록일
+            quotation(None, Some("2025-06-26 09:30:00")),
+            quotation(Some("미정"), Some("2025-05-02 10:00:00")),
+            quotation(None, None),
+        ];
+        let series = trend(&quotations, Interval::Quarter, None, None);
+
+        assert_eq!(series.skipped, 1);
+        let counts: Vec<(&str, usize)> = series.points.iter().map(|p| (p.period.as_str(), p.count)).collect();
+        assert_eq!(counts, [("2025-Q1", 1), ("2025-Q2", 2)]);
+    }
+
+    fn date(text: &str) -> NaiveDate {
+        NaiveDate::parse_from_str(text, "%Y-%m-%d").unwrap()
+    }
+
+    fn points(series: &TrendSeries) -> Vec<(&str, usize)> {
+        series.points.iter().map(|p| (p.period.as_str(), p.count)).collect()
+    }
+
+    #[test]
+    fn bucket_keys_labels_and_rollover() {
+        let cases = [
+            (Interval::Month, "2024-12-31", "2024-12", "2024년 12월", "2025-01"),
+            (Interval::Month, "2025-06-26", "2025-06", "2025년 6월", "2025-07"),
+            (Interval::Quarter, "2024-11-01", "2024-Q4", "2024년 4분기", "2025-Q1"),
+            (Interval::Quarter, "2025-04-01", "2025-Q2", "2025년 2분기", "2025-Q3"),
+            (Interval::Year, "2024-12-31", "2024", "2024년", "2025"),
+        ];
+        for (interval, day, key, label, next) in cases {
+            let bucket = Bucket::of(date(day), interval);
+            assert_eq!(bucket.key(interval), key);
+            assert_eq!(bucket.label(interval), label);
+            assert_eq!(bucket.next(interval).key(interval), next);
+        }
+    }
+
+    #[test]
+    fn fills_gaps_with_zero_for_each_interval() {
+        let quotations = [
+            quotation(Some("2024-11-05"), None),
+            quotation(Some("2025-02-10"), None),
+            quotation(Some("2025-02-20"), None),
+        ];
+        let cases: [(Interval, &[(&str, usize)]); 3] = [
+            (
+                Interval::Month,
+                &[("2024-11", 1), ("2024-12", 0), ("2025-01", 0), ("2025-02", 2)],
+            ),
+            (Interval::Quarter, &[("2024-Q4", 1), ("2025-Q1", 2)]),
+            (Interval::Year, &[("2024", 1), ("2025", 2)]),
+        ];
+        for (interval, expected) in cases {
+            let series = trend(&quotations, interval, None, None);
+            assert_eq!(points(&series), expected, "{:?}", interval);
+        }
+
+        let series = trend(&quotations, Interval::Month, None, None);
+        assert_eq!(series.points[1].contract_amount, Decimal::ZERO);
+        assert_eq!(series.points[3].contract_amount, Decimal::from(2_000));
+        assert!(trend(&[], Interval::Month, None, None).points.is_empty());
+    }
+
+    #[test]
+    fn clamps_to_query_range() {
+        let quotations = [
+            quotation(Some("2024-12-31"), None),
+            quotation(Some("2025-01-01"), None),
+            quotation(Some("2025-03-31"), None),
+            quotation(Some("2025-04-01"), None),
+        ];
+        // 범위 밖 견적은 제외하고, 범위는 데이터가 없는 기간까지 채움
+        let (from, to) = (Some(date("2025-01-01")), Some(date("2025-05-15")));
+        let series = trend(&quotations, Interval::Month, from, to);
+        assert_eq!(
+            points(&series),
+            [("2025-01", 1), ("2025-02", 0), ("2025-03", 1), ("2025-04", 1), ("2025-05", 0)]
+        );
+        let (from, to) = (Some(date("2024-10-01")), Some(date("2025-03-31")));
+        let series = trend(&quotations, Interval::Quarter, from, to);
+        assert_eq!(points(&series), [("2024-Q4", 1), ("2025-Q1", 2)]);
+
+        // 한쪽 끝만 있으면 다른 쪽은 데이터 기준
+        let series = trend(&quotations, Interval::Quarter, None, Some(date("2025-03-31")));
+        assert_eq!(points(&series), [("2024-Q4", 1), ("2025-Q1", 2)]);
+        let series = trend(&quotations, Interval::Year, Some(date("2023-06-01")), None);
+        assert_eq!(points(&series), [("2023", 0), ("2024", 1), ("2025", 3)]);
+    }
+
+    #[test]
+    fn open_range_without_data_is_zero_filled() {
+        let today = date("2025-02-14");
+        let series = trend_until(&[], Interval::Month, Some(date("2024-12-01")), None, today);
+        assert_eq!(points(&series), [("2024-12", 0), ("2025-01", 0), ("2025-02", 0)]);
+
+        let outside = [quotation(Some("2020-01-01"), None)];
+        let series = trend_until(&outside, Interval::Year, Some(date("2024-03-01")), None, today);
+        assert_eq!(points(&series), [("2024", 0), ("2025", 0)]);
+
+        let series = trend_until(&[], Interval::Quarter, None, Some(date("2025-05-01")), today);
+        assert_eq!(points(&series), [("2025-Q2", 0)]);
+    }
+
+    #[test]
+    fn rejects_reversed_or_invalid_range() {
+        let query = |from: Option<&str>, to: Option<&str>| TrendQuery {
+            from: from.map(str::to_string),
+            to: to.map(str::to_string),
+            ..Default::default()
+        };
+        let err = query_range(&query(Some("2025-06-01"), Some("2025-05-31"))).unwrap_err();
+        assert_eq!(err.code(), "INVALID_INPUT");
+        assert_eq!(err.message(), "조회 시작일이 종료일보다 늦습니다.");
+        assert_eq!(query_range(&query(Some("날짜 아님"), None)).unwrap_err().code(), "INVALID_INPUT");
+
+        let (from, to) = query_range(&query(Some("2025.06.01"), Some("2025-06-01"))).unwrap();
+        assert_eq!((from, to), (Some(date("2025-06-01")), Some(date("2025-06-01"))));
+        assert_eq!(query_range(&query(None, None)).unwrap(), (None, None));
+    }
+}
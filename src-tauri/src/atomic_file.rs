@@ -0,0 +1,180 @@
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use tempfile::{Builder, NamedTempFile};
+
+use crate::error::AppError;
+
+// 같은 디렉터리의 임시 파일에 쓰고 fsync 후 대상 파일로 이름을 바꿈.
+// 중간에 앱이 죽거나 디스크가 가득 차도 기존 파일은 그대로 남는다.
+// backups > 0 이면 기존 파일을 path.bak.1 ~ path.bak.N 으로 보관 (1이 가장 최근).
+pub fn write_atomic(path: &Path, contents: &[u8], backups: u32) -> Result<(), AppError> {
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir.to_path_buf(),
+        _ => PathBuf::from("."),
+    };
+
+    let mut temp = temp_file(&dir).map_err(|e| AppError::io(e, &dir))?;
+    temp.write_all(contents).map_err(|e| AppError::io(e, path))?;
+    temp.as_file().sync_all().map_err(|e| AppError::io(e, path))?;
+    // 기존 파일을 교체할 때는 권한을 그대로 유지
+    if let Ok(metadata) = fs::metadata(path) {
+        fs::set_permissions(temp.path(), metadata.permissions()).map_err(|e| AppError::io(e, path))?;
+    }
+
+    if backups > 0 && path.exists() {
+        rotate_backups(path, backups)?;
+    }
+
+    temp.persist(path).map_err(|e| AppError::io(e.error, path))?;
+    sync_dir(&dir);
+
+    Ok(())
+}
+
+// 임시 파일은 기본이 0600이라 그대로 옮기면 새 파일이 소유자 전용이 됨.
+// 일반 파일처럼 0666에서 umask를 뺀 권한으로 만든다 (유닉스 계열만 해당)
+fn temp_file(dir: &Path) -> io::Result<NamedTempFile> {
+    let mut builder = Builder::new();
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        builder.permissions(fs::Permissions::from_mode(0o666));
+    }
+    builder.tempfile_in(dir)
+}
+
+pub fn backup_path(path: &Path, index: u32) -> PathBuf {
+    let mut name = path.as_os_str().to_owned();
+    name.push(format!(".bak.{}", index));
+    PathBuf::from(name)
+}
+
+fn rotate_backups(path: &Path, backups: u32) -> Result<(), AppError> {
+    let oldest = backup_path(path, backups);
+    if oldest.exists() {
+        fs::remove_file(&oldest).map_err(|e| AppError::io(e, &oldest))?;
+    }
+
+    for index in (1..backups).rev() {
+        let from = backup_path(path, index);
+        if from.exists() {
+            fs::rename(&from, backup_path(path, index + 1)).map_err(|e| AppError::io(e, &from))?;
+        }
+    }
+
+    // 대상 파일은 교체 직전까지 남아 있어야 하므로 이동이 아닌 복사
+    fs::copy(path, backup_path(path, 1)).map_err(|e| AppError::io(e, path))?;
+    Ok(())
+}
+
+// 이름 변경 자체가 디스크에 기록되도록 디렉터리도 fsync (유닉스 계열만 가능)
+pub(crate) fn sync_dir(dir: &Path) {
+    #[cfg(unix)]
+    {
+        if let Ok(handle) = fs::File::open(dir) {
+            let _ = handle.sync_all();
+        }
+    }
+
+    #[cfg(not(unix))]
+    {
+        let _ = dir;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn read(path: &Path) -> String {
+        fs::read_to_string(path).unwrap()
+    }
+
+    // 대상 파일과 백업 외에 임시 파일이 남지 않았는지 확인
+    fn entries(dir: &Path) -> Vec<String> {
+        let mut names: Vec<String> = fs::read_dir(dir)
+            .unwrap()
+            .map(|entry| entry.unwrap().file_name().to_string_lossy().to_string())
+            .collect();
+        names.sort();
+        names
+    }
+
+    #[test]
+    fn creates_and_overwrites_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("template.json");
+
+        write_atomic(&path, b"first", 0).unwrap();
+        assert_eq!(read(&path), "first");
+        write_atomic(&path, b"second", 0).unwrap();
+        assert_eq!(read(&path), "second");
+        assert_eq!(entries(dir.path()), vec!["template.json"]);
+    }
+
+    #[test]
+    fn rotates_backups_newest_first() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("template.json");
+
+        for version in 1..=4 {
+            write_atomic(&path, format!("v{}", version).as_bytes(), 2).unwrap();
+        }
+        assert_eq!(read(&path), "v4");
+        assert_eq!(read(&backup_path(&path, 1)), "v3");
+        assert_eq!(read(&backup_path(&path, 2)), "v2");
+        assert_eq!(
+            entries(dir.path()),
+            vec!["template.json", "template.json.bak.1", "template.json.bak.2"]
+        );
+    }
+
+    #[test]
+    fn failed_write_keeps_original() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("template.json");
+        write_atomic(&path, b"original", 0).unwrap();
+
+        // 가장 오래된 백업 자리에 지울 수 없는 디렉터리를 두어 교체 직전에 실패시킴
+        let blocker = backup_path(&path, 2);
+        fs::create_dir(&blocker).unwrap();
+        fs::write(blocker.join("keep"), b"").unwrap();
+
+        let err = write_atomic(&path, b"replacement", 2).unwrap_err();
+        assert_eq!(err.context().path.as_deref(), Some(blocker.to_string_lossy().as_ref()));
+        assert_eq!(read(&path), "original");
+        assert_eq!(entries(dir.path()), vec!["template.json", "template.json.bak.2"]);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn keeps_permissions_of_replaced_file() {
+        use std::os::unix::fs::PermissionsExt;
+
+        let mode = |path: &Path| fs::metadata(path).unwrap().permissions().mode() & 0o777;
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("report.xlsx");
+
+        // 새 파일은 fs::write로 만든 파일과 같은 (umask 적용) 권한
+        let plain = dir.path().join("plain");
+        fs::write(&plain, b"").unwrap();
+        write_atomic(&path, b"first", 0).unwrap();
+        assert_eq!(mode(&path), mode(&plain));
+
+        fs::set_permissions(&path, fs::Permissions::from_mode(0o640)).unwrap();
+        write_atomic(&path, b"second", 1).unwrap();
+        assert_eq!(read(&path), "second");
+        assert_eq!(mode(&path), 0o640);
+    }
+
+    #[test]
+    fn missing_directory_is_reported() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("missing").join("template.json");
+
+        let err = write_atomic(&path, b"data", 0).unwrap_err();
+        assert_eq!(err.code(), "FILE_NOT_FOUND");
+        assert!(!path.exists());
+    }
+}
@@ -1,64 +1,246 @@
-use tauri::{command, Window};
+use tauri::{command, Emitter, Runtime, State, Window};
+use crate::atomic_file::{sync_dir, write_atomic};
+use crate::error::AppError;
+use crate::text_encoding::{decode_bytes, DecodedText};
+use futures_util::StreamExt;
 use reqwest::Client;
-use serde::Deserialize;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::fs::File;
-use std::io::{Read, Write};
+use std::io::{BufWriter, Read, Write};
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
 use std::path::Path;
-use std::fs;
 use std::process::Command;
+use tempfile::NamedTempFile;
+use tokio::sync::Notify;
 
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Serialize, Deserialize)]
 struct RequestData {
     file_ids: Vec<i32>,
 }
 
+// 진행 중인 다운로드의 취소 신호 (다운로드 ID -> 신호)
+#[derive(Default)]
+pub struct DownloadRegistry {
+    downloads: Mutex<HashMap<String, Arc<Notify>>>,
+    next_id: AtomicU64,
+}
+
+impl DownloadRegistry {
+    // ID를 지정하지 않으면 겹치지 않는 ID를 새로 만듦. 이미 진행 중인 ID이면 오류
+    fn register(&self, id: Option<String>) -> Result<(String, Arc<Notify>), AppError> {
+        let mut downloads = self.downloads.lock().unwrap();
+        let id = match id {
+            Some(id) if downloads.contains_key(&id) => {
+                return Err(AppError::invalid(format!("이미 진행 중인 다운로드입니다: {}", id)));
+            }
+            Some(id) => id,
+            None => loop {
+                let id = format!("download-{}", self.next_id.fetch_add(1, Ordering::SeqCst) + 1);
+                if !downloads.contains_key(&id) {
+                    break id;
+                }
+            },
+        };
+        let cancel = Arc::new(Notify::new());
+        downloads.insert(id.clone(), cancel.clone());
+        Ok((id, cancel))
+    }
+
+    fn remove(&self, id: &str) {
+        self.downloads.lock().unwrap().remove(id);
+    }
+
+    // notify_one은 대기 중이 아니어도 신호를 남겨 두므로 다음 대기에서 바로 취소됨
+    fn cancel(&self, id: &str) -> bool {
+        match self.downloads.lock().unwrap().get(id) {
+            Some(cancel) => {
+                cancel.notify_one();
+                true
+            }
+            None => false,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+enum DownloadStatus {
+    Downloading,
+    Completed,
+    Cancelled,
+    Failed,
+}
+
+// 창으로 보내는 다운로드 진행 이벤트. 끝나면(완료/취소/실패) done = true 인 이벤트를 한 번 더 보냄
+#[derive(Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+struct DownloadProgress {
+    id: String,
+    received: u64,
+    total: Option<u64>,
+    done: bool,
+    status: DownloadStatus,
+    error: Option<String>,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DownloadResult {
+    pub id: String,
+    pub path: String,
+}
+
+const DOWNLOAD_PROGRESS_EVENT: &str = "download-progress";
+// 진행 이벤트 최소 간격
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
 #[command]
-pub async fn download_and_save_file(
+pub async fn download_and_save_file<R: Runtime>(
+    window: Window<R>,
+    registry: State<'_, DownloadRegistry>,
     url: String,
     save_path: String,
     file_ids: Vec<i32>,
     method: String,
-) -> Result<String, String> {
+    id: Option<String>,
+) -> Result<DownloadResult, AppError> {
+    let (id, cancel) = registry.register(id)?;
+    let mut progress = DownloadProgress {
+        id: id.clone(),
+        received: 0,
+        total: None,
+        done: false,
+        status: DownloadStatus::Downloading,
+        error: None,
+    };
+    // 응답이 오기 전에도 취소 버튼을 보여 줄 수 있도록 시작 이벤트를 보냄
+    let _ = window.emit(DOWNLOAD_PROGRESS_EVENT, progress.clone());
+
+    let result = stream_to_file(&window, &cancel, url, &save_path, file_ids, method, &mut progress).await;
+    registry.remove(&id);
+
+    // 진행 표시줄을 닫을 수 있도록 결과와 상관없이 마지막 이벤트를 보냄
+    progress.done = true;
+    progress.status = match &result {
+        Ok(()) => DownloadStatus::Completed,
+        Err(AppError::Cancelled) => DownloadStatus::Cancelled,
+        Err(_) => DownloadStatus::Failed,
+    };
+    progress.error = result.as_ref().err().map(|e| e.message());
+    let _ = window.emit(DOWNLOAD_PROGRESS_EVENT, progress);
+
+    result.map(|_| DownloadResult { id, path: save_path })
+}
+
+async fn stream_to_file<R: Runtime>(
+    window: &Window<R>,
+    cancel: &Notify,
+    url: String,
+    save_path: &str,
+    file_ids: Vec<i32>,
+    method: String,
+    progress: &mut DownloadProgress,
+) -> Result<(), AppError> {
     // HTTP 클라이언트 생성
     let client = Client::new();
-    
-    let mut request_builder = match method.to_uppercase().as_str() {
+
+    let request_builder = match method.to_uppercase().as_str() {
         "POST" => {
             let request_data = RequestData { file_ids };
             client.post(&url).json(&request_data)
         },
         _ => client.get(&url),
     };
-    
-    // 요청 보내기
-    let response = request_builder
-        .send()
-        .await
-        .map_err(|e| e.to_string())?;
-    
+
+    // 요청 보내기. 서버가 응답하지 않아도 취소할 수 있도록 취소 신호와 함께 기다림
+    let response = tokio::select! {
+        biased;
+        _ = cancel.notified() => return Err(AppError::Cancelled),
+        response = request_builder.send() => response.map_err(|e| AppError::request(e, &url))?,
+    };
+
     // 응답 확인
     if !response.status().is_success() {
-        return Err(format!("서버 응답 오류: {}", response.status()));
+        return Err(AppError::http(url, response.status()));
     }
-    
-    // 응답 본문 (파일) 다운로드
-    let bytes = response.bytes().await.map_err(|e| e.to_string())?;
-    
-    // 파일 저장
-    let mut file = File::create(&save_path).map_err(|e| e.to_string())?;
-    file.write_all(&bytes).map_err(|e| e.to_string())?;
-    
-    Ok(save_path)
+
+    progress.total = response.content_length();
+
+    // 완료 전까지는 같은 디렉터리의 임시 파일(.part)에 기록하고 마지막에 이름을 바꿈.
+    // 다운로드마다 이름이 달라서 이전 실행이 남긴 .part나 같은 경로로 받는 다른 다운로드를 덮어쓰지 않는다.
+    let part = part_file(save_path)?;
+    let part_path = part.path().to_path_buf();
+    let mut file = BufWriter::new(part);
+
+    let mut last_emit = Instant::now();
+    let mut stream = response.bytes_stream();
+
+    loop {
+        // 다운로드가 멈춰 다음 조각이 오지 않아도 취소되도록 취소 신호와 경쟁
+        let next = tokio::select! {
+            biased;
+            _ = cancel.notified() => Err(AppError::Cancelled),
+            chunk = stream.next() => Ok(chunk),
+        };
+        // 오류로 반환하면 임시 파일은 drop 되면서 지워짐
+        let chunk = match next {
+            Ok(None) => break,
+            Ok(Some(Ok(chunk))) => chunk,
+            Ok(Some(Err(e))) => return Err(AppError::request(e, &url)),
+            Err(e) => return Err(e),
+        };
+        file.write_all(&chunk).map_err(|e| AppError::io(e, &part_path))?;
+
+        progress.received += chunk.len() as u64;
+        if last_emit.elapsed() >= PROGRESS_INTERVAL {
+            let _ = window.emit(DOWNLOAD_PROGRESS_EVENT, progress.clone());
+            last_emit = Instant::now();
+        }
+    }
+
+    // write_atomic과 같이 내용을 디스크에 기록(fsync)한 뒤에 이름을 바꿈
+    let part = file.into_inner().map_err(|e| AppError::io(e.into_error(), &part_path))?;
+    part.as_file().sync_all().map_err(|e| AppError::io(e, &part_path))?;
+    part.persist(save_path).map_err(|e| AppError::io(e.error, save_path))?;
+    if let Some(dir) = part_path.parent() {
+        sync_dir(dir);
+    }
+
+    Ok(())
+}
+
+// 저장 경로와 같은 디렉터리에 "<파일 이름>.<임의 문자열>.part" 임시 파일을 만듦
+fn part_file(save_path: &str) -> Result<NamedTempFile, AppError> {
+    let path = Path::new(save_path);
+    let dir = match path.parent() {
+        Some(dir) if !dir.as_os_str().is_empty() => dir,
+        _ => Path::new("."),
+    };
+    let mut prefix = path.file_name().unwrap_or_default().to_owned();
+    prefix.push(".");
+    tempfile::Builder::new()
+        .prefix(&prefix)
+        .suffix(".part")
+        .tempfile_in(dir)
+        .map_err(|e| AppError::io(e, dir))
 }
 
 #[command]
-pub fn open_folder(path: String) -> Result<(), String> {
+pub fn cancel_download(registry: State<'_, DownloadRegistry>, id: String) -> bool {
+    registry.cancel(&id)
+}
+
+#[command]
+pub fn open_folder(path: String) -> Result<(), AppError> {
     #[cfg(target_os = "windows")]
     {
         Command::new("explorer")
             .args([&path])
             .spawn()
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| AppError::io(e, &path))?;
     }
     
     #[cfg(target_os = "macos")]
@@ -66,7 +248,7 @@ pub fn open_folder(path: String) -> Result<(), String> {
         Command::new("open")
             .args([&path])
             .spawn()
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| AppError::io(e, &path))?;
     }
     
     #[cfg(target_os = "linux")]
@@ -74,7 +256,7 @@ pub fn open_folder(path: String) -> Result<(), String> {
         Command::new("xdg-open")
             .args([&path])
             .spawn()
-            .map_err(|e| e.to_string())?;
+            .map_err(|e| AppError::io(e, &path))?;
     }
     
     Ok(())
@@ -86,16 +268,85 @@ pub fn get_version() -> String {
 }
 
 #[command]
-pub fn save_file_content(path: String, content: String) -> Result<(), String> {
-    let mut file = File::create(&path).map_err(|e| e.to_string())?;
-    file.write_all(content.as_bytes()).map_err(|e| e.to_string())?;
-    Ok(())
+pub fn save_file_content(path: String, content: String, backups: Option<u32>) -> Result<(), AppError> {
+    // 임시 파일 + 이름 변경으로 저장해서 저장 도중 실패해도 기존 파일을 보존
+    write_atomic(Path::new(&path), content.as_bytes(), backups.unwrap_or(0))
 }
 
 #[command]
-pub fn read_file_content(path: String) -> Result<String, String> {
-    let mut file = File::open(&path).map_err(|e| e.to_string())?;
-    let mut content = String::new();
-    file.read_to_string(&mut content).map_err(|e| e.to_string())?;
-    Ok(content)
+pub fn read_file_content(path: String, encoding: Option<String>) -> Result<DecodedText, AppError> {
+    let mut file = File::open(&path).map_err(|e| AppError::io(e, &path))?;
+    let mut bytes = Vec::new();
+    file.read_to_end(&mut bytes).map_err(|e| AppError::io(e, &path))?;
+
+    // CP949/EUC-KR 등 레거시 인코딩 파일도 읽을 수 있도록 인코딩 감지 후 디코딩
+    decode_bytes(&bytes, encoding.as_deref()).map_err(AppError::invalid)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use futures_util::FutureExt;
+
+    #[test]
+    fn registry_generates_unique_ids() {
+        let registry = DownloadRegistry::default();
+        let (first, _) = registry.register(None).unwrap();
+        let (second, _) = registry.register(None).unwrap();
+        assert_ne!(first, second);
+
+        // 사용자가 지정한 ID가 자동 ID와 겹쳐도 건너뜀
+        let (custom, _) = registry.register(Some("download-3".into())).unwrap();
+        let (third, _) = registry.register(None).unwrap();
+        assert_eq!(custom, "download-3");
+        assert_eq!(third, "download-4");
+    }
+
+    #[test]
+    fn registry_rejects_duplicate_id() {
+        let registry = DownloadRegistry::default();
+        registry.register(Some("report".into())).unwrap();
+        let err = registry.register(Some("report".into())).unwrap_err();
+        assert_eq!(err.code(), "INVALID_INPUT");
+
+        // 끝난 다운로드의 ID는 다시 쓸 수 있음
+        registry.remove("report");
+        assert!(registry.register(Some("report".into())).is_ok());
+    }
+
+    #[test]
+    fn cancel_before_wait_is_kept() {
+        let registry = DownloadRegistry::default();
+        let (id, cancel) = registry.register(None).unwrap();
+
+        assert!(registry.cancel(&id));
+        assert_eq!(cancel.notified().now_or_never(), Some(()));
+        assert_eq!(cancel.notified().now_or_never(), None);
+
+        registry.remove(&id);
+        assert!(!registry.cancel(&id));
+    }
+
+    #[test]
+    fn part_file_is_unique_and_discarded_on_drop() {
+        let dir = tempfile::tempdir().unwrap();
+        let save_path = dir.path().join("quote.xlsx");
+        let save_path = save_path.to_str().unwrap();
+        // 이전 실행이 남긴 .part는 건드리지 않음
+        let stale = dir.path().join("quote.xlsx.part");
+        std::fs::write(&stale, b"stale").unwrap();
+
+        let first = part_file(save_path).unwrap();
+        let second = part_file(save_path).unwrap();
+        assert_ne!(first.path(), second.path());
+        assert_eq!(first.path().parent(), Some(dir.path()));
+        let name = first.path().file_name().unwrap().to_string_lossy().to_string();
+        assert!(name.starts_with("quote.xlsx.") && name.ends_with(".part"));
+
+        let (first_path, second_path) = (first.path().to_path_buf(), second.path().to_path_buf());
+        drop(first);
+        drop(second);
+        assert!(!first_path.exists() && !second_path.exists());
+        assert_eq!(std::fs::read(&stale).unwrap(), b"stale");
+    }
 }
@@ -0,0 +1,299 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+use std::sync::OnceLock;
+
+use crate::text_encoding::to_half_width;
+
+// 일/월 순서가 모호한 날짜(예: 03/04/2025, 25/06/26)를 해석할 기본 순서
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DateOrder {
+    // 연-월-일 (한국식)
+    #[default]
+    #[serde(alias = "ko", alias = "ko-KR")]
+    Ymd,
+    // 일-월-연 (유럽식)
+    #[serde(alias = "en-GB")]
+    Dmy,
+    // 월-일-연 (미국식)
+    #[serde(alias = "en-US")]
+    Mdy,
+}
+
+// 월 단위 기간. 예) "계약일로부터 12개월" -> { months: 12, from: "계약일" }
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Period {
+    pub months: u32,
+    // 기산일 (예: "계약일", "검수일")
+    pub from: Option<String>,
+}
+
+fn regex(cell: &'static OnceLock<Regex>, pattern: &str) -> &'static Regex {
+    cell.get_or_init(|| Regex::new(pattern).expect("valid date regex"))
+}
+
+// 날짜 뒤에 붙는 요일/시각은 무시
+fn is_trailer(rest: &str) -> bool {
+    static TRAILER: OnceLock<Regex> = OnceLock::new();
+    regex(
+        &TRAILER,
+        r"^\s*(\(?[월화수목금토일](요일)?\)?)?\s*([T ]?\d{1,2}:\d{2}(:\d{2}(\.\d+)?)?)?\s*$",
+    )
+    .is_match(rest)
+}
+
+// 견적서의 날짜 표기를 해석.
+// 예) "2025.06.26", "2025. 6. 26.", "2025년 6월 26일", "25/06/26", "20250626", "45834"
+pub fn parse_date(text: &str, order: DateOrder) -> Result<NaiveDate, String> {
+    static KOREAN: OnceLock<Regex> = OnceLock::new();
+    static SEPARATED: OnceLock<Regex> = OnceLock::new();
+
+    let normalized = to_half_width(text.trim());
+    let invalid = || format!("날짜로 변환할 수 없습니다: {}", text.trim());
+
+    let korean = regex(&KOREAN, r"^(\d{2,4})\s*년\s*(\d{1,2})\s*월\s*(\d{1,2})\s*일?");
+    if let Some(captures) = korean.captures(&normalized) {
+        if !is_trailer(&normalized[captures[0].len()..]) {
+            return Err(invalid());
+        }
+        return ymd(&captures[1], &captures[2], &captures[3]).ok_or_else(invalid);
+    }
+
+    let separated = regex(
+        &SEPARATED,
+        r"^(\d{1,4})\s*[./-]\s*(\d{1,4})\s*[./-]\s*(\d{1,4})\.?",
+    );
+    if let Some(captures) = separated.captures(&normalized) {
+        if !is_trailer(&normalized[captures[0].len()..]) {
+            return Err(invalid());
+        }
+        return resolve(&captures[1], &captures[2], &captures[3], order, text.trim());
+    }
+
+    if normalized.chars().all(|c| c.is_ascii_digit()) {
+        // 20250626
+        if normalized.len() == 8 {
+            return ymd(&normalized[..4], &normalized[4..6], &normalized[6..]).ok_or_else(invalid);
+        }
+        // 문자열로 저장된 엑셀 날짜 일련번호
+        if normalized.len() <= 5 {
+            let serial: f64 = normalized.parse().map_err(|_| invalid())?;
+            return excel_serial_to_date(serial).ok_or_else(invalid);
+        }
+    }
+
+    Err(invalid())
+}
+
+fn number(text: &str) -> Option<u32> {
+    text.parse().ok()
+}
+
+// 두 자리 연도는 70 미만이면 2000년대
+fn year(text: &str) -> Option<i32> {
+    let value: i32 = text.parse().ok()?;
+    match text.len() {
+        4 => Some(value),
+        1 | 2 if value < 70 => Some(2000 + value),
+        1 | 2 => Some(1900 + value),
+        _ => None,
+    }
+}
+
+fn ymd(y: &str, m: &str, d: &str) -> Option<NaiveDate> {
+    NaiveDate::from_ymd_opt(year(y)?, number(m)?, number(d)?)
+}
+
+// 구분 기호로 나뉜 세 숫자를 순서 규칙에 따라 날짜로 해석
+fn resolve(a: &str, b: &str, c: &str, order: DateOrder, text: &str) -> Result<NaiveDate, String> {
+    // 연도가 네 자리이면 위치로 순서를 알 수 있음
+    if a.len() == 4 {
+        return ymd(a, b, c).ok_or_else(|| format!("날짜로 변환할 수 없습니다: {}", text));
+    }
+
+    let candidates = [
+        (DateOrder::Ymd, ymd(a, b, c).filter(|_| c.len() <= 2)),
+        (DateOrder::Dmy, ymd(c, b, a)),
+        (DateOrder::Mdy, ymd(c, a, b)),
+    ];
+    // 연도가 뒤에 네 자리로 있으면 연-월-일은 후보에서 제외
+    let valid: Vec<(DateOrder, NaiveDate)> = candidates
+        .iter()
+        .filter(|(candidate, _)| c.len() != 4 || *candidate != DateOrder::Ymd)
+        .filter_map(|(candidate, date)| date.map(|date| (*candidate, date)))
+        .collect();
+
+    if let Some((_, date)) = valid.iter().find(|(candidate, _)| *candidate == order) {
+        return Ok(*date);
+    }
+    match valid.as_slice() {
+        [(_, date)] => Ok(*date),
+        [] => Err(format!("날짜로 변환할 수 없습니다: {}", text)),
+        _ => Err(format!(
+            "일/월 순서가 모호한 날짜입니다: {} (템플릿의 dateOrder를 지정하세요)",
+            text
+        )),
+    }
+}
+
+// 1900 날짜 체계 기준 (1 = 1900-01-01).
+// 엑셀은 없는 날짜 1900-02-29를 60으로 세므로 61부터는 하루 당겨 계산하고 60은 거부
+pub fn excel_serial_to_date(serial: f64) -> Option<NaiveDate> {
+    if !(1.0..2_958_466.0).contains(&serial) {
+        return None;
+    }
+    let days = serial.floor() as i64;
+    let base = match days {
+        ..=59 => NaiveDate::from_ymd_opt(1899, 12, 31)?,
+        60 => return None,
+        _ => NaiveDate::from_ymd_opt(1899, 12, 30)?,
+    };
+    base.checked_add_signed(Duration::days(days))
+}
+
+// 기간 표기를 개월 수로 해석.
+// 예) "12개월", "1년", "2년 6개월", "계약일로부터 12개월간", "36 months",
+//     "2025.01.01 ~ 2025.12.31", 단위 없는 숫자는 개월로 간주
+pub fn parse_period(text: &str, order: DateOrder) -> Result<Period, String> {
+    static FROM: OnceLock<Regex> = OnceLock::new();
+    static UNIT: OnceLock<Regex> = OnceLock::new();
+
+    let normalized = to_half_width(text.trim());
+    let invalid = || format!("기간으로 변환할 수 없습니다: {}", text.trim());
+
+    if let Ok(months) = normalized.parse::<u32>() {
+        return Ok(Period { months, from: None });
+    }
+
+    // 날짜 범위
+    if let Some((start, end)) = normalized.split_once('~') {
+        let start = parse_date(start, order)?;
+        let end = parse_date(end, order)?;
+        return months_between(start, end)
+            .map(|months| Period { months, from: None })
+            .ok_or_else(|| format!("월 단위 기간이 아닙니다: {}", text.trim()));
+    }
+
+    let from = regex(&FROM, r"([가-힣]+일)\s*(?:로|으로)?\s*부터")
+        .captures(&normalized)
+        .map(|captures| captures[1].to_string());
+
+    // "2년 6개월"처럼 공백으로만 이어진 단위는 한 표현으로 합산
+    let unit = regex(
+        &UNIT,
+        r"(?i)(\d+(?:\.\d+)?)\s*(개월|달|months?|mos?|년|years?|yrs?)",
+    );
+    let mut expressions: Vec<f64> = Vec::new();
+    let mut last_end: Option<usize> = None;
+    for captures in unit.captures_iter(&normalized) {
+        let whole = captures.get(0).expect("whole match");
+        let value: f64 = captures[1].parse().map_err(|_| invalid())?;
+        let months = match captures[2].to_lowercase().as_str() {
+            "년" | "year" | "years" | "yr" | "yrs" => value * 12.0,
+            _ => value,
+        };
+
+        let joined = last_end.is_some_and(|end| normalized[end..whole.start()].trim().is_empty());
+        match expressions.last_mut() {
+            Some(total) if joined => *total += months,
+            _ => expressions.push(months),
+        }
+        last_end = Some(whole.end());
+    }
+
+    let Some(first) = expressions.first().copied() else {
+        return Err(invalid());
+    };
+    // "1년(12개월)"처럼 같은 기간을 다시 쓴 경우만 허용
+    if expressions.iter().any(|months| (months - first).abs() > f64::EPSILON) {
+        return Err(format!("기간이 여러 개 표기되어 있습니다: {}", text.trim()));
+    }
+    if first.fract() != 0.0 || first < 0.0 {
+        return Err(format!("월 단위 기간이 아닙니다: {}", text.trim()));
+    }
+
+    Ok(Period {
+        months: first as u32,
+        from,
+    })
+}
+
+// 종료일 다음 날까지 정확히 몇 개월인지 (2025-01-01 ~ 2025-12-31 -> 12)
+fn months_between(start: NaiveDate, end: NaiveDate) -> Option<u32> {
+    let next = end.succ_opt()?;
+    let diff = (next.year() - start.year()) * 12 + next.month() as i32 - start.month() as i32;
+    let months = u32::try_from(diff).ok()?;
+    (start.checked_add_months(Months::new(months))? == next).then_some(months)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn converts_excel_serials_around_fake_leap_day() {
+        assert_eq!(excel_serial_to_date(1.0), Some(date(1900, 1, 1)));
+        assert_eq!(excel_serial_to_date(59.0), Some(date(1900, 2, 28)));
+        assert_eq!(excel_serial_to_date(60.0), None);
+        assert_eq!(excel_serial_to_date(61.0), Some(date(1900, 3, 1)));
+        assert_eq!(excel_serial_to_date(45834.75), Some(date(2025, 6, 26)));
+        assert_eq!(excel_serial_to_date(0.0), None);
+    }
+
+    #[test]
+    fn parses_dates() {
+        let cases = [
+            ("2025.06.26", DateOrder::Ymd, date(2025, 6, 26)),
+            ("2025. 6. 26.", DateOrder::Ymd, date(2025, 6, 26)),
+            ("2025년 6월 26일 (목)", DateOrder::Ymd, date(2025, 6, 26)),
+            ("２０２５－０６－２６", DateOrder::Ymd, date(2025, 6, 26)),
+            ("25/06/26", DateOrder::Ymd, date(2025, 6, 26)),
+            ("03/04/2025", DateOrder::Dmy, date(2025, 4, 3)),
+            ("03/04/2025", DateOrder::Mdy, date(2025, 3, 4)),
+            // 한 가지로만 해석되면 순서 설정과 무관
+            ("26/06/2025", DateOrder::Mdy, date(2025, 6, 26)),
+            ("20250626", DateOrder::Ymd, date(2025, 6, 26)),
+            ("45834", DateOrder::Ymd, date(2025, 6, 26)),
+            ("2025-06-26 09:30", DateOrder::Ymd, date(2025, 6, 26)),
+        ];
+        for (text, order, expected) in cases {
+            assert_eq!(parse_date(text, order), Ok(expected), "{}", text);
+        }
+
+        for text in ["2025.13.01", "2025.02.30", "2025.06.26 외", "내일"] {
+            assert!(parse_date(text, DateOrder::Ymd).is_err(), "{}", text);
+        }
+        assert!(parse_date("03/04/25", DateOrder::Ymd).is_ok());
+    }
+
+    #[test]
+    fn parses_periods() {
+        let months = |text: &str| parse_period(text, DateOrder::Ymd).map(|p| p.months);
+        assert_eq!(months("12개월"), Ok(12));
+        assert_eq!(months("1년"), Ok(12));
+        assert_eq!(months("2년 6개월"), Ok(30));
+        assert_eq!(months("36 months"), Ok(36));
+        assert_eq!(months("24"), Ok(24));
+        assert_eq!(months("1년(12개월)"), Ok(12));
+        assert_eq!(months("2025.01.01 ~ 2025.12.31"), Ok(12));
+
+        assert_eq!(
+            parse_period("계약일로부터 12개월간", DateOrder::Ymd),
+            Ok(Period {
+                months: 12,
+                from: Some("계약일".to_string()),
+            })
+        );
+
+        assert!(months("1년 또는 24개월").is_err());
+        assert!(months("1.5개월").is_err());
+        assert!(months("2025.01.01 ~ 2025.12.15").is_err());
+        assert!(months("협의").is_err());
+    }
+}
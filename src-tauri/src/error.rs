@@ -0,0 +1,345 @@
+use serde::ser::{Serialize, SerializeStruct, Serializer};
+use std::any::Any;
+use std::fmt;
+use std::io;
+use std::path::Path;
+
+// 모든 커맨드가 반환하는 오류.
+// 프론트엔드에는 { code, message, detail, context } 로 직렬화된다.
+//   code    - UI가 분기할 때 쓰는 고정 값 (예: FILE_NOT_FOUND, HTTP_ERROR)
+//   message - 토스트에 그대로 보여 줄 한국어 안내
+//   detail  - 원인 오류의 영어 원문 (로그/문의용)
+//   context - 관련 경로 (템플릿 오류는 JSON 안의 위치), URL, HTTP 상태 코드
+#[derive(Debug)]
+pub enum AppError {
+    FileNotFound { path: Option<String>, detail: String },
+    PermissionDenied { path: Option<String>, detail: String },
+    Io { path: Option<String>, detail: String },
+    Http { url: String, status: u16, reason: String },
+    Network { url: String, detail: String },
+    Timeout { url: String },
+    Cancelled,
+    Database(String),
+    NotFound { entity: String, id: i64 },
+    // 사용자가 고칠 수 있는 입력/데이터 오류 (한국어 메시지)
+    Invalid(String),
+    Parse { path: String, detail: String },
+    // 템플릿 매핑 JSON 오류. path는 JSON 안의 위치 (예: columns[2].dataType)
+    Template { path: String, detail: String },
+    FontNotFound,
+    Export { format: &'static str, detail: String },
+    Internal(String),
+}
+
+#[derive(Debug, Default, Clone, PartialEq, Eq, serde::Serialize)]
+pub struct ErrorContext {
+    pub path: Option<String>,
+    pub url: Option<String>,
+    pub status: Option<u16>,
+}
+
+impl AppError {
+    // 경로가 있는 입출력 오류. 종류에 따라 FILE_NOT_FOUND / PERMISSION_DENIED / IO_ERROR
+    pub fn io(error: io::Error, path: impl AsRef<Path>) -> Self {
+        Self::from_io(error, Some(path.as_ref().display().to_string()))
+    }
+
+    fn from_io(error: io::Error, path: Option<String>) -> Self {
+        let detail = error.to_string();
+        match error.kind() {
+            io::ErrorKind::NotFound => AppError::FileNotFound { path, detail },
+            io::ErrorKind::PermissionDenied => AppError::PermissionDenied { path, detail },
+            _ => AppError::Io { path, detail },
+        }
+    }
+
+    // HTTP 요청 오류. 시간 초과 / 상태 코드 / 연결 실패를 구분
+    pub fn request(error: reqwest::Error, url: &str) -> Self {
+        let url = url.to_string();
+        if error.is_timeout() {
+            AppError::Timeout { url }
+        } else if let Some(status) = error.status() {
+            AppError::http(url, status)
+        } else {
+            AppError::Network {
+                url,
+                detail: error.to_string(),
+            }
+        }
+    }
+
+    pub fn http(url: impl Into<String>, status: reqwest::StatusCode) -> Self {
+        AppError::Http {
+            url: url.into(),
+            status: status.as_u16(),
+            reason: status.canonical_reason().unwrap_or_default().to_string(),
+        }
+    }
+
+    pub fn invalid(message: impl Into<String>) -> Self {
+        AppError::Invalid(message.into())
+    }
+
+    pub fn not_found(entity: &str, id: i64) -> Self {
+        AppError::NotFound {
+            entity: entity.to_string(),
+            id,
+        }
+    }
+
+    pub fn code(&self) -> &'static str {
+        match self {
+            AppError::FileNotFound { .. } => "FILE_NOT_FOUND",
+            AppError::PermissionDenied { .. } => "PERMISSION_DENIED",
+            AppError::Io { .. } => "IO_ERROR",
+            AppError::Http { .. } => "HTTP_ERROR",
+            AppError::Network { .. } => "NETWORK_ERROR",
+            AppError::Timeout { .. } => "TIMEOUT",
+            AppError::Cancelled => "CANCELLED",
+            AppError::Database(_) => "DATABASE_ERROR",
+            AppError::NotFound { .. } => "NOT_FOUND",
+            AppError::Invalid(_) => "INVALID_INPUT",
+            AppError::Parse { .. } => "PARSE_ERROR",
+            AppError::Template { .. } => "TEMPLATE_ERROR",
+            AppError::FontNotFound => "FONT_NOT_FOUND",
+            AppError::Export { .. } => "EXPORT_ERROR",
+            AppError::Internal(_) => "INTERNAL_ERROR",
+        }
+    }
+
+    pub fn message(&self) -> String {
+        let at = |path: &Option<String>| path.as_deref().map(|p| format!(": {}", p)).unwrap_or_default();
+        match self {
+            AppError::FileNotFound { path, .. } => format!("파일을 찾을 수 없습니다{}", at(path)),
+            AppError::PermissionDenied { path, .. } => format!("파일에 접근할 권한이 없습니다{}", at(path)),
+            AppError::Io { path, .. } => format!("파일을 읽거나 쓰지 못했습니다{}", at(path)),
+            AppError::Http { status, .. } => format!("서버 응답 오류 (HTTP {})", status),
+            AppError::Network { .. } => "서버에 연결할 수 없습니다.".to_string(),
+            AppError::Timeout { .. } => "서버 응답 시간이 초과되었습니다.".to_string(),
+            AppError::Cancelled => "작업이 취소되었습니다.".to_string(),
+            AppError::Database(_) => "데이터베이스 오류가 발생했습니다.".to_string(),
+            AppError::NotFound { entity, id } => format!("{} 데이터를 찾을 수 없습니다: id={}", entity, id),
+            AppError::Invalid(message) => message.clone(),
+            AppError::Parse { path, .. } => format!("파일 내용을 해석할 수 없습니다: {}", path),
+            AppError::Template { path, .. } => format!("템플릿 JSON 형식 오류: {}", path),
+            AppError::FontNotFound => {
+                "한글 글꼴을 찾을 수 없습니다. 앱 데이터 폴더의 fonts 폴더에 TTF 글꼴을 넣거나 글꼴 경로를 지정하세요."
+                    .to_string()
+            }
+            AppError::Export { format, .. } => format!("{} 파일을 만들지 못했습니다.", format),
+            AppError::Internal(_) => "내부 오류가 발생했습니다.".to_string(),
+        }
+    }
+
+    pub fn detail(&self) -> String {
+        match self {
+            AppError::FileNotFound { detail, .. }
+            | AppError::PermissionDenied { detail, .. }
+            | AppError::Io { detail, .. }
+            | AppError::Network { detail, .. }
+            | AppError::Parse { detail, .. }
+            | AppError::Template { detail, .. }
+            | AppError::Export { detail, .. }
+            | AppError::Database(detail)
+            | AppError::Internal(detail) => detail.clone(),
+            AppError::Http { status, reason, .. } => format!("HTTP status {} {}", status, reason).trim_end().to_string(),
+            AppError::Timeout { .. } => "request timed out".to_string(),
+            AppError::Cancelled => "operation cancelled".to_string(),
+            AppError::NotFound { entity, id } => format!("no {} row with id {}", entity, id),
+            AppError::Invalid(_) => "invalid input".to_string(),
+            AppError::FontNotFound => "no Korean font file found".to_string(),
+        }
+    }
+
+    pub fn context(&self) -> ErrorContext {
+        match self {
+            AppError::FileNotFound { path, .. } | AppError::PermissionDenied { path, .. } | AppError::Io { path, .. } => {
+                ErrorContext {
+                    path: path.clone(),
+                    ..Default::default()
+                }
+            }
+            AppError::Parse { path, .. } | AppError::Template { path, .. } => ErrorContext {
+                path: Some(path.clone()),
+                ..Default::default()
+            },
+            AppError::Http { url, status, .. } => ErrorContext {
+                url: Some(url.clone()),
+                status: Some(*status),
+                ..Default::default()
+            },
+            AppError::Network { url, .. } | AppError::Timeout { url } => ErrorContext {
+                url: Some(url.clone()),
+                ..Default::default()
+            },
+            _ => ErrorContext::default(),
+        }
+    }
+}
+
+// catch_unwind로 잡은 패닉의 메시지 (panic!에 넘긴 문자열이 아니면 고정 문구)
+pub fn panic_message(payload: &(dyn Any + Send)) -> String {
+    payload
+        .downcast_ref::<&str>()
+        .map(|message| message.to_string())
+        .or_else(|| payload.downcast_ref::<String>().cloned())
+        .unwrap_or_else(|| "unknown panic".to_string())
+}
+
+// 로그나 가져오기 상태에 남기는 문자열: 한국어 메시지 + 원인
+impl fmt::Display for AppError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            AppError::FileNotFound { .. }
+            | AppError::PermissionDenied { .. }
+            | AppError::Io { .. }
+            | AppError::Network { .. }
+            | AppError::Database(_)
+            | AppError::Parse { .. }
+            | AppError::Template { .. }
+            | AppError::Export { .. }
+            | AppError::Internal(_) => write!(f, "{} ({})", self.message(), self.detail()),
+            _ => f.write_str(&self.message()),
+        }
+    }
+}
+
+impl std::error::Error for AppError {}
+
+impl Serialize for AppError {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("AppError", 4)?;
+        state.serialize_field("code", self.code())?;
+        state.serialize_field("message", &self.message())?;
+        state.serialize_field("detail", &self.detail())?;
+        state.serialize_field("context", &self.context())?;
+        state.end()
+    }
+}
+
+impl From<io::Error> for AppError {
+    fn from(error: io::Error) -> Self {
+        Self::from_io(error, None)
+    }
+}
+
+impl From<rusqlite::Error> for AppError {
+    fn from(error: rusqlite::Error) -> Self {
+        AppError::Database(error.to_string())
+    }
+}
+
+impl From<serde_json::Error> for AppError {
+    fn from(error: serde_json::Error) -> Self {
+        AppError::Internal(error.to_string())
+    }
+}
+
+impl From<tauri::Error> for AppError {
+    fn from(error: tauri::Error) -> Self {
+        AppError::Internal(error.to_string())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::{json, Value};
+
+    fn serialized(error: &AppError) -> Value {
+        serde_json::to_value(error).unwrap()
+    }
+
+    #[test]
+    fn serializes_code_message_detail_and_context() {
+        let error = AppError::io(io::Error::new(io::ErrorKind::NotFound, "No such file"), "/tmp/a.xlsx");
+        assert_eq!(
+            serialized(&error),
+            json!({
+                "code": "FILE_NOT_FOUND",
+                "message": "파일을 찾을 수 없습니다: /tmp/a.xlsx",
+                "detail": "No such file",
+                "context": { "path": "/tmp/a.xlsx", "url": null, "status": null },
+            })
+        );
+
+        let error = AppError::http("https://example.com/q.xlsx", reqwest::StatusCode::NOT_FOUND);
+        assert_eq!(
+            serialized(&error),
+            json!({
+                "code": "HTTP_ERROR",
+                "message": "서버 응답 오류 (HTTP 404)",
+                "detail": "HTTP status 404 Not Found",
+                "context": { "path": null, "url": "https://example.com/q.xlsx", "status": 404 },
+            })
+        );
+
+        let error = AppError::Template {
+            path: "columns[1].dataType".to_string(),
+            detail: "unknown variant `bogus`".to_string(),
+        };
+        assert_eq!(
+            serialized(&error),
+            json!({
+                "code": "TEMPLATE_ERROR",
+                "message": "템플릿 JSON 형식 오류: columns[1].dataType",
+                "detail": "unknown variant `bogus`",
+                "context": { "path": "columns[1].dataType", "url": null, "status": null },
+            })
+        );
+
+        assert_eq!(
+            serialized(&AppError::not_found("견적", 7)),
+            json!({
+                "code": "NOT_FOUND",
+                "message": "견적 데이터를 찾을 수 없습니다: id=7",
+                "detail": "no 견적 row with id 7",
+                "context": { "path": null, "url": null, "status": null },
+            })
+        );
+    }
+
+    #[test]
+    fn every_variant_has_a_stable_code() {
+        let path = || Some("a.csv".to_string());
+        let url = || "https://example.com".to_string();
+        let text = || "detail".to_string();
+        let errors = [
+            (AppError::FileNotFound { path: path(), detail: text() }, "FILE_NOT_FOUND"),
+            (AppError::PermissionDenied { path: path(), detail: text() }, "PERMISSION_DENIED"),
+            (AppError::Io { path: None, detail: text() }, "IO_ERROR"),
+            (AppError::Http { url: url(), status: 500, reason: text() }, "HTTP_ERROR"),
+            (AppError::Network { url: url(), detail: text() }, "NETWORK_ERROR"),
+            (AppError::Timeout { url: url() }, "TIMEOUT"),
+            (AppError::Cancelled, "CANCELLED"),
+            (AppError::Database(text()), "DATABASE_ERROR"),
+            (AppError::not_found("견적", 1), "NOT_FOUND"),
+            (AppError::invalid("잘못된 입력"), "INVALID_INPUT"),
+            (AppError::Parse { path: "a.pdf".to_string(), detail: text() }, "PARSE_ERROR"),
+            (AppError::Template { path: ".".to_string(), detail: text() }, "TEMPLATE_ERROR"),
+            (AppError::FontNotFound, "FONT_NOT_FOUND"),
+            (AppError::Export { format: "CSV", detail: text() }, "EXPORT_ERROR"),
+            (AppError::Internal(text()), "INTERNAL_ERROR"),
+        ];
+        for (error, code) in &errors {
+            let value = serialized(error);
+            assert_eq!(value["code"], *code);
+            let keys: Vec<&String> = value.as_object().unwrap().keys().collect();
+            assert_eq!(keys, ["code", "message", "detail", "context"]);
+            assert!(!value["message"].as_str().unwrap().is_empty(), "{}", code);
+        }
+
+        // 입력 오류의 메시지는 그대로, 원인 오류가 있는 변형은 Display에 원인을 덧붙임
+        assert_eq!(AppError::invalid("잘못된 입력").to_string(), "잘못된 입력");
+        assert_eq!(AppError::Database(text()).to_string(), "데이터베이스 오류가 발생했습니다. (detail)");
+    }
+
+    #[test]
+    fn maps_io_error_kinds() {
+        let error = |kind| AppError::io(io::Error::new(kind, "e"), "a.txt").code();
+        assert_eq!(error(io::ErrorKind::NotFound), "FILE_NOT_FOUND");
+        assert_eq!(error(io::ErrorKind::PermissionDenied), "PERMISSION_DENIED");
+        assert_eq!(error(io::ErrorKind::UnexpectedEof), "IO_ERROR");
+        assert_eq!(AppError::from(io::Error::other("e")).context(), ErrorContext::default());
+    }
+}
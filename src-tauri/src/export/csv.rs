@@ -0,0 +1,288 @@
+use csv::{QuoteStyle, Terminator, WriterBuilder};
+use encoding_rs::EUC_KR;
+use serde::Deserialize;
+use std::path::Path;
+use tauri::{command, State};
+
+use super::xlsx::ExportResult;
+use super::{field, report_columns, select_quotations, value, FieldValue, ReportColumn};
+use crate::atomic_file::write_atomic;
+use crate::error::AppError;
+use crate::storage::columns::Column;
+use crate::storage::quotations::{Quotation, QuotationFilter};
+use crate::storage::Database;
+
+const UTF8_BOM: &[u8] = b"\xEF\xBB\xBF";
+
+// 출력 인코딩. 한글 윈도우 엑셀에서 바로 열리도록 기본은 BOM 붙은 UTF-8
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum CsvEncoding {
+    #[default]
+    Utf8Bom,
+    Cp949,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum Delimiter {
+    #[default]
+    Comma,
+    Tab,
+    Semicolon,
+}
+
+impl Delimiter {
+    fn byte(self) -> u8 {
+        match self {
+            Delimiter::Comma => b',',
+            Delimiter::Tab => b'\t',
+            Delimiter::Semicolon => b';',
+        }
+    }
+}
+
+// 내보낼 열. 이름이 없으면 columns 정의 순서 전체, 있으면 지정한 순서대로 (정의 이름 또는 필드 키)
+pub fn select_columns(columns: &[Column], names: Option<&[String]>) -> Result<Vec<ReportColumn>, AppError> {
+    let available = report_columns(columns);
+    let Some(names) = names.filter(|names| !names.is_empty()) else {
+        return Ok(available);
+    };
+
+    let mut selected: Vec<ReportColumn> = Vec::new();
+    for name in names {
+        let resolved = field(name).ok_or_else(|| AppError::invalid(format!("내보낼 수 없는 열입니다: {name}")))?;
+        if selected.iter().any(|c| c.field.key == resolved.key) {
+            continue;
+        }
+        // columns 정의에 있는 열이면 정의의 이름을 머리글로
+        let column = available
+            .iter()
+            .find(|c| c.field.key == resolved.key)
+            .cloned()
+            .unwrap_or_else(|| ReportColumn {
+                field: resolved,
+                header: resolved.names[0].to_string(),
+            });
+        selected.push(column);
+    }
+    Ok(selected)
+}
+
+fn csv_error(e: impl ToString) -> AppError {
+    AppError::Export {
+        format: "CSV",
+        detail: e.to_string(),
+    }
+}
+
+// 금액은 통화 기호 없이 숫자로 (엑셀에서 숫자로 인식)
+fn cell(quotation: &Quotation, column: &ReportColumn) -> String {
+    match value(quotation, column.field.key) {
+        FieldValue::Empty => String::new(),
+        FieldValue::Text(text) => text,
+        FieldValue::Integer(number) => number.to_string(),
+        FieldValue::Money(amount) => amount.normalize().to_string(),
+    }
+}
+
+fn encode(text: String, encoding: CsvEncoding) -> Result<Vec<u8>, AppError> {
+    match encoding {
+        CsvEncoding::Utf8Bom => {
+            let mut bytes = UTF8_BOM.to_vec();
+            bytes.extend_from_slice(text.as_bytes());
+            Ok(bytes)
+        }
+        CsvEncoding::Cp949 => {
+            let (bytes, _, had_errors) = EUC_KR.encode(&text);
+            if had_errors {
+                let unmappable = text
+                    .chars()
+                    .find(|c| EUC_KR.encode(c.encode_utf8(&mut [0; 4])).2)
+                    .unwrap_or('?');
+                return Err(AppError::invalid(format!(
+                    "CP949로 표현할 수 없는 문자가 있습니다 ('{unmappable}'). UTF-8(BOM)로 내보내세요."
+                )));
+            }
+            Ok(bytes.into_owned())
+        }
+    }
+}
+
+// RFC 4180 CSV: 구분자/따옴표/줄바꿈이 든 값만 따옴표로 감싸고 따옴표는 두 번, 줄 끝은 CRLF
+pub fn build_csv(
+    columns: &[ReportColumn],
+    quotations: &[Quotation],
+    delimiter: Delimiter,
+    encoding: CsvEncoding,
+) -> Result<Vec<u8>, AppError> {
+    let mut writer = WriterBuilder::new()
+        .delimiter(delimiter.byte())
+        .quote_style(QuoteStyle::Necessary)
+        .double_quote(true)
+        .terminator(Terminator::CRLF)
+        .from_writer(Vec::new());
+
+    writer
+        .write_record(columns.iter().map(|c| c.header.as_str()))
+        .map_err(csv_error)?;
+    for quotation in quotations {
+        writer
+            .write_record(columns.iter().map(|c| cell(quotation, c)))
+            .map_err(csv_error)?;
+    }
+
+    let bytes = writer.into_inner().map_err(csv_error)?;
+    let text = String::from_utf8(bytes).map_err(csv_error)?;
+    encode(text, encoding)
+}
+
+// 데이터 관리 탭 필터와 같은 조건으로 CSV 저장. quotation_ids가 있으면 선택한 항목만
+#[command]
+pub async fn export_quotations_csv(
+    db: State<'_, Database>,
+    path: String,
+    filter: Option<QuotationFilter>,
+    quotation_ids: Option<Vec<i64>>,
+    columns: Option<Vec<String>>,
+    encoding: Option<CsvEncoding>,
+    delimiter: Option<Delimiter>,
+) -> Result<ExportResult, AppError> {
+    let quotations = select_quotations(&db, &filter.unwrap_or_default(), quotation_ids.as_deref())?;
+    let columns = select_columns(&db.list_columns()?, columns.as_deref())?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let contents = build_csv(
+            &columns,
+            &quotations,
+            delimiter.unwrap_or_default(),
+            encoding.unwrap_or_default(),
+        )?;
+        write_atomic(Path::new(&path), &contents, 0)?;
+        Ok(ExportResult {
+            path,
+            rows: quotations.len(),
+        })
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn column(name: &str) -> Column {
+        Column {
+            id: 0,
+            name: name.to_string(),
+            data_type: "string".to_string(),
+            required: false,
+            default_value: None,
+            created_at: None,
+        }
+    }
+
+    fn quotation(vendor: &str, notes: Option<&str>) -> Quotation {
+        serde_json::from_value(json!({
+            "id": 1,
+            "quotationId": "Q-1",
+            "vendor": vendor,
+            "specialNotes": notes,
+            "consumerPrice": "1500000.50",
+            "contractAmount": "1234000",
+            "version": 2,
+        }))
+        .unwrap()
+    }
+
+    fn names(names: &[&str]) -> Vec<String> {
+        names.iter().map(|n| n.to_string()).collect()
+    }
+
+    fn export(
+        selected: &[&str],
+        quotations: &[Quotation],
+        delimiter: Delimiter,
+        encoding: CsvEncoding,
+    ) -> Vec<u8> {
+        let columns = select_columns(&[], Some(&names(selected))).unwrap();
+        build_csv(&columns, quotations, delimiter, encoding).unwrap()
+    }
+
+    #[test]
+    fn utf8_output_starts_with_bom() {
+        let quotations = [quotation("가나상사", None)];
+        let bytes = export(&["벤더", "계약금액"], &quotations, Delimiter::Comma, CsvEncoding::Utf8Bom);
+        assert_eq!(&bytes[..3], UTF8_BOM);
+        assert_eq!(&bytes[3..], "벤더,계약금액\r\n가나상사,1234000\r\n".as_bytes());
+    }
+
+    #[test]
+    fn cp949_encodes_hangul_without_bom() {
+        let bytes = export(&["벤더"], &[quotation("가나", None)], Delimiter::Comma, CsvEncoding::Cp949);
+        // 벤더 = BAA5 B4F5, 가나 = B0A1 B3AA
+        assert_eq!(bytes, b"\xBA\xA5\xB4\xF5\r\n\xB0\xA1\xB3\xAA\r\n");
+
+        let columns = select_columns(&[], Some(&names(&["벤더"]))).unwrap();
+        let quotations = [quotation("가나😀", None)];
+        let err = build_csv(&columns, &quotations, Delimiter::Comma, CsvEncoding::Cp949).unwrap_err();
+        assert_eq!(err.code(), "INVALID_INPUT");
+        assert!(err.to_string().contains('😀'), "{}", err);
+    }
+
+    #[test]
+    fn uses_custom_delimiter() {
+        let quotations = [quotation("가나상사", Some("a,b"))];
+        let bytes = export(&["벤더", "비고"], &quotations, Delimiter::Tab, CsvEncoding::Utf8Bom);
+        // 탭 구분에서는 쉼표가 든 값을 감싸지 않음
+        assert_eq!(&bytes[3..], "벤더\t특이사항\r\n가나상사\ta,b\r\n".as_bytes());
+
+        let bytes = export(&["벤더", "비고"], &quotations, Delimiter::Semicolon, CsvEncoding::Utf8Bom);
+        assert_eq!(&bytes[3..], "벤더;특이사항\r\n가나상사;a,b\r\n".as_bytes());
+    }
+
+    #[test]
+    fn quotes_fields_per_rfc_4180() {
+        let quotations = [
+            quotation("가나,상사", Some("12\" 모니터")),
+            quotation("다라;전자", Some("1줄\n2줄")),
+            quotation("마바\t정보", Some("CR\r끝")),
+        ];
+        let bytes = export(&["벤더", "비고"], &quotations, Delimiter::Comma, CsvEncoding::Utf8Bom);
+        assert_eq!(
+            &bytes[3..],
+            "벤더,특이사항\r\n\
+             \"가나,상사\",\"12\"\" 모니터\"\r\n\
+             다라;전자,\"1줄\n2줄\"\r\n\
+             마바\t정보,\"CR\r끝\"\r\n"
+                .as_bytes()
+        );
+
+        let bytes = export(&["벤더"], &quotations[1..2], Delimiter::Semicolon, CsvEncoding::Utf8Bom);
+        assert_eq!(&bytes[3..], "벤더\r\n\"다라;전자\"\r\n".as_bytes());
+    }
+
+    #[test]
+    fn keeps_selected_column_order() {
+        let quotations = [quotation("가나상사", None)];
+        let bytes = export(
+            &["차수", "contractAmount", "벤더", "계약금액", "소비자가"],
+            &quotations,
+            Delimiter::Comma,
+            CsvEncoding::Utf8Bom,
+        );
+        // 중복 열은 한 번만, 금액은 통화 기호나 천 단위 구분 없이
+        assert_eq!(
+            &bytes[3..],
+            "차수,계약금액,벤더,소비자가격\r\n2,1234000,가나상사,1500000.5\r\n".as_bytes()
+        );
+
+        // columns 정의가 있으면 정의 이름이 머리글
+        let columns = [column("업체명"), column("벤더사")];
+        let defined = select_columns(&columns, Some(&names(&["vendor"]))).unwrap();
+        assert_eq!(defined[0].header, "벤더사");
+        let err = select_columns(&[], Some(&names(&["없는열"]))).unwrap_err();
+        assert_eq!(err.code(), "INVALID_INPUT");
+    }
+}
@@ -0,0 +1,144 @@
+use rust_decimal::Decimal;
+use std::collections::HashSet;
+
+use crate::error::AppError;
+use crate::storage::columns::Column;
+use crate::storage::quotations::{Quotation, QuotationFilter};
+use crate::storage::Database;
+use crate::text_encoding::normalize_key;
+
+pub mod csv;
+pub mod pdf;
+pub mod xlsx;
+
+// 셀 값의 종류 (엑셀 서식/정렬 기준)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldKind {
+    Text,
+    Integer,
+    Money,
+}
+
+// 내보낼 수 있는 견적 필드. names는 columns 정의에서 이 필드로 볼 이름 (첫 번째가 기본 헤더)
+#[derive(Debug, Clone, Copy)]
+pub struct ExportField {
+    pub key: &'static str,
+    pub names: &'static [&'static str],
+    pub kind: FieldKind,
+}
+
+pub const FIELDS: &[ExportField] = &[
+    ExportField { key: "quotationId", names: &["견적ID", "ID", "견적번호"], kind: FieldKind::Text },
+    ExportField { key: "solution", names: &["솔루션"], kind: FieldKind::Text },
+    ExportField { key: "category", names: &["카테고리", "분류"], kind: FieldKind::Text },
+    ExportField { key: "partner", names: &["파트너사", "협력사"], kind: FieldKind::Text },
+    ExportField { key: "vendor", names: &["벤더", "벤더사"], kind: FieldKind::Text },
+    ExportField { key: "mainProduct", names: &["주요제품", "제품"], kind: FieldKind::Text },
+    ExportField { key: "quantity", names: &["수량"], kind: FieldKind::Integer },
+    ExportField { key: "consumerPrice", names: &["소비자가격", "소비자가"], kind: FieldKind::Money },
+    ExportField { key: "contractAmount", names: &["계약금액"], kind: FieldKind::Money },
+    ExportField { key: "savingsAmount", names: &["절감액"], kind: FieldKind::Money },
+    ExportField {
+        key: "freeMaintenancePeriod",
+        names: &["무상유지보수기간", "무상기간", "무상 유지보수 기간"],
+        kind: FieldKind::Text,
+    },
+    ExportField { key: "vendorContact", names: &["벤더담당자", "벤더담당"], kind: FieldKind::Text },
+    ExportField { key: "vendorEmail", names: &["벤더이메일"], kind: FieldKind::Text },
+    ExportField { key: "partnerContact", names: &["파트너담당자", "협력사담당"], kind: FieldKind::Text },
+    ExportField { key: "partnerEmail", names: &["파트너이메일"], kind: FieldKind::Text },
+    ExportField { key: "specialNotes", names: &["특이사항", "비고"], kind: FieldKind::Text },
+    ExportField { key: "version", names: &["차수", "버전"], kind: FieldKind::Integer },
+];
+
+// 내보낼 열 (헤더는 columns 정의의 이름)
+#[derive(Debug, Clone)]
+pub struct ReportColumn {
+    pub field: ExportField,
+    pub header: String,
+}
+
+pub enum FieldValue {
+    Empty,
+    Text(String),
+    Integer(i64),
+    Money(Decimal),
+}
+
+pub fn field(key_or_name: &str) -> Option<ExportField> {
+    let name = normalize_key(key_or_name);
+    FIELDS.iter().copied().find(|field| {
+        normalize_key(field.key) == name || field.names.iter().any(|n| normalize_key(n) == name)
+    })
+}
+
+// columns 정의 순서대로 견적 필드에 대응하는 열. 대응하는 정의가 없으면 전체 필드를 기본 헤더로
+pub fn report_columns(columns: &[Column]) -> Vec<ReportColumn> {
+    let mut resolved: Vec<ReportColumn> = Vec::new();
+    for column in columns {
+        let Some(field) = field(&column.name) else {
+            continue;
+        };
+        if resolved.iter().all(|c| c.field.key != field.key) {
+            resolved.push(ReportColumn {
+                field,
+                header: column.name.clone(),
+            });
+        }
+    }
+
+    if resolved.is_empty() {
+        return FIELDS
+            .iter()
+            .map(|field| ReportColumn {
+                field: *field,
+                header: field.names[0].to_string(),
+            })
+            .collect();
+    }
+    resolved
+}
+
+pub fn value(quotation: &Quotation, key: &str) -> FieldValue {
+    let text = |value: &Option<String>| match value.as_deref().map(str::trim) {
+        Some(text) if !text.is_empty() => FieldValue::Text(text.to_string()),
+        _ => FieldValue::Empty,
+    };
+    let money = |value: Option<Decimal>| value.map_or(FieldValue::Empty, FieldValue::Money);
+
+    match key {
+        "quotationId" => FieldValue::Text(quotation.quotation_id.clone()),
+        "solution" => text(&quotation.solution),
+        "category" => text(&quotation.category),
+        "partner" => text(&quotation.partner),
+        "vendor" => text(&quotation.vendor),
+        "mainProduct" => text(&quotation.main_product),
+        "quantity" => quotation.quantity.map_or(FieldValue::Empty, FieldValue::Integer),
+        "consumerPrice" => money(quotation.consumer_price),
+        "contractAmount" => money(quotation.contract_amount),
+        // 입력값이 아니라 소비자가격 - 계약금액으로 계산한 절감액
+        "savingsAmount" => money(quotation.savings()),
+        "freeMaintenancePeriod" => text(&quotation.free_maintenance_period),
+        "vendorContact" => text(&quotation.vendor_contact),
+        "vendorEmail" => text(&quotation.vendor_email),
+        "partnerContact" => text(&quotation.partner_contact),
+        "partnerEmail" => text(&quotation.partner_email),
+        "specialNotes" => text(&quotation.special_notes),
+        "version" => FieldValue::Integer(quotation.version),
+        _ => FieldValue::Empty,
+    }
+}
+
+// 필터에 맞는 견적 중 선택한 항목 (선택이 없으면 전체)
+pub fn select_quotations(
+    db: &Database,
+    filter: &QuotationFilter,
+    quotation_ids: Option<&[i64]>,
+) -> Result<Vec<Quotation>, AppError> {
+    let quotations = db.list_quotations(filter)?;
+    let Some(ids) = quotation_ids else {
+        return Ok(quotations);
+    };
+    let ids: HashSet<i64> = ids.iter().copied().collect();
+    Ok(quotations.into_iter().filter(|q| ids.contains(&q.id)).collect())
+}
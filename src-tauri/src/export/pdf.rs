@@ -0,0 +1,755 @@
+use printpdf::path::PaintMode;
+use printpdf::{
+    Color, IndirectFontRef, Line, Mm, PdfDocument, PdfDocumentReference, PdfLayerReference, Point,
+    Rect, Rgb,
+};
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use std::collections::HashMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Manager, Runtime, State};
+
+use super::select_quotations;
+use super::xlsx::ExportResult;
+use crate::analysis::savings::{self, GroupSavings, SavingSum, SavingsReport};
+use crate::analysis::totals::{summarize, GroupBy, GroupTotals, MoneyTotals, TotalsReport};
+use crate::analysis::trends::{trend, Interval, TrendSeries};
+use crate::atomic_file::write_atomic;
+use crate::error::AppError;
+use crate::storage::history::HistoryEntry;
+use crate::storage::quotations::{Quotation, QuotationFilter};
+use crate::storage::Database;
+
+// A4 세로, 단위 mm
+const PAGE_WIDTH: f32 = 210.0;
+const PAGE_HEIGHT: f32 = 297.0;
+const MARGIN: f32 = 18.0;
+const CONTENT_WIDTH: f32 = PAGE_WIDTH - MARGIN * 2.0;
+const ROW_HEIGHT: f32 = 6.0;
+const TABLE_FONT: f32 = 8.0;
+// 막대 차트에 그릴 최대 그룹 수 (계약금액 상위)
+const CHART_GROUPS: usize = 10;
+const PT_TO_MM: f32 = 0.3528;
+
+// 지정한 글꼴이 없을 때 찾을 시스템 한글 글꼴. printpdf가 글꼴 모음(.ttc)은 읽지 못하므로 .ttf만
+const SYSTEM_FONTS: &[&str] = &[
+    "C:\\Windows\\Fonts\\malgun.ttf",
+    "/System/Library/Fonts/Supplemental/AppleGothic.ttf",
+    "/Library/Fonts/AppleGothic.ttf",
+    "/usr/share/fonts/truetype/nanum/NanumGothic.ttf",
+    "/usr/share/fonts/nanum/NanumGothic.ttf",
+    "/usr/share/fonts/truetype/noto/NotoSansKR-Regular.ttf",
+];
+
+const HEADER_FILL: (f32, f32, f32) = (0.85, 0.88, 0.95);
+const TOTAL_FILL: (f32, f32, f32) = (0.87, 0.92, 0.97);
+const CONTRACT_COLOR: (f32, f32, f32) = (0.27, 0.45, 0.77);
+const SAVINGS_COLOR: (f32, f32, f32) = (0.44, 0.68, 0.28);
+const GRID_COLOR: (f32, f32, f32) = (0.8, 0.8, 0.8);
+const TEXT_COLOR: (f32, f32, f32) = (0.0, 0.0, 0.0);
+
+#[derive(Clone, Copy, PartialEq, Eq)]
+enum Align {
+    Left,
+    Right,
+}
+
+fn rgb((r, g, b): (f32, f32, f32)) -> Color {
+    Color::Rgb(Rgb::new(r, g, b, None))
+}
+
+fn pdf_error(e: printpdf::Error) -> AppError {
+    AppError::Export {
+        format: "PDF",
+        detail: e.to_string(),
+    }
+}
+
+fn number(value: Decimal) -> f32 {
+    value.to_f32().unwrap_or(0.0)
+}
+
+// 1234567 -> "1,234,567" (원 단위 반올림)
+fn grouped(value: Decimal) -> String {
+    let rounded = value.round();
+    let digits = rounded.abs().to_string();
+    let groups: Vec<&str> = digits
+        .as_bytes()
+        .rchunks(3)
+        .rev()
+        .map(|chunk| std::str::from_utf8(chunk).unwrap_or_default())
+        .collect();
+    let sign = if rounded.is_sign_negative() && !rounded.is_zero() { "-" } else { "" };
+    format!("{sign}{}", groups.join(","))
+}
+
+fn won(value: Decimal) -> String {
+    format!("₩{}", grouped(value))
+}
+
+fn percent(rate: Option<Decimal>) -> String {
+    rate.map_or_else(|| "-".to_string(), |rate| format!("{:.2}%", rate))
+}
+
+// 차트 축 눈금용 짧은 금액 ("1.2억", "350만")
+fn short_won(value: f32) -> String {
+    let abs = value.abs();
+    if abs >= 1.0e8 {
+        format!("{:.1}억", value / 1.0e8)
+    } else if abs >= 1.0e4 {
+        format!("{:.0}만", value / 1.0e4)
+    } else {
+        format!("{:.0}", value)
+    }
+}
+
+fn group_name(key: &Option<String>) -> String {
+    key.clone().unwrap_or_else(|| "(미지정)".to_string())
+}
+
+// 글꼴 메트릭 없이 쓰는 글자 폭 추정. 한글/한자는 1em, 나머지는 0.55em
+fn text_width(text: &str, size: f32) -> f32 {
+    let ems: f32 = text
+        .chars()
+        .map(|c| if (c as u32) >= 0x1100 { 1.0 } else { 0.55 })
+        .sum();
+    ems * size * PT_TO_MM
+}
+
+// 폭에 맞지 않으면 뒤를 잘라 "…"로 줄임
+fn fit(text: &str, size: f32, width: f32) -> String {
+    if text_width(text, size) <= width {
+        return text.to_string();
+    }
+    let mut out = String::new();
+    for c in text.chars() {
+        out.push(c);
+        if text_width(&out, size) + text_width("…", size) > width {
+            out.pop();
+            break;
+        }
+    }
+    out.push('…');
+    out
+}
+
+// 글꼴 파일 찾기: 지정한 경로 > 앱 데이터 fonts 폴더 > 시스템 한글 글꼴
+pub fn resolve_font(font_path: Option<&Path>, app_fonts: Option<&Path>) -> Result<PathBuf, AppError> {
+    if let Some(path) = font_path {
+        if path.is_file() {
+            return Ok(path.to_path_buf());
+        }
+        return Err(AppError::FileNotFound {
+            path: Some(path.display().to_string()),
+            detail: "font file does not exist".to_string(),
+        });
+    }
+
+    if let Some(dir) = app_fonts {
+        if let Ok(entries) = fs::read_dir(dir) {
+            let mut fonts: Vec<PathBuf> = entries
+                .filter_map(|entry| entry.ok().map(|e| e.path()))
+                .filter(|path| {
+                    path.extension()
+                        .and_then(|ext| ext.to_str())
+                        .is_some_and(|ext| matches!(ext.to_lowercase().as_str(), "ttf" | "otf"))
+                })
+                .collect();
+            fonts.sort();
+            if let Some(font) = fonts.into_iter().next() {
+                return Ok(font);
+            }
+        }
+    }
+
+    SYSTEM_FONTS
+        .iter()
+        .map(PathBuf::from)
+        .find(|path| path.is_file())
+        .ok_or(AppError::FontNotFound)
+}
+
+// 보고서에 표시할 조회 조건
+#[derive(Debug, Clone, Default)]
+pub struct ReportCriteria {
+    pub filter: QuotationFilter,
+    pub selected: Option<usize>,
+    pub interval: Interval,
+}
+
+impl ReportCriteria {
+    fn lines(&self) -> Vec<(String, String)> {
+        let filter = &self.filter;
+        let mut lines = Vec::new();
+        let fields = [
+            ("카테고리", &filter.category),
+            ("파트너사", &filter.partner),
+            ("벤더", &filter.vendor),
+            ("솔루션", &filter.solution),
+        ];
+        for (label, value) in fields {
+            if let Some(value) = value.as_deref().map(str::trim).filter(|v| !v.is_empty()) {
+                lines.push((label.to_string(), value.to_string()));
+            }
+        }
+        let months = match (filter.min_free_maintenance_months, filter.max_free_maintenance_months) {
+            (Some(min), Some(max)) => Some(format!("{min}~{max}개월")),
+            (Some(min), None) => Some(format!("{min}개월 이상")),
+            (None, Some(max)) => Some(format!("{max}개월 이하")),
+            (None, None) => None,
+        };
+        if let Some(months) = months {
+            lines.push(("무상 유지보수".to_string(), months));
+        }
+        if let Some(selected) = self.selected {
+            lines.push(("선택 항목".to_string(), format!("{selected}건")));
+        }
+        if lines.is_empty() {
+            lines.push(("조건".to_string(), "전체 견적".to_string()));
+        }
+        let interval = match self.interval {
+            Interval::Month => "월별",
+            Interval::Quarter => "분기별",
+            Interval::Year => "연도별",
+        };
+        lines.push(("추이 단위".to_string(), interval.to_string()));
+        lines
+    }
+}
+
+struct Column {
+    header: &'static str,
+    width: f32,
+    align: Align,
+}
+
+const fn column(header: &'static str, width: f32, align: Align) -> Column {
+    Column { header, width, align }
+}
+
+// 페이지를 넘기며 위에서 아래로 그리는 작성기
+struct Writer {
+    doc: PdfDocumentReference,
+    font: IndirectFontRef,
+    layer: PdfLayerReference,
+    // 현재 줄의 위쪽 y (mm, 페이지 아래가 0)
+    y: f32,
+}
+
+impl Writer {
+    fn new(title: &str, font: &[u8]) -> Result<Self, AppError> {
+        let (doc, page, layer) = PdfDocument::new(title, Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "본문");
+        let font = doc.add_external_font(font).map_err(pdf_error)?;
+        let layer = doc.get_page(page).get_layer(layer);
+        Ok(Writer {
+            doc,
+            font,
+            layer,
+            y: PAGE_HEIGHT - MARGIN,
+        })
+    }
+
+    fn new_page(&mut self) {
+        let (page, layer) = self.doc.add_page(Mm(PAGE_WIDTH), Mm(PAGE_HEIGHT), "본문");
+        self.layer = self.doc.get_page(page).get_layer(layer);
+        self.y = PAGE_HEIGHT - MARGIN;
+    }
+
+    // 남은 공간이 height보다 작으면 새 페이지
+    fn ensure(&mut self, height: f32) {
+        if self.y - height < MARGIN {
+            self.new_page();
+        }
+    }
+
+    fn text(&self, text: &str, size: f32, x: f32, baseline: f32, color: (f32, f32, f32)) {
+        self.layer.set_fill_color(rgb(color));
+        self.layer.use_text(text, size, Mm(x), Mm(baseline), &self.font);
+    }
+
+    fn text_aligned(&self, text: &str, size: f32, x: f32, width: f32, baseline: f32, align: Align) {
+        let text = fit(text, size, width - 2.0);
+        let x = match align {
+            Align::Left => x + 1.0,
+            Align::Right => x + width - 1.0 - text_width(&text, size),
+        };
+        self.text(&text, size, x, baseline, TEXT_COLOR);
+    }
+
+    fn fill(&self, x: f32, y: f32, width: f32, height: f32, color: (f32, f32, f32)) {
+        self.layer.set_fill_color(rgb(color));
+        self.layer
+            .add_rect(Rect::new(Mm(x), Mm(y), Mm(x + width), Mm(y + height)).with_mode(PaintMode::Fill));
+    }
+
+    fn line(&self, points: &[(f32, f32)], color: (f32, f32, f32), thickness: f32) {
+        self.layer.set_outline_color(rgb(color));
+        self.layer.set_outline_thickness(thickness);
+        self.layer.add_line(Line {
+            points: points
+                .iter()
+                .map(|&(x, y)| (Point::new(Mm(x), Mm(y)), false))
+                .collect(),
+            is_closed: false,
+        });
+    }
+
+    fn heading(&mut self, text: &str) {
+        self.ensure(14.0 + ROW_HEIGHT * 3.0);
+        self.y -= 8.0;
+        self.text(text, 13.0, MARGIN, self.y, TEXT_COLOR);
+        self.line(&[(MARGIN, self.y - 2.0), (MARGIN + CONTENT_WIDTH, self.y - 2.0)], GRID_COLOR, 0.5);
+        self.y -= 6.0;
+    }
+
+    fn row(&mut self, columns: &[Column], cells: &[String], fill: Option<(f32, f32, f32)>) {
+        let top = self.y;
+        let bottom = top - ROW_HEIGHT;
+        if let Some(color) = fill {
+            self.fill(MARGIN, bottom, CONTENT_WIDTH, ROW_HEIGHT, color);
+        }
+        let mut x = MARGIN;
+        for (column, cell) in columns.iter().zip(cells) {
+            self.text_aligned(cell, TABLE_FONT, x, column.width, bottom + 1.9, column.align);
+            x += column.width;
+        }
+        self.line(&[(MARGIN, bottom), (MARGIN + CONTENT_WIDTH, bottom)], GRID_COLOR, 0.3);
+        self.y = bottom;
+    }
+
+    // 머리글 + 본문 + 합계 행. 페이지가 넘어가면 머리글을 다시 그림
+    fn table(&mut self, columns: &[Column], rows: &[Vec<String>], total: Option<&[String]>) {
+        let header: Vec<String> = columns.iter().map(|c| c.header.to_string()).collect();
+        self.ensure(ROW_HEIGHT * 2.0);
+        self.row(columns, &header, Some(HEADER_FILL));
+        for cells in rows {
+            if self.y - ROW_HEIGHT < MARGIN {
+                self.new_page();
+                self.row(columns, &header, Some(HEADER_FILL));
+            }
+            self.row(columns, cells, None);
+        }
+        if let Some(total) = total {
+            self.ensure(ROW_HEIGHT);
+            self.row(columns, total, Some(TOTAL_FILL));
+        }
+        self.y -= 4.0;
+    }
+
+    fn legend(&self, x: f32, y: f32, items: &[(&str, (f32, f32, f32))]) {
+        let mut x = x;
+        for (label, color) in items {
+            self.fill(x, y, 3.0, 3.0, *color);
+            self.text(label, TABLE_FONT, x + 4.5, y + 0.4, TEXT_COLOR);
+            x += 8.0 + text_width(label, TABLE_FONT);
+        }
+    }
+
+    // 값 축 눈금과 격자. 축 최댓값 반환
+    fn value_axis(&self, left: f32, bottom: f32, width: f32, height: f32, max: f32, min: f32) -> (f32, f32) {
+        let max = if max > 0.0 { max } else { 1.0 };
+        let min = min.min(0.0);
+        for step in 0..=4 {
+            let value = min + (max - min) * step as f32 / 4.0;
+            let y = bottom + height * step as f32 / 4.0;
+            self.line(&[(left, y), (left + width, y)], GRID_COLOR, 0.3);
+            let label = short_won(value);
+            self.text(&label, 7.0, left - 1.5 - text_width(&label, 7.0), y - 1.0, TEXT_COLOR);
+        }
+        (min, max)
+    }
+
+    // 그룹별 계약금액/절감액 막대 차트
+    fn bar_chart(&mut self, title: &str, groups: &[(String, f32, f32)]) {
+        let height = 60.0;
+        self.ensure(height + 30.0);
+        self.y -= 6.0;
+        self.text(title, 10.0, MARGIN, self.y, TEXT_COLOR);
+        self.legend(MARGIN + CONTENT_WIDTH - 50.0, self.y - 0.5, &[("계약금액", CONTRACT_COLOR), ("절감액", SAVINGS_COLOR)]);
+
+        let left = MARGIN + 16.0;
+        let width = CONTENT_WIDTH - 16.0;
+        let bottom = self.y - 6.0 - height;
+        let max = groups.iter().map(|(_, a, b)| a.max(*b)).fold(0.0, f32::max);
+        let min = groups.iter().map(|(_, a, b)| a.min(*b)).fold(0.0, f32::min);
+        let (min, max) = self.value_axis(left, bottom, width, height, max, min);
+        let scale = |value: f32| bottom + (value - min) / (max - min) * height;
+        let zero = scale(0.0);
+
+        if !groups.is_empty() {
+            let slot = width / groups.len() as f32;
+            let bar = (slot * 0.35).min(10.0);
+            for (i, (label, contract, savings)) in groups.iter().enumerate() {
+                let x = left + slot * i as f32 + slot / 2.0;
+                for (offset, value, color) in [(-bar, *contract, CONTRACT_COLOR), (0.0, *savings, SAVINGS_COLOR)] {
+                    let top = scale(value);
+                    let (low, high) = if top >= zero { (zero, top) } else { (top, zero) };
+                    if high - low > 0.0 {
+                        self.fill(x + offset, low, bar, high - low, color);
+                    }
+                }
+                let label = fit(label, 7.0, slot - 1.0);
+                self.text(&label, 7.0, x - text_width(&label, 7.0) / 2.0, bottom - 4.0, TEXT_COLOR);
+            }
+        }
+        self.line(&[(left, zero), (left + width, zero)], TEXT_COLOR, 0.5);
+        self.y = bottom - 10.0;
+    }
+
+    // 기간별 계약금액/절감액 꺾은선 차트
+    fn line_chart(&mut self, title: &str, series: &TrendSeries) {
+        let height = 60.0;
+        self.ensure(height + 30.0);
+        self.y -= 6.0;
+        self.text(title, 10.0, MARGIN, self.y, TEXT_COLOR);
+        self.legend(MARGIN + CONTENT_WIDTH - 50.0, self.y - 0.5, &[("계약금액", CONTRACT_COLOR), ("절감액", SAVINGS_COLOR)]);
+
+        let left = MARGIN + 16.0;
+        let width = CONTENT_WIDTH - 16.0;
+        let bottom = self.y - 6.0 - height;
+        let values: Vec<(f32, f32)> = series
+            .points
+            .iter()
+            .map(|p| (number(p.contract_amount), number(p.savings_amount)))
+            .collect();
+        let max = values.iter().map(|(a, b)| a.max(*b)).fold(0.0, f32::max);
+        let min = values.iter().map(|(a, b)| a.min(*b)).fold(0.0, f32::min);
+        let (min, max) = self.value_axis(left, bottom, width, height, max, min);
+        let scale = |value: f32| bottom + (value - min) / (max - min) * height;
+
+        if series.points.is_empty() {
+            self.text("표시할 기간이 없습니다.", TABLE_FONT, left + 4.0, bottom + height / 2.0, TEXT_COLOR);
+        } else {
+            let count = series.points.len();
+            let step = if count > 1 { width / (count - 1) as f32 } else { 0.0 };
+            let x = |i: usize| if count > 1 { left + step * i as f32 } else { left + width / 2.0 };
+
+            for (pick, color) in [(0, CONTRACT_COLOR), (1, SAVINGS_COLOR)] {
+                let points: Vec<(f32, f32)> = values
+                    .iter()
+                    .enumerate()
+                    .map(|(i, v)| (x(i), scale(if pick == 0 { v.0 } else { v.1 })))
+                    .collect();
+                if points.len() > 1 {
+                    self.line(&points, color, 1.2);
+                }
+                for &(px, py) in &points {
+                    self.fill(px - 0.8, py - 0.8, 1.6, 1.6, color);
+                }
+            }
+
+            // 라벨이 겹치지 않도록 간격을 두고 표시
+            let label_width = series
+                .points
+                .iter()
+                .map(|p| text_width(&p.period, 7.0))
+                .fold(0.0, f32::max)
+                + 2.0;
+            let every = if step > 0.0 { (label_width / step).ceil().max(1.0) as usize } else { 1 };
+            for (i, point) in series.points.iter().enumerate() {
+                if i % every == 0 {
+                    let w = text_width(&point.period, 7.0);
+                    self.text(&point.period, 7.0, x(i) - w / 2.0, bottom - 4.0, TEXT_COLOR);
+                }
+            }
+        }
+        self.y = bottom - 10.0;
+    }
+}
+
+const TOTALS_COLUMNS: [Column; 7] = [
+    column("구분", 44.0, Align::Left),
+    column("건수", 12.0, Align::Right),
+    column("수량", 14.0, Align::Right),
+    column("소비자가격", 26.0, Align::Right),
+    column("계약금액", 26.0, Align::Right),
+    column("절감액", 34.0, Align::Right),
+    column("절감률", 18.0, Align::Right),
+];
+
+const SAVINGS_COLUMNS: [Column; 7] = [
+    column("구분", 44.0, Align::Left),
+    column("건수", 12.0, Align::Right),
+    column("최초 대비", 26.0, Align::Right),
+    column("최초 대비율", 18.0, Align::Right),
+    column("직전 대비", 26.0, Align::Right),
+    column("직전 대비율", 18.0, Align::Right),
+    column("소비자가 대비", 30.0, Align::Right),
+];
+
+const BASIS_COLUMNS: [Column; 6] = [
+    column("기준", 44.0, Align::Left),
+    column("건수", 12.0, Align::Right),
+    column("기준 금액", 30.0, Align::Right),
+    column("현재 금액", 30.0, Align::Right),
+    column("절감액", 38.0, Align::Right),
+    column("절감률", 20.0, Align::Right),
+];
+
+fn totals_cells(label: String, totals: &MoneyTotals) -> Vec<String> {
+    vec![
+        label,
+        totals.count.to_string(),
+        grouped(Decimal::from(totals.quantity)),
+        won(totals.consumer_price),
+        won(totals.contract_amount),
+        won(totals.savings_amount),
+        percent(totals.savings_rate),
+    ]
+}
+
+fn savings_cells(label: String, group: &savings::SavingsTotals) -> Vec<String> {
+    vec![
+        label,
+        group.count.to_string(),
+        won(group.vs_first.amount),
+        percent(group.vs_first.rate),
+        won(group.vs_previous.amount),
+        percent(group.vs_previous.rate),
+        won(group.discount.amount),
+    ]
+}
+
+fn basis_cells(label: &str, sum: &SavingSum) -> Vec<String> {
+    vec![
+        label.to_string(),
+        sum.count.to_string(),
+        won(sum.baseline),
+        won(sum.current),
+        won(sum.amount),
+        percent(sum.rate),
+    ]
+}
+
+fn write_title_page(writer: &mut Writer, criteria: &ReportCriteria, totals: &MoneyTotals) {
+    writer.y = PAGE_HEIGHT - 80.0;
+    writer.text("견적 분석 보고서", 24.0, MARGIN, writer.y, TEXT_COLOR);
+    writer.y -= 10.0;
+    let generated = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+    writer.text(&format!("생성일시 {generated}"), 10.0, MARGIN, writer.y, TEXT_COLOR);
+    writer.line(&[(MARGIN, writer.y - 4.0), (MARGIN + CONTENT_WIDTH, writer.y - 4.0)], TEXT_COLOR, 0.8);
+    writer.y -= 18.0;
+
+    writer.text("조회 조건", 12.0, MARGIN, writer.y, TEXT_COLOR);
+    writer.y -= 8.0;
+    for (label, value) in criteria.lines() {
+        writer.text(&label, 10.0, MARGIN + 4.0, writer.y, TEXT_COLOR);
+        writer.text(&fit(&value, 10.0, CONTENT_WIDTH - 40.0), 10.0, MARGIN + 40.0, writer.y, TEXT_COLOR);
+        writer.y -= 6.5;
+    }
+
+    writer.y -= 10.0;
+    writer.text("전체 요약", 12.0, MARGIN, writer.y, TEXT_COLOR);
+    writer.y -= 8.0;
+    let summary = [
+        ("견적 수", format!("{}건", totals.count)),
+        ("소비자가격 합계", won(totals.consumer_price)),
+        ("계약금액 합계", won(totals.contract_amount)),
+        ("절감액 합계", won(totals.savings_amount)),
+        ("절감률", percent(totals.savings_rate)),
+    ];
+    for (label, value) in summary {
+        writer.text(label, 10.0, MARGIN + 4.0, writer.y, TEXT_COLOR);
+        writer.text(&value, 10.0, MARGIN + 40.0, writer.y, TEXT_COLOR);
+        writer.y -= 6.5;
+    }
+}
+
+fn write_totals_section(writer: &mut Writer, title: &str, report: &TotalsReport) {
+    writer.heading(title);
+    let rows: Vec<Vec<String>> = report
+        .groups
+        .iter()
+        .map(|group| totals_cells(group_name(&group.key), &group.totals))
+        .collect();
+    writer.table(&TOTALS_COLUMNS, &rows, Some(&totals_cells("합계".to_string(), &report.total)));
+    writer.bar_chart(&format!("{title} 계약금액/절감액"), &chart_groups(&report.groups));
+}
+
+// 계약금액 상위 그룹
+fn chart_groups(groups: &[GroupTotals]) -> Vec<(String, f32, f32)> {
+    let mut groups: Vec<&GroupTotals> = groups.iter().collect();
+    groups.sort_by_key(|group| std::cmp::Reverse(group.totals.contract_amount));
+    groups
+        .into_iter()
+        .take(CHART_GROUPS)
+        .map(|group| {
+            (
+                group_name(&group.key),
+                number(group.totals.contract_amount),
+                number(group.totals.savings_amount),
+            )
+        })
+        .collect()
+}
+
+fn write_savings_section(writer: &mut Writer, report: &SavingsReport) {
+    writer.heading("차수별 절감");
+    let total = &report.total;
+    let rows = vec![
+        basis_cells("최초 차수 대비", &total.vs_first),
+        basis_cells("직전 차수 대비", &total.vs_previous),
+        basis_cells("소비자가격 대비", &total.discount),
+    ];
+    writer.table(&BASIS_COLUMNS, &rows, None);
+
+    for (title, groups) in [("카테고리별 절감", &report.by_category), ("벤더별 절감", &report.by_vendor)] {
+        writer.heading(title);
+        let rows: Vec<Vec<String>> = groups
+            .iter()
+            .map(|group: &GroupSavings| savings_cells(group_name(&group.key), &group.totals))
+            .collect();
+        writer.table(&SAVINGS_COLUMNS, &rows, Some(&savings_cells("합계".to_string(), total)));
+    }
+}
+
+fn write_trend_section(writer: &mut Writer, series: &TrendSeries) {
+    let title = match series.interval {
+        Interval::Month => "월별 추이",
+        Interval::Quarter => "분기별 추이",
+        Interval::Year => "연도별 추이",
+    };
+    writer.heading(title);
+    writer.line_chart(&format!("{title} 계약금액/절감액"), series);
+    if series.skipped > 0 {
+        writer.text(
+            &format!("견적일과 등록일을 알 수 없어 제외한 견적 {}건", series.skipped),
+            TABLE_FONT,
+            MARGIN,
+            writer.y,
+            TEXT_COLOR,
+        );
+        writer.y -= 6.0;
+    }
+}
+
+// 분석 탭과 같은 집계(합계/절감/추이)로 만든 보고서 PDF 내용
+pub fn build_report(
+    quotations: &[Quotation],
+    history: &HashMap<i64, Vec<HistoryEntry>>,
+    criteria: &ReportCriteria,
+    font: &[u8],
+) -> Result<Vec<u8>, AppError> {
+    let by_category = summarize(quotations, Some(GroupBy::Category));
+    let by_vendor = summarize(quotations, Some(GroupBy::Vendor));
+    let savings = savings::compute(quotations, history);
+    let series = trend(quotations, criteria.interval, None, None);
+
+    let mut writer = Writer::new("견적 분석 보고서", font)?;
+    write_title_page(&mut writer, criteria, &by_category.total);
+
+    writer.new_page();
+    write_totals_section(&mut writer, "카테고리별 요약", &by_category);
+    writer.new_page();
+    write_totals_section(&mut writer, "벤더별 요약", &by_vendor);
+    writer.new_page();
+    write_savings_section(&mut writer, &savings);
+    write_trend_section(&mut writer, &series);
+
+    writer.doc.save_to_bytes().map_err(pdf_error)
+}
+
+#[derive(Debug, Clone, serde::Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfExportResult {
+    #[serde(flatten)]
+    pub result: ExportResult,
+    // 보고서에 포함한 글꼴 파일
+    pub font: String,
+}
+
+// 분석 보고서를 PDF로 저장. 글꼴은 font_path > 앱 데이터 fonts 폴더 > 번들 글꼴 > 시스템 한글 글꼴 순으로 찾아 포함
+#[command]
+pub async fn export_report_pdf<R: Runtime>(
+    app: AppHandle<R>,
+    db: State<'_, Database>,
+    path: String,
+    filter: Option<QuotationFilter>,
+    quotation_ids: Option<Vec<i64>>,
+    interval: Option<Interval>,
+    font_path: Option<String>,
+) -> Result<PdfExportResult, AppError> {
+    let filter = filter.unwrap_or_default();
+    let quotations = select_quotations(&db, &filter, quotation_ids.as_deref())?;
+    let history = db.quotation_history_map()?;
+    let app_fonts = app.path().app_data_dir().ok().map(|dir| dir.join("fonts"));
+    let criteria = ReportCriteria {
+        filter,
+        selected: quotation_ids.as_ref().map(|_| quotations.len()),
+        interval: interval.unwrap_or_default(),
+    };
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let font = resolve_font(font_path.as_deref().map(Path::new), app_fonts.as_deref())?;
+        let font_data = fs::read(&font).map_err(|e| AppError::io(e, &font))?;
+        let contents = build_report(&quotations, &history, &criteria, &font_data)?;
+        write_atomic(Path::new(&path), &contents, 0)?;
+        Ok(PdfExportResult {
+            result: ExportResult {
+                path,
+                rows: quotations.len(),
+            },
+            font: font.display().to_string(),
+        })
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    // 한글 글리프는 없지만 글꼴 포함과 숫자 출력 확인에는 충분
+    const TEST_FONT: &[u8] = include_bytes!("../../tests/fixtures/RobotoMedium.ttf");
+
+    #[test]
+    fn report_parses_back() {
+        let quotation: Quotation = serde_json::from_value(json!({
+            "id": 1,
+            "quotationId": "Q-1",
+            "category": "보안",
+            "vendor": "가나상사",
+            "consumerPrice": "1500000",
+            "contractAmount": "1234000",
+            "version": 1,
+            "createdAt": "2025-06-26 09:30:00",
+        }))
+        .unwrap();
+        let criteria = ReportCriteria {
+            filter: QuotationFilter::default(),
+            selected: None,
+            interval: Interval::Month,
+        };
+
+        let contents = build_report(&[quotation], &HashMap::new(), &criteria, TEST_FONT).unwrap();
+        let doc = pdf_extract::Document::load_mem(&contents).unwrap();
+        assert!(doc.get_pages().len() >= 4);
+        let text = pdf_extract::extract_text_from_mem(&contents).unwrap();
+        assert!(text.contains("1,234,000"), "{}", text);
+        assert!(text.contains("266,000"), "{}", text);
+    }
+
+    #[test]
+    fn resolves_fonts_in_order() {
+        let dir = tempfile::tempdir().unwrap();
+        let app_fonts = dir.path().join("fonts");
+        fs::create_dir(&app_fonts).unwrap();
+
+        // 글꼴 모음(.ttc)은 건너뜀
+        fs::write(app_fonts.join("a.ttc"), b"collection").unwrap();
+        let custom = app_fonts.join("c.ttf");
+        fs::write(&custom, b"custom").unwrap();
+        fs::write(app_fonts.join("d.otf"), b"custom").unwrap();
+        assert_eq!(resolve_font(None, Some(&app_fonts)).unwrap(), custom);
+
+        let chosen = dir.path().join("chosen.ttf");
+        fs::write(&chosen, b"chosen").unwrap();
+        assert_eq!(resolve_font(Some(&chosen), Some(&app_fonts)).unwrap(), chosen);
+
+        let missing = dir.path().join("missing.ttf");
+        let error = resolve_font(Some(&missing), Some(&app_fonts)).unwrap_err();
+        assert_eq!(error.code(), "FILE_NOT_FOUND");
+    }
+}
@@ -0,0 +1,641 @@
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use rust_xlsxwriter::{
+    utility::column_number_to_name, Color, Format, FormatAlign, FormatBorder, Formula, Workbook,
+    Worksheet, XlsxError,
+};
+use serde::Serialize;
+use std::collections::{BTreeMap, HashMap};
+use std::path::Path;
+use tauri::{command, State};
+
+use super::{report_columns, select_quotations, value, FieldKind, FieldValue, ReportColumn};
+use crate::analysis::comparison::{self, Comparison};
+use crate::analysis::savings::{self, SavingSum};
+use crate::analysis::totals::{summarize, GroupBy, MoneyTotals};
+use crate::atomic_file::write_atomic;
+use crate::error::AppError;
+use crate::storage::columns::Column;
+use crate::storage::history::HistoryEntry;
+use crate::storage::quotations::{Quotation, QuotationFilter};
+use crate::storage::Database;
+
+const DATA_SHEET: &str = "견적 목록";
+const SUMMARY_SHEET: &str = "요약";
+const COMPARISON_SHEET: &str = "벤더 비교";
+const WON_FORMAT: &str = "\"₩\"#,##0;[Red]-\"₩\"#,##0";
+const RATE_FORMAT: &str = "0.00\"%\"";
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ExportResult {
+    pub path: String,
+    pub rows: usize,
+}
+
+struct Formats {
+    header: Format,
+    text: Format,
+    integer: Format,
+    money: Format,
+    rate: Format,
+    subtotal_label: Format,
+    subtotal_integer: Format,
+    subtotal_money: Format,
+    total_label: Format,
+    total_integer: Format,
+    total_money: Format,
+    // 비교표 최저가 강조
+    lowest_money: Format,
+    lowest_total_money: Format,
+    title: Format,
+}
+
+impl Formats {
+    fn new() -> Self {
+        let cell = Format::new().set_border(FormatBorder::Thin);
+        let subtotal = cell.clone().set_bold().set_background_color(Color::RGB(0xF2F2F2));
+        let total = cell.clone().set_bold().set_background_color(Color::RGB(0xDDEBF7));
+        let lowest = Color::RGB(0xC6EFCE);
+
+        Formats {
+            header: cell
+                .clone()
+                .set_bold()
+                .set_align(FormatAlign::Center)
+                .set_background_color(Color::RGB(0xD9E1F2)),
+            text: cell.clone(),
+            integer: cell.clone().set_num_format("#,##0"),
+            money: cell.clone().set_num_format(WON_FORMAT),
+            rate: cell.clone().set_num_format(RATE_FORMAT),
+            subtotal_label: subtotal.clone(),
+            subtotal_integer: subtotal.clone().set_num_format("#,##0"),
+            subtotal_money: subtotal.set_num_format(WON_FORMAT),
+            total_label: total.clone(),
+            total_integer: total.clone().set_num_format("#,##0"),
+            total_money: total.clone().set_num_format(WON_FORMAT),
+            lowest_money: cell.clone().set_num_format(WON_FORMAT).set_background_color(lowest).set_font_color(Color::RGB(0x006100)),
+            lowest_total_money: total.set_num_format(WON_FORMAT).set_background_color(lowest).set_font_color(Color::RGB(0x006100)),
+            title: Format::new().set_bold().set_font_size(14),
+        }
+    }
+}
+
+fn number(value: Decimal) -> f64 {
+    value.to_f64().unwrap_or_default()
+}
+
+fn xlsx_error(e: XlsxError) -> AppError {
+    AppError::Export {
+        format: "엑셀",
+        detail: e.to_string(),
+    }
+}
+
+fn column_width(column: &ReportColumn) -> f64 {
+    match column.field.kind {
+        FieldKind::Money => 16.0,
+        FieldKind::Integer => 8.0,
+        FieldKind::Text => match column.field.key {
+            "mainProduct" | "specialNotes" => 30.0,
+            "vendorEmail" | "partnerEmail" => 24.0,
+            _ => 14.0,
+        },
+    }
+}
+
+// 소계/합계 행에서 SUBTOTAL 수식으로 더하는 열
+const SUMMED_FIELDS: [&str; 4] = ["quantity", "consumerPrice", "contractAmount", "savingsAmount"];
+
+// 소계/합계 이름을 쓸 위치. 선택한 열이 모두 합계 열이면 맨 앞에 이름 열을 따로 둠
+#[derive(Clone, Copy)]
+struct TotalLayout {
+    // 데이터 열의 시작 위치 (이름 열이 있으면 1)
+    first_col: u16,
+    label_col: u16,
+}
+
+impl TotalLayout {
+    fn new(columns: &[ReportColumn]) -> Self {
+        match columns.iter().position(|c| !SUMMED_FIELDS.contains(&c.field.key)) {
+            Some(col) => TotalLayout {
+                first_col: 0,
+                label_col: col as u16,
+            },
+            None => TotalLayout {
+                first_col: 1,
+                label_col: 0,
+            },
+        }
+    }
+}
+
+// 그룹별 데이터 행 뒤에 소계 행, 마지막에 합계 행.
+// 소계/합계는 SUBTOTAL(9, ...) 수식이라 자동 필터로 행을 숨겨도 맞고, 합계는 소계를 중복 합산하지 않는다.
+fn write_data_sheet(
+    sheet: &mut Worksheet,
+    columns: &[ReportColumn],
+    quotations: &[Quotation],
+    group_by: GroupBy,
+    formats: &Formats,
+) -> Result<(), XlsxError> {
+    sheet.set_name(DATA_SHEET)?;
+    let layout = TotalLayout::new(columns);
+    if layout.first_col > 0 {
+        sheet.write_string_with_format(0, 0, "구분", &formats.header)?;
+        sheet.set_column_width(0, 14.0)?;
+    }
+    for (col, column) in columns.iter().enumerate() {
+        let col = layout.first_col + col as u16;
+        sheet.write_string_with_format(0, col, &column.header, &formats.header)?;
+        sheet.set_column_width(col, column_width(column))?;
+    }
+
+    let mut groups: BTreeMap<Option<String>, Vec<&Quotation>> = BTreeMap::new();
+    for quotation in quotations {
+        groups.entry(group_by.key(quotation)).or_default().push(quotation);
+    }
+
+    let mut row: u32 = 1;
+    for (key, items) in &groups {
+        let first = row;
+        for quotation in items {
+            if layout.first_col > 0 {
+                sheet.write_blank(row, 0, &formats.text)?;
+            }
+            for (col, column) in columns.iter().enumerate() {
+                let col = layout.first_col + col as u16;
+                match value(quotation, column.field.key) {
+                    FieldValue::Empty => sheet.write_blank(row, col, &formats.text)?,
+                    FieldValue::Text(text) => sheet.write_string_with_format(row, col, &text, &formats.text)?,
+                    FieldValue::Integer(n) => sheet.write_number_with_format(row, col, n as f64, &formats.integer)?,
+                    FieldValue::Money(m) => sheet.write_number_with_format(row, col, number(m), &formats.money)?,
+                };
+            }
+            row += 1;
+        }
+
+        let label = format!("{} 소계", key.as_deref().unwrap_or("(미지정)"));
+        let mut totals = MoneyTotals::default();
+        for quotation in items {
+            totals.add(quotation);
+        }
+        write_total_row(sheet, columns, layout, row, (first, row - 1), &label, &totals, formats, false)?;
+        row += 1;
+    }
+
+    let last_col = (layout.first_col as usize + columns.len()).saturating_sub(1) as u16;
+    sheet.set_freeze_panes(1, 0)?;
+    if quotations.is_empty() {
+        sheet.autofilter(0, 0, 0, last_col)?;
+        return Ok(());
+    }
+
+    let totals = summarize(quotations, None).total;
+    write_total_row(sheet, columns, layout, row, (1, row - 1), "합계", &totals, formats, true)?;
+    sheet.autofilter(0, 0, row - 1, last_col)?;
+    Ok(())
+}
+
+#[allow(clippy::too_many_arguments)]
+fn write_total_row(
+    sheet: &mut Worksheet,
+    columns: &[ReportColumn],
+    layout: TotalLayout,
+    row: u32,
+    (first, last): (u32, u32),
+    label: &str,
+    totals: &MoneyTotals,
+    formats: &Formats,
+    grand: bool,
+) -> Result<(), XlsxError> {
+    let (label_format, integer_format, money_format) = if grand {
+        (&formats.total_label, &formats.total_integer, &formats.total_money)
+    } else {
+        (&formats.subtotal_label, &formats.subtotal_integer, &formats.subtotal_money)
+    };
+
+    if layout.first_col > 0 {
+        sheet.write_string_with_format(row, layout.label_col, label, label_format)?;
+    }
+    for (col, column) in columns.iter().enumerate() {
+        let col = layout.first_col + col as u16;
+        // 미리 계산한 값을 수식 결과로 넣어 두면 재계산 전에도 값이 보임
+        let result = match column.field.key {
+            "quantity" => Some(Decimal::from(totals.quantity)),
+            "consumerPrice" => Some(totals.consumer_price),
+            "contractAmount" => Some(totals.contract_amount),
+            "savingsAmount" => Some(totals.savings_amount),
+            _ => None,
+        };
+        match result {
+            Some(result) => {
+                let name = column_number_to_name(col);
+                // 엑셀 행 번호는 1부터
+                let formula = Formula::new(format!("=SUBTOTAL(9,{0}{1}:{0}{2})", name, first + 1, last + 1))
+                    .set_result(result.to_string());
+                let format = if column.field.kind == FieldKind::Money { money_format } else { integer_format };
+                sheet.write_formula_with_format(row, col, formula, format)?;
+            }
+            None if col == layout.label_col => {
+                sheet.write_string_with_format(row, col, label, label_format)?;
+            }
+            None => {
+                sheet.write_blank(row, col, label_format)?;
+            }
+        }
+    }
+    Ok(())
+}
+
+fn write_summary_sheet(
+    sheet: &mut Worksheet,
+    quotations: &[Quotation],
+    savings: &savings::SavingsReport,
+    group_by: GroupBy,
+    formats: &Formats,
+) -> Result<(), XlsxError> {
+    sheet.set_name(SUMMARY_SHEET)?;
+    sheet.set_column_width(0, 24.0)?;
+    for col in 1..=5 {
+        sheet.set_column_width(col, 16.0)?;
+    }
+
+    let generated = chrono::Local::now().format("%Y-%m-%d %H:%M").to_string();
+    sheet.write_string_with_format(0, 0, "견적 요약 보고서", &formats.title)?;
+    sheet.write_string(1, 0, format!("작성일시: {}", generated))?;
+
+    // 금액 합계 (그룹별)
+    let report = summarize(quotations, Some(group_by));
+    let mut row = 3;
+    let headers = ["구분", "건수", "소비자가격", "계약금액", "절감액", "절감률"];
+    for (col, header) in headers.iter().enumerate() {
+        sheet.write_string_with_format(row, col as u16, *header, &formats.header)?;
+    }
+    row += 1;
+    for group in &report.groups {
+        let label = group.key.as_deref().unwrap_or("(미지정)");
+        write_money_totals(sheet, row, label, &group.totals, formats, false)?;
+        row += 1;
+    }
+    write_money_totals(sheet, row, "전체", &report.total, formats, true)?;
+
+    // 차수 이력 기준 절감
+    row += 3;
+    sheet.write_string_with_format(row - 1, 0, "차수별 절감", &formats.title)?;
+    let headers = ["기준", "품목 수", "기준 금액", "현재 금액", "절감액", "절감률"];
+    for (col, header) in headers.iter().enumerate() {
+        sheet.write_string_with_format(row, col as u16, *header, &formats.header)?;
+    }
+    let rows = [
+        ("최초 차수 대비", &savings.total.vs_first),
+        ("직전 차수 대비", &savings.total.vs_previous),
+        ("소비자가격 대비 할인", &savings.total.discount),
+    ];
+    for (label, sum) in rows {
+        row += 1;
+        write_saving_sum(sheet, row, label, sum, formats)?;
+    }
+    Ok(())
+}
+
+fn write_money_totals(
+    sheet: &mut Worksheet,
+    row: u32,
+    label: &str,
+    totals: &MoneyTotals,
+    formats: &Formats,
+    grand: bool,
+) -> Result<(), XlsxError> {
+    let (label_format, integer_format, money_format) = if grand {
+        (&formats.total_label, &formats.total_integer, &formats.total_money)
+    } else {
+        (&formats.text, &formats.integer, &formats.money)
+    };
+    sheet.write_string_with_format(row, 0, label, label_format)?;
+    sheet.write_number_with_format(row, 1, totals.count as f64, integer_format)?;
+    sheet.write_number_with_format(row, 2, number(totals.consumer_price), money_format)?;
+    sheet.write_number_with_format(row, 3, number(totals.contract_amount), money_format)?;
+    sheet.write_number_with_format(row, 4, number(totals.savings_amount), money_format)?;
+    write_rate(sheet, row, 5, totals.savings_rate, formats)
+}
+
+fn write_saving_sum(
+    sheet: &mut Worksheet,
+    row: u32,
+    label: &str,
+    sum: &SavingSum,
+    formats: &Formats,
+) -> Result<(), XlsxError> {
+    sheet.write_string_with_format(row, 0, label, &formats.text)?;
+    sheet.write_number_with_format(row, 1, sum.count as f64, &formats.integer)?;
+    sheet.write_number_with_format(row, 2, number(sum.baseline), &formats.money)?;
+    sheet.write_number_with_format(row, 3, number(sum.current), &formats.money)?;
+    sheet.write_number_with_format(row, 4, number(sum.amount), &formats.money)?;
+    write_rate(sheet, row, 5, sum.rate, formats)
+}
+
+fn write_rate(
+    sheet: &mut Worksheet,
+    row: u32,
+    col: u16,
+    rate: Option<Decimal>,
+    formats: &Formats,
+) -> Result<(), XlsxError> {
+    match rate {
+        Some(rate) => sheet.write_number_with_format(row, col, number(rate), &formats.rate)?,
+        None => sheet.write_blank(row, col, &formats.rate)?,
+    };
+    Ok(())
+}
+
+// 견적 목록 + 요약 시트로 된 보고서 엑셀 파일 내용
+pub fn build_report(
+    columns: &[Column],
+    quotations: &[Quotation],
+    history: &HashMap<i64, Vec<HistoryEntry>>,
+    group_by: GroupBy,
+) -> Result<Vec<u8>, AppError> {
+    let columns = report_columns(columns);
+    let savings = savings::compute(quotations, history);
+    let formats = Formats::new();
+
+    let mut workbook = Workbook::new();
+    write_data_sheet(workbook.add_worksheet(), &columns, quotations, group_by, &formats)
+        .map_err(xlsx_error)?;
+    write_summary_sheet(workbook.add_worksheet(), quotations, &savings, group_by, &formats)
+        .map_err(xlsx_error)?;
+    workbook.save_to_buffer().map_err(xlsx_error)
+}
+
+// 사용자가 고른 경로에 보고서 저장. quotation_ids가 있으면 선택한 항목만
+#[command]
+pub async fn export_report_xlsx(
+    db: State<'_, Database>,
+    path: String,
+    filter: Option<QuotationFilter>,
+    quotation_ids: Option<Vec<i64>>,
+    group_by: Option<GroupBy>,
+) -> Result<ExportResult, AppError> {
+    let quotations = select_quotations(&db, &filter.unwrap_or_default(), quotation_ids.as_deref())?;
+    let columns = db.list_columns()?;
+    let history = db.quotation_history_map()?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let contents = build_report(&columns, &quotations, &history, group_by.unwrap_or(GroupBy::Category))?;
+        write_atomic(Path::new(&path), &contents, 0)?;
+        Ok(ExportResult {
+            path,
+            rows: quotations.len(),
+        })
+    })
+    .await?
+}
+
+// 견적마다 수량/단가/계약금액/무상유지보수 4열
+const COMPARISON_FIELDS: [&str; 4] = ["수량", "단가", "계약금액", "무상유지보수"];
+
+fn write_comparison_sheet(sheet: &mut Worksheet, comparison: &Comparison, formats: &Formats) -> Result<(), XlsxError> {
+    sheet.set_name(COMPARISON_SHEET)?;
+    let title = match comparison.solution.as_deref() {
+        Some(solution) => format!("{} 견적 비교", solution),
+        None => "견적 비교".to_string(),
+    };
+    sheet.write_string_with_format(0, 0, title, &formats.title)?;
+    sheet.write_string(1, 0, "같은 제품 중 단가가 가장 낮은 견적을 초록색으로 표시")?;
+
+    // 3행: 견적(벤더) 머리글, 4행: 항목 머리글
+    let (group_row, header_row) = (3, 4);
+    sheet.merge_range(group_row, 0, header_row, 0, "제품", &formats.header)?;
+    sheet.set_column_width(0, 30.0)?;
+    let width = COMPARISON_FIELDS.len() as u16;
+    for (i, quotation) in comparison.quotations.iter().enumerate() {
+        let first = 1 + i as u16 * width;
+        let name = quotation.vendor.as_deref().or(quotation.partner.as_deref()).unwrap_or("(미지정)");
+        let label = match quotation.partner.as_deref().filter(|p| Some(*p) != quotation.vendor.as_deref()) {
+            Some(partner) => format!("{} / {} ({})", name, partner, quotation.quotation_id),
+            None => format!("{} ({})", name, quotation.quotation_id),
+        };
+        sheet.merge_range(group_row, first, group_row, first + width - 1, &label, &formats.header)?;
+        for (offset, header) in COMPARISON_FIELDS.iter().enumerate() {
+            let col = first + offset as u16;
+            sheet.write_string_with_format(header_row, col, *header, &formats.header)?;
+            sheet.set_column_width(col, if offset == 0 { 8.0 } else { 16.0 })?;
+        }
+    }
+
+    let mut row = header_row + 1;
+    for line in &comparison.rows {
+        sheet.write_string_with_format(row, 0, &line.product, &formats.text)?;
+        for (i, cell) in line.cells.iter().enumerate() {
+            let first = 1 + i as u16 * width;
+            let Some(cell) = cell else {
+                for offset in 0..width {
+                    sheet.write_blank(row, first + offset, &formats.text)?;
+                }
+                continue;
+            };
+            let money_format = if cell.lowest { &formats.lowest_money } else { &formats.money };
+            match cell.quantity {
+                Some(quantity) => sheet.write_number_with_format(row, first, quantity as f64, &formats.integer)?,
+                None => sheet.write_blank(row, first, &formats.integer)?,
+            };
+            for (offset, amount) in [(1, cell.unit_price), (2, cell.contract_amount)] {
+                match amount {
+                    Some(amount) => sheet.write_number_with_format(row, first + offset, number(amount), money_format)?,
+                    None => sheet.write_blank(row, first + offset, &formats.money)?,
+                };
+            }
+            match cell.free_maintenance_period.as_deref() {
+                Some(period) => sheet.write_string_with_format(row, first + 3, period, &formats.text)?,
+                None => sheet.write_blank(row, first + 3, &formats.text)?,
+            };
+        }
+        row += 1;
+    }
+
+    sheet.write_string_with_format(row, 0, "합계", &formats.total_label)?;
+    for (i, quotation) in comparison.quotations.iter().enumerate() {
+        let first = 1 + i as u16 * width;
+        let money_format = if quotation.lowest { &formats.lowest_total_money } else { &formats.total_money };
+        sheet.write_number_with_format(row, first, quotation.lines as f64, &formats.total_integer)?;
+        sheet.write_blank(row, first + 1, &formats.total_label)?;
+        sheet.write_number_with_format(row, first + 2, number(quotation.contract_amount), money_format)?;
+        sheet.write_blank(row, first + 3, &formats.total_label)?;
+    }
+
+    sheet.set_freeze_panes(header_row + 1, 1)?;
+    Ok(())
+}
+
+// 벤더 비교표 엑셀 파일 내용
+pub fn build_comparison(comparison: &Comparison) -> Result<Vec<u8>, AppError> {
+    let formats = Formats::new();
+    let mut workbook = Workbook::new();
+    write_comparison_sheet(workbook.add_worksheet(), comparison, &formats).map_err(xlsx_error)?;
+    workbook.save_to_buffer().map_err(xlsx_error)
+}
+
+// 비교표를 엑셀로 저장하고 UI에서 쓸 비교 결과도 함께 반환
+#[command]
+pub async fn export_comparison_xlsx(
+    db: State<'_, Database>,
+    path: String,
+    quotation_ids: Vec<String>,
+) -> Result<Comparison, AppError> {
+    let comparison = comparison::load_comparison(&db, &quotation_ids)?;
+
+    tauri::async_runtime::spawn_blocking(move || {
+        let contents = build_comparison(&comparison)?;
+        write_atomic(Path::new(&path), &contents, 0)?;
+        Ok(comparison)
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use calamine::{Data, Reader, Xlsx};
+    use serde_json::json;
+    use std::io::{Cursor, Read};
+
+    fn column(id: i64, name: &str) -> Column {
+        Column {
+            id,
+            name: name.to_string(),
+            data_type: "string".to_string(),
+            required: false,
+            default_value: None,
+            created_at: None,
+        }
+    }
+
+    fn data_sheet(columns: &[Column]) -> Vec<Vec<Data>> {
+        let quotation: Quotation = serde_json::from_value(json!({
+            "id": 1,
+            "quotationId": "Q-1",
+            "quantity": 2,
+            "contractAmount": "1000",
+            "version": 1,
+        }))
+        .unwrap();
+        let contents = build_report(columns, &[quotation], &HashMap::new(), GroupBy::Category).unwrap();
+        let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(contents)).unwrap();
+        let range = workbook.worksheet_range(DATA_SHEET).unwrap();
+        range.rows().map(|row| row.to_vec()).collect()
+    }
+
+    #[test]
+    fn total_label_uses_first_text_column() {
+        let rows = data_sheet(&[column(1, "수량"), column(2, "계약금액"), column(3, "견적ID")]);
+        let last = rows.last().unwrap();
+        assert_eq!(last[2], Data::String("합계".to_string()));
+        assert_eq!(last[0], Data::Float(2.0));
+    }
+
+    #[test]
+    fn total_label_gets_own_column_when_all_columns_are_summed() {
+        let rows = data_sheet(&[column(1, "수량"), column(2, "계약금액")]);
+        assert_eq!(rows[0][0], Data::String("구분".to_string()));
+        assert_eq!(rows[0][1], Data::String("수량".to_string()));
+        let last = rows.last().unwrap();
+        assert_eq!(last[0], Data::String("합계".to_string()));
+        assert_eq!(last[2], Data::Float(1000.0));
+    }
+
+    fn quotation(id: i64, category: Option<&str>, quantity: i64, consumer: Option<&str>, contract: &str) -> Quotation {
+        serde_json::from_value(json!({
+            "id": id,
+            "quotationId": format!("Q-{}", id),
+            "category": category,
+            "quantity": quantity,
+            "consumerPrice": consumer,
+            "contractAmount": contract,
+            "version": 1,
+        }))
+        .unwrap()
+    }
+
+    fn sheet_xml(contents: &[u8], index: usize) -> String {
+        let mut archive = zip::ZipArchive::new(Cursor::new(contents)).unwrap();
+        let mut xml = String::new();
+        archive
+            .by_name(&format!("xl/worksheets/sheet{}.xml", index))
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+        xml
+    }
+
+    #[test]
+    fn report_groups_rows_by_category_with_subtotals() {
+        let columns = ["분류", "견적ID", "수량", "소비자가격", "계약금액", "절감액"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| column(i as i64 + 1, name))
+            .collect::<Vec<_>>();
+        let quotations = [
+            quotation(1, Some("HW"), 2, Some("1000"), "800"),
+            quotation(2, Some("SW"), 3, Some("2000"), "1500"),
+            quotation(3, Some("HW"), 1, Some("500"), "500"),
+            // 분류 없음, 소비자가격 없음 → 절감액 계산 안 됨
+            quotation(4, None, 1, None, "300"),
+        ];
+        let contents = build_report(&columns, &quotations, &HashMap::new(), GroupBy::Category).unwrap();
+        let mut workbook: Xlsx<_> = Xlsx::new(Cursor::new(contents.clone())).unwrap();
+
+        let text = |s: &str| Data::String(s.to_string());
+        let range = workbook.worksheet_range(DATA_SHEET).unwrap();
+        let rows: Vec<Vec<Data>> = range.rows().map(|row| row.to_vec()).collect();
+        let ids: Vec<Data> = rows.iter().map(|row| row[1].clone()).collect();
+        assert_eq!(
+            ids,
+            [
+                text("견적ID"),
+                text("Q-4"),
+                Data::Empty,
+                text("Q-1"),
+                text("Q-3"),
+                Data::Empty,
+                text("Q-2"),
+                Data::Empty,
+                Data::Empty,
+            ]
+        );
+        let totals = |row: usize| -> Vec<Data> { rows[row][2..].to_vec() };
+        assert_eq!(rows[2][0], text("(미지정) 소계"));
+        assert_eq!(totals(2), [Data::Float(1.0), Data::Float(0.0), Data::Float(300.0), Data::Float(0.0)]);
+        assert_eq!(rows[5][0], text("HW 소계"));
+        assert_eq!(totals(5), [Data::Float(3.0), Data::Float(1500.0), Data::Float(1300.0), Data::Float(200.0)]);
+        assert_eq!(rows[7][0], text("SW 소계"));
+        assert_eq!(totals(7), [Data::Float(3.0), Data::Float(2000.0), Data::Float(1500.0), Data::Float(500.0)]);
+        assert_eq!(rows[8][0], text("합계"));
+        assert_eq!(totals(8), [Data::Float(7.0), Data::Float(3500.0), Data::Float(3100.0), Data::Float(700.0)]);
+
+        // 소계는 그룹 데이터 행만, 합계는 데이터 시작부터 마지막 소계까지
+        let formulas = workbook.worksheet_formula(DATA_SHEET).unwrap();
+        let formula = |row: u32, col: u32| formulas.get_value((row, col)).cloned().unwrap_or_default();
+        assert_eq!(formula(2, 2), "SUBTOTAL(9,C2:C2)");
+        assert_eq!(formula(5, 3), "SUBTOTAL(9,D4:D5)");
+        assert_eq!(formula(7, 4), "SUBTOTAL(9,E7:E7)");
+        assert_eq!(formula(8, 5), "SUBTOTAL(9,F2:F8)");
+
+        // 머리글 고정, 자동 필터는 합계 행 제외
+        let xml = sheet_xml(&contents, 1);
+        assert!(xml.contains(r#"<pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>"#), "{}", xml);
+        assert!(xml.contains(r#"<autoFilter ref="A1:F8"/>"#), "{}", xml);
+
+        let range = workbook.worksheet_range(SUMMARY_SHEET).unwrap();
+        let summary: Vec<Vec<Data>> = range.rows().skip(4).take(4).map(|row| row.to_vec()).collect();
+        assert_eq!(
+            summary,
+            [
+                vec![text("(미지정)"), Data::Float(1.0), Data::Float(0.0), Data::Float(300.0), Data::Float(0.0), Data::Empty],
+                vec![text("HW"), Data::Float(2.0), Data::Float(1500.0), Data::Float(1300.0), Data::Float(200.0), Data::Float(13.33)],
+                vec![text("SW"), Data::Float(1.0), Data::Float(2000.0), Data::Float(1500.0), Data::Float(500.0), Data::Float(25.0)],
+                vec![text("전체"), Data::Float(4.0), Data::Float(3500.0), Data::Float(3100.0), Data::Float(700.0), Data::Float(20.0)],
+            ]
+        );
+    }
+}
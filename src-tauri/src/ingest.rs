@@ -0,0 +1,460 @@
+use glob::Pattern;
+use rayon::prelude::*;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use tauri::{command, AppHandle, Emitter, Manager, Runtime};
+use walkdir::WalkDir;
+
+use crate::error::{panic_message, AppError};
+use crate::mapping::{apply_mapping, has_errors};
+use crate::matcher::{parse_templates, rank_templates, ParsedTemplate, AUTO_MATCH_THRESHOLD};
+use crate::storage::quotes::NewQuote;
+use crate::storage::uploaded_files::{FileStatus, NewUploadedFile, UploadedFile};
+use crate::storage::Database;
+use crate::workbook::{load_workbook, FileType};
+
+pub const INGEST_STATUS_EVENT: &str = "ingest-status";
+
+// 기본으로 처리할 파일 패턴
+const DEFAULT_INCLUDE: &[&str] = &["*.xlsx", "*.xls", "*.csv", "*.pdf"];
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestOptions {
+    // 하위 폴더까지 포함
+    #[serde(default)]
+    pub recursive: bool,
+    // 파일명 또는 폴더 기준 상대 경로에 대한 glob 패턴 (예: "*.xlsx", "2025/**/*.csv")
+    #[serde(default)]
+    pub include: Vec<String>,
+    #[serde(default)]
+    pub exclude: Vec<String>,
+    // 자동 매핑 기준 점수 (기본값 AUTO_MATCH_THRESHOLD)
+    #[serde(default)]
+    pub min_score: Option<f64>,
+}
+
+// 파일별 처리 상태 이벤트 (uploaded_files.status 값과 동일)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestEvent {
+    pub path: String,
+    pub file_id: Option<i64>,
+    pub status: FileStatus,
+    pub template_id: Option<i64>,
+    pub score: Option<f64>,
+    pub row_count: Option<usize>,
+    // 실패 또는 수동 매핑이 필요한 이유
+    pub reason: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IngestSummary {
+    pub total: usize,
+    pub completed: usize,
+    pub failed: usize,
+    // 템플릿 점수가 낮아 수동 매핑이 필요한 파일
+    pub pending: usize,
+    pub files: Vec<IngestEvent>,
+}
+
+fn compile_patterns(patterns: &[String]) -> Result<Vec<Pattern>, AppError> {
+    patterns
+        .iter()
+        .map(|p| Pattern::new(p).map_err(|e| AppError::invalid(format!("잘못된 glob 패턴 '{}': {}", p, e))))
+        .collect()
+}
+
+fn matches_any(patterns: &[Pattern], relative: &Path) -> bool {
+    let name = relative.file_name().map(Path::new).unwrap_or(relative);
+    patterns
+        .iter()
+        .any(|p| p.matches_path(relative) || p.matches_path(name))
+}
+
+// 폴더에서 처리 대상 파일 목록 수집 (경로 순 정렬)
+pub fn collect_files(root: &Path, options: &IngestOptions) -> Result<Vec<PathBuf>, AppError> {
+    if !root.is_dir() {
+        return Err(AppError::FileNotFound {
+            path: Some(root.display().to_string()),
+            detail: "folder does not exist or is not a directory".to_string(),
+        });
+    }
+
+    let include = if options.include.is_empty() {
+        DEFAULT_INCLUDE.iter().map(|p| p.to_string()).collect()
+    } else {
+        options.include.clone()
+    };
+    let include = compile_patterns(&include)?;
+    let exclude = compile_patterns(&options.exclude)?;
+
+    let max_depth = if options.recursive { usize::MAX } else { 1 };
+    let mut files = Vec::new();
+    for entry in WalkDir::new(root).max_depth(max_depth) {
+        let entry = entry.map_err(|e| {
+            let path = e.path().unwrap_or(root).to_path_buf();
+            AppError::io(e.into(), path)
+        })?;
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry.path().strip_prefix(root).unwrap_or(entry.path());
+        if !matches_any(&include, relative) || matches_any(&exclude, relative) {
+            continue;
+        }
+        files.push(entry.into_path());
+    }
+
+    files.sort();
+    Ok(files)
+}
+
+// 파일 하나를 파싱하고 템플릿 감지 후 매핑 결과를 저장
+fn process_file(
+    db: &Database,
+    templates: &[ParsedTemplate],
+    path: &Path,
+    file_id: i64,
+    min_score: f64,
+) -> IngestEvent {
+    let mut event = IngestEvent {
+        path: path.to_string_lossy().to_string(),
+        file_id: Some(file_id),
+        status: FileStatus::Failed,
+        template_id: None,
+        score: None,
+        row_count: None,
+        reason: None,
+    };
+
+    if FileType::from_path(path).is_none() {
+        event.reason = Some("지원하지 않는 파일 형식입니다.".to_string());
+        return event;
+    }
+
+    let workbook = match load_workbook(path) {
+        Ok(workbook) => workbook,
+        Err(e) => {
+            event.reason = Some(e.to_string());
+            return event;
+        }
+    };
+
+    let Some(best) = rank_templates(&workbook, templates).into_iter().next() else {
+        event.status = FileStatus::Pending;
+        event.reason = Some("등록된 템플릿이 없어 수동 매핑이 필요합니다.".to_string());
+        return event;
+    };
+    event.score = Some(best.score);
+
+    if best.score < min_score {
+        event.status = FileStatus::Pending;
+        event.reason = Some(format!(
+            "일치하는 템플릿이 없어 수동 매핑이 필요합니다. (최고 후보: {}, 점수 {:.2})",
+            best.template_name, best.score
+        ));
+        return event;
+    }
+    event.template_id = Some(best.template_id);
+
+    let result = templates
+        .iter()
+        .find(|t| t.id == best.template_id)
+        .ok_or_else(|| "템플릿을 찾을 수 없습니다.".to_string())
+        .and_then(|t| apply_mapping(&workbook, &t.mapping));
+    let result = match result {
+        Ok(result) => result,
+        Err(e) => {
+            event.reason = Some(e);
+            return event;
+        }
+    };
+
+    let quote = NewQuote {
+        file_id,
+        template_id: best.template_id,
+        data: json!({
+            "rows": result.rows,
+            "fields": result.fields,
+            "diagnostics": result.diagnostics,
+        }),
+    };
+    if let Err(e) = db.create_quote(&quote) {
+        event.reason = Some(e.to_string());
+        return event;
+    }
+
+    event.status = FileStatus::Completed;
+    event.row_count = Some(result.rows.len());
+    if has_errors(&result.diagnostics) {
+        event.reason = Some("일부 셀에서 매핑 오류가 발생했습니다.".to_string());
+    }
+    event
+}
+
+// 파서(calamine, pdf-extract 등)가 패닉을 일으켜도 다른 파일은 계속 처리하도록 파일 단위로 격리.
+// 패닉이 난 파일은 실패로 기록한다.
+fn process_isolated(path: &Path, file_id: i64, process: impl FnOnce() -> IngestEvent) -> IngestEvent {
+    panic::catch_unwind(AssertUnwindSafe(process)).unwrap_or_else(|payload| {
+        let message = panic_message(payload.as_ref());
+        log::error!("{}: 파일 처리 중 패닉이 발생했습니다: {}", path.display(), message);
+        IngestEvent {
+            path: path.to_string_lossy().to_string(),
+            file_id: Some(file_id),
+            status: FileStatus::Failed,
+            template_id: None,
+            score: None,
+            row_count: None,
+            reason: Some(format!("파일을 처리하는 중 내부 오류가 발생했습니다. ({})", message)),
+        }
+    })
+}
+
+fn record_status(db: &Database, file: &UploadedFile, event: &IngestEvent) -> Result<(), AppError> {
+    db.update_uploaded_file(
+        file.id,
+        &NewUploadedFile {
+            filename: file.filename.clone(),
+            size: file.size,
+            status: event.status,
+            template_id: event.template_id,
+        },
+    )
+    .map(|_| ())
+}
+
+// 폴더 일괄 처리. 모든 파일을 pending으로 등록한 뒤 병렬로 처리하며
+// 상태가 바뀔 때마다 emit을 호출한다.
+pub fn ingest(
+    db: &Database,
+    root: &Path,
+    options: &IngestOptions,
+    emit: &(dyn Fn(&IngestEvent) + Sync),
+) -> Result<IngestSummary, AppError> {
+    let files = collect_files(root, options)?;
+    let min_score = options.min_score.unwrap_or(AUTO_MATCH_THRESHOLD);
+    // 템플릿은 파일마다 읽지 않고 처음에 한 번만 읽고 파싱
+    let templates = parse_templates(&db.list_templates()?);
+
+    let mut registered = Vec::new();
+    for path in files {
+        let size = path.metadata().map(|m| m.len() as i64).unwrap_or(0);
+        let filename = path
+            .strip_prefix(root)
+            .unwrap_or(&path)
+            .to_string_lossy()
+            .to_string();
+        let file = db.create_uploaded_file(&NewUploadedFile {
+            filename,
+            size,
+            status: FileStatus::Pending,
+            template_id: None,
+        })?;
+        emit(&IngestEvent {
+            path: path.to_string_lossy().to_string(),
+            file_id: Some(file.id),
+            status: FileStatus::Pending,
+            template_id: None,
+            score: None,
+            row_count: None,
+            reason: None,
+        });
+        registered.push((path, file));
+    }
+
+    let results: Vec<IngestEvent> = registered
+        .par_iter()
+        .map(|(path, file)| {
+            let processing = IngestEvent {
+                path: path.to_string_lossy().to_string(),
+                file_id: Some(file.id),
+                status: FileStatus::Processing,
+                template_id: None,
+                score: None,
+                row_count: None,
+                reason: None,
+            };
+            // 중간 상태 기록 실패는 최종 상태 기록에서 다시 드러나므로 처리는 계속함
+            if let Err(e) = record_status(db, file, &processing) {
+                log::warn!("{}: 처리 중 상태를 기록하지 못했습니다: {}", processing.path, e);
+            }
+            emit(&processing);
+
+            let mut event = process_isolated(path, file.id, || {
+                process_file(db, &templates, path, file.id, min_score)
+            });
+            if let Err(e) = record_status(db, file, &event) {
+                event.status = FileStatus::Failed;
+                event.reason = Some(e.to_string());
+            }
+            emit(&event);
+            event
+        })
+        .collect();
+
+    let count = |status: FileStatus| results.iter().filter(|e| e.status == status).count();
+    Ok(IngestSummary {
+        total: results.len(),
+        completed: count(FileStatus::Completed),
+        failed: count(FileStatus::Failed),
+        pending: count(FileStatus::Pending),
+        files: results,
+    })
+}
+
+#[command]
+pub async fn ingest_folder<R: Runtime>(
+    app: AppHandle<R>,
+    path: String,
+    options: Option<IngestOptions>,
+) -> Result<IngestSummary, AppError> {
+    let options = options.unwrap_or_default();
+    tauri::async_runtime::spawn_blocking(move || {
+        let db = app.state::<Database>();
+        let emit = |event: &IngestEvent| {
+            let _ = app.emit(INGEST_STATUS_EVENT, event.clone());
+        };
+        ingest(&db, Path::new(&path), &options, &emit)
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::templates::NewTemplate;
+    use std::fs;
+    use std::sync::Mutex;
+
+    fn touch(root: &Path, relative: &str, contents: &str) {
+        let path = root.join(relative);
+        fs::create_dir_all(path.parent().unwrap()).unwrap();
+        fs::write(path, contents).unwrap();
+    }
+
+    fn names(root: &Path, options: &IngestOptions) -> Vec<String> {
+        collect_files(root, options)
+            .unwrap()
+            .iter()
+            .map(|path| path.strip_prefix(root).unwrap().to_string_lossy().replace('\\', "/"))
+            .collect()
+    }
+
+    fn folder() -> tempfile::TempDir {
+        let dir = tempfile::tempdir().unwrap();
+        for name in ["a.xlsx", "b.csv", "notes.txt", "2025/c.pdf", "2025/old/d.xls", "2025/e.csv"] {
+            touch(dir.path(), name, "");
+        }
+        dir
+    }
+
+    #[test]
+    fn collects_flat_or_recursive() {
+        let dir = folder();
+        assert_eq!(names(dir.path(), &IngestOptions::default()), vec!["a.xlsx", "b.csv"]);
+
+        let recursive = IngestOptions {
+            recursive: true,
+            ..Default::default()
+        };
+        assert_eq!(
+            names(dir.path(), &recursive),
+            vec!["2025/c.pdf", "2025/e.csv", "2025/old/d.xls", "a.xlsx", "b.csv"]
+        );
+    }
+
+    #[test]
+    fn applies_include_and_exclude_globs() {
+        let dir = folder();
+        // 패턴은 파일명 또는 폴더 기준 상대 경로와 비교
+        let options = IngestOptions {
+            recursive: true,
+            include: vec!["*.csv".into(), "2025/old/*".into()],
+            exclude: vec!["2025/e.*".into()],
+            ..Default::default()
+        };
+        assert_eq!(names(dir.path(), &options), vec!["2025/old/d.xls", "b.csv"]);
+
+        let options = IngestOptions {
+            include: vec!["[".into()],
+            ..Default::default()
+        };
+        assert_eq!(collect_files(dir.path(), &options).unwrap_err().code(), "INVALID_INPUT");
+        let missing = dir.path().join("missing");
+        let err = collect_files(&missing, &IngestOptions::default()).unwrap_err();
+        assert_eq!(err.code(), "FILE_NOT_FOUND");
+    }
+
+    #[test]
+    fn records_status_transitions() {
+        let db = Database::open_in_memory().unwrap();
+        db.create_template(&NewTemplate {
+            name: "기본 양식".into(),
+            mapping_data: r#"{"skipRows": 1, "columns": [
+                {"name": "견적ID", "sourceColumn": "A"},
+                {"name": "솔루션", "sourceColumn": "B"},
+                {"name": "카테고리", "sourceColumn": "C"}
+            ]}"#
+            .into(),
+        })
+        .unwrap();
+
+        let dir = tempfile::tempdir().unwrap();
+        touch(dir.path(), "a.csv", "견적ID,솔루션,카테고리\nQ-1,백신,보안\nQ-2,방화벽,보안\n");
+        touch(dir.path(), "b.csv", "품목,수량\n서버,1\n");
+        touch(dir.path(), "c.xlsx", "not a workbook");
+
+        let events = Mutex::new(Vec::new());
+        let emit = |event: &IngestEvent| {
+            let name = Path::new(&event.path).file_name().unwrap().to_string_lossy().to_string();
+            events.lock().unwrap().push((name, event.status));
+        };
+        let summary = ingest(&db, dir.path(), &IngestOptions::default(), &emit).unwrap();
+        assert_eq!((summary.total, summary.completed, summary.pending, summary.failed), (3, 1, 1, 1));
+        assert_eq!(summary.files[0].row_count, Some(2));
+
+        // 파일마다 pending -> processing -> 최종 상태 순으로 알림
+        let events = events.into_inner().unwrap();
+        let finals = [
+            ("a.csv", FileStatus::Completed),
+            ("b.csv", FileStatus::Pending),
+            ("c.xlsx", FileStatus::Failed),
+        ];
+        for (name, last) in finals {
+            let statuses: Vec<FileStatus> = events
+                .iter()
+                .filter(|(n, _)| n == name)
+                .map(|(_, status)| *status)
+                .collect();
+            assert_eq!(statuses, vec![FileStatus::Pending, FileStatus::Processing, last], "{name}");
+        }
+
+        let stored: Vec<(String, FileStatus)> = db
+            .list_uploaded_files()
+            .unwrap()
+            .into_iter()
+            .map(|file| (file.filename, file.status))
+            .collect();
+        assert_eq!(
+            stored,
+            vec![
+                ("a.csv".to_string(), FileStatus::Completed),
+                ("b.csv".to_string(), FileStatus::Pending),
+                ("c.xlsx".to_string(), FileStatus::Failed),
+            ]
+        );
+        assert_eq!(db.list_quotes(None).unwrap().len(), 1);
+    }
+
+    #[test]
+    fn panicking_file_is_recorded_as_failed() {
+        let event = process_isolated(Path::new("broken.pdf"), 7, || panic!("malformed xref"));
+        assert_eq!(event.status, FileStatus::Failed);
+        assert_eq!(event.file_id, Some(7));
+        assert!(event.reason.unwrap().contains("malformed xref"));
+    }
+}
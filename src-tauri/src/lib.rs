@@ -1,16 +1,122 @@
-#[cfg_attr(mobile, tauri::mobile_entry_point)]
-pub fn run() {
-  tauri::Builder::default()
+use tauri::{AppHandle, Manager, Runtime};
+
+pub mod analysis;
+pub mod atomic_file;
+pub mod commands;
+pub mod dates;
+pub mod error;
+pub mod export;
+pub mod ingest;
+pub mod mapping;
+pub mod matcher;
+pub mod money;
+pub mod pdf;
+pub mod storage;
+pub mod text_encoding;
+pub mod workbook;
+
+// 앱 데이터 디렉터리의 DB를 열어 마이그레이션을 적용하고 상태로 등록.
+// 더 새로운 앱이 만든 DB이면 오류를 반환해서 시작을 중단한다.
+// 테스트처럼 빌더에서 DB를 미리 등록했으면 그대로 사용한다.
+pub fn init_database<R: Runtime>(app: &AppHandle<R>) -> Result<(), error::AppError> {
+  if app.try_state::<storage::Database>().is_some() {
+    return Ok(());
+  }
+  let db = storage::Database::open_in_app_dir(app)?;
+  app.manage(db);
+  Ok(())
+}
+
+// 로그 플러그인, 상태(다운로드 레지스트리, 시작 훅에서 여는 DB), 커맨드를 모두 등록한 빌더.
+// 데스크톱/모바일 진입점과 통합 테스트(tests/app.rs)가 같은 구성을 쓴다.
+// 설정 상태와 작업 큐는 두지 않는다: 컬럼/템플릿 같은 설정은 DB에 저장하고,
+// 일괄 작업(ingest_folder, 다운로드)은 커맨드 호출 단위로 실행되어 공유할 큐가 없다.
+pub fn builder<R: Runtime>(builder: tauri::Builder<R>) -> tauri::Builder<R> {
+  builder
+    .plugin(
+      tauri_plugin_log::Builder::default()
+        .level(if cfg!(debug_assertions) {
+          log::LevelFilter::Debug
+        } else {
+          log::LevelFilter::Info
+        })
+        .build(),
+    )
+    .manage(commands::DownloadRegistry::default())
     .setup(|app| {
-      if cfg!(debug_assertions) {
-        app.handle().plugin(
-          tauri_plugin_log::Builder::default()
-            .level(log::LevelFilter::Info)
-            .build(),
-        )?;
-      }
+      // 로컬 SQLite DB 연결 및 마이그레이션
+      init_database(app.handle())?;
       Ok(())
     })
+    .invoke_handler(tauri::generate_handler![
+      commands::download_and_save_file,
+      commands::cancel_download,
+      commands::open_folder,
+      commands::get_version,
+      commands::save_file_content,
+      commands::read_file_content,
+      workbook::parse_workbook,
+      pdf::extract_pdf,
+      mapping::apply_template,
+      mapping::pattern::test_patterns,
+      matcher::compute_fingerprint,
+      matcher::match_templates,
+      ingest::ingest_folder,
+      analysis::totals::quotation_totals,
+      analysis::totals::category_totals,
+      analysis::savings::quotation_savings,
+      analysis::trends::quotation_trends,
+      analysis::comparison::compare_quotations,
+      export::xlsx::export_report_xlsx,
+      export::xlsx::export_comparison_xlsx,
+      export::pdf::export_report_pdf,
+      export::csv::export_quotations_csv,
+      storage::columns::list_columns,
+      storage::columns::get_column,
+      storage::columns::create_column,
+      storage::columns::update_column,
+      storage::columns::delete_column,
+      storage::templates::list_templates,
+      storage::templates::get_template,
+      storage::templates::create_template,
+      storage::templates::update_template,
+      storage::templates::delete_template,
+      storage::quotations::list_quotations,
+      storage::quotations::get_quotation,
+      storage::quotations::create_quotation,
+      storage::quotations::update_quotation,
+      storage::quotations::delete_quotation,
+      storage::history::list_quotation_history,
+      storage::history::list_quotation_versions,
+      storage::history::get_quotation_version,
+      storage::history::diff_quotation_versions,
+      storage::history::restore_quotation_version,
+      storage::categories::list_categories,
+      storage::categories::get_category,
+      storage::categories::create_category,
+      storage::categories::update_category,
+      storage::categories::delete_category,
+      storage::categories::list_category_items,
+      storage::categories::list_item_categories,
+      storage::categories::add_category_items,
+      storage::categories::remove_category_items,
+      storage::categories::apply_category_rules,
+      storage::uploaded_files::list_uploaded_files,
+      storage::uploaded_files::get_uploaded_file,
+      storage::uploaded_files::create_uploaded_file,
+      storage::uploaded_files::update_uploaded_file,
+      storage::uploaded_files::delete_uploaded_file,
+      storage::quotes::list_quotes,
+      storage::quotes::get_quote,
+      storage::quotes::create_quote,
+      storage::quotes::update_quote,
+      storage::quotes::delete_quote
+    ])
+}
+
+#[cfg_attr(mobile, tauri::mobile_entry_point)]
+pub fn run() {
+  builder(tauri::Builder::default())
     .run(tauri::generate_context!())
-    .expect("error while running tauri application");
+    .expect("오류: Tauri 앱을 실행하는 데 실패했습니다.");
 }
@@ -0,0 +1,323 @@
+use regex::Regex;
+use serde::{Deserialize, Serialize};
+
+use super::{column_index, column_letters};
+use crate::text_encoding::normalize_key;
+use crate::workbook::{Sheet, Workbook};
+
+// 라벨 기준 셀 추출 규칙.
+// 예) "합계금액" 셀을 찾아 오른쪽으로 두 칸 떨어진 값을 가져옴
+//     { "text": "합계금액", "offset": { "rows": 0, "cols": 2 } }
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnchorRule {
+    // 공백을 무시하고 셀 전체가 일치해야 하는 라벨
+    #[serde(default)]
+    pub text: Option<String>,
+    // 셀 텍스트에 대한 정규식
+    #[serde(default)]
+    pub pattern: Option<String>,
+    // 검색할 시트. 없으면 템플릿 시트 또는 모든 시트
+    #[serde(default)]
+    pub sheet: Option<String>,
+    // 검색 범위 (예: "A1:H40")
+    #[serde(default)]
+    pub range: Option<String>,
+    // 라벨 셀 기준 상대 위치
+    #[serde(default)]
+    pub offset: Option<Offset>,
+    // 라벨 다음의 비어 있지 않은 셀 방향 (offset이 없을 때 기본값 right)
+    #[serde(default)]
+    pub next: Option<Direction>,
+    // 같은 라벨이 여러 번 나올 때 몇 번째를 쓸지 (1부터, 행 우선 순서)
+    #[serde(default)]
+    pub occurrence: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, Default, Deserialize)]
+pub struct Offset {
+    #[serde(default)]
+    pub rows: i64,
+    #[serde(default)]
+    pub cols: i64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Direction {
+    Right,
+    Below,
+}
+
+// 검색 범위 (0부터 시작, 끝 포함)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CellRange {
+    pub start_row: usize,
+    pub start_col: usize,
+    pub end_row: usize,
+    pub end_col: usize,
+}
+
+impl CellRange {
+    fn contains(&self, row: usize, col: usize) -> bool {
+        (self.start_row..=self.end_row).contains(&row) && (self.start_col..=self.end_col).contains(&col)
+    }
+}
+
+// 라벨과 값 셀의 위치 (UI 표시용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AnchorHit {
+    pub field: String,
+    pub sheet: String,
+    pub anchor_cell: String,
+    pub value_cell: String,
+}
+
+pub struct Located<'a> {
+    pub sheet: &'a Sheet,
+    pub anchor: (usize, usize),
+    pub value: (usize, usize),
+}
+
+// "B3" -> (2, 1)
+pub fn parse_cell_ref(reference: &str) -> Option<(usize, usize)> {
+    let reference = reference.trim().replace('$', "");
+    let split = reference.find(|c: char| c.is_ascii_digit())?;
+    let (letters, digits) = reference.split_at(split);
+    let col = column_index(letters)?;
+    let row: usize = digits.parse().ok()?;
+    row.checked_sub(1).map(|row| (row, col))
+}
+
+// "A1:H40" -> CellRange. 단일 셀도 허용
+pub fn parse_range(range: &str) -> Option<CellRange> {
+    let mut parts = range.split(':');
+    let start = parse_cell_ref(parts.next()?)?;
+    let end = match parts.next() {
+        Some(end) => parse_cell_ref(end)?,
+        None => start,
+    };
+    if parts.next().is_some() {
+        return None;
+    }
+
+    Some(CellRange {
+        start_row: start.0.min(end.0),
+        start_col: start.1.min(end.1),
+        end_row: start.0.max(end.0),
+        end_col: start.1.max(end.1),
+    })
+}
+
+pub fn cell_ref(row: usize, col: usize) -> String {
+    format!("{}{}", column_letters(col), row + 1)
+}
+
+enum Matcher {
+    Text(String),
+    Pattern(Regex),
+}
+
+impl Matcher {
+    fn new(rule: &AnchorRule) -> Result<Self, String> {
+        match (&rule.pattern, &rule.text) {
+            (Some(pattern), _) => Regex::new(pattern)
+                .map(Matcher::Pattern)
+                .map_err(|e| format!("잘못된 정규식 '{}': {}", pattern, e)),
+            (None, Some(text)) => Ok(Matcher::Text(normalize_key(text))),
+            (None, None) => Err("anchor에는 text 또는 pattern이 필요합니다.".to_string()),
+        }
+    }
+
+    fn is_match(&self, value: &str) -> bool {
+        match self {
+            Matcher::Text(label) => normalize_key(value) == *label,
+            Matcher::Pattern(regex) => regex.is_match(value),
+        }
+    }
+}
+
+// 라벨 셀을 찾고 규칙에 따라 값 셀 위치를 계산
+pub fn locate<'a>(
+    workbook: &'a Workbook,
+    rule: &AnchorRule,
+    default_sheet: Option<&str>,
+) -> Result<Located<'a>, String> {
+    let matcher = Matcher::new(rule)?;
+    let range = match &rule.range {
+        Some(range) => {
+            Some(parse_range(range).ok_or_else(|| format!("범위를 해석할 수 없습니다: {}", range))?)
+        }
+        None => None,
+    };
+
+    let sheets: Vec<&Sheet> = match rule.sheet.as_deref().or(default_sheet) {
+        Some(name) => vec![workbook
+            .sheet(name)
+            .ok_or_else(|| format!("시트를 찾을 수 없습니다: {}", name))?],
+        None => workbook.sheets.iter().collect(),
+    };
+
+    let mut remaining = rule.occurrence.unwrap_or(1).max(1);
+    for sheet in sheets {
+        for (row, cells) in sheet.rows.iter().enumerate() {
+            for (col, cell) in cells.iter().enumerate() {
+                if range.is_some_and(|r| !r.contains(row, col)) {
+                    continue;
+                }
+                let Some(text) = cell.as_text() else {
+                    continue;
+                };
+                if !matcher.is_match(&text) {
+                    continue;
+                }
+                remaining -= 1;
+                if remaining > 0 {
+                    continue;
+                }
+
+                let value = value_position(sheet, rule, row, col)?;
+                return Ok(Located {
+                    sheet,
+                    anchor: (row, col),
+                    value,
+                });
+            }
+        }
+    }
+
+    let label = rule.pattern.as_deref().or(rule.text.as_deref()).unwrap_or_default();
+    Err(format!("라벨을 찾을 수 없습니다: {}", label))
+}
+
+fn value_position(
+    sheet: &Sheet,
+    rule: &AnchorRule,
+    row: usize,
+    col: usize,
+) -> Result<(usize, usize), String> {
+    if let Some(offset) = rule.offset {
+        let target_row = row as i64 + offset.rows;
+        let target_col = col as i64 + offset.cols;
+        if target_row < 0 || target_col < 0 {
+            return Err(format!(
+                "라벨 위치 {}에서 오프셋이 시트 범위를 벗어납니다.",
+                cell_ref(row, col)
+            ));
+        }
+        return Ok((target_row as usize, target_col as usize));
+    }
+
+    // 병합 셀 때문에 라벨 바로 옆이 비어 있는 경우가 많아 다음 값 셀을 찾음
+    match rule.next.unwrap_or(Direction::Right) {
+        Direction::Right => (col + 1..sheet.column_count)
+            .find(|c| !sheet.cell(row, *c).is_empty())
+            .map(|c| (row, c)),
+        Direction::Below => (row + 1..sheet.row_count)
+            .find(|r| !sheet.cell(*r, col).is_empty())
+            .map(|r| (r, col)),
+    }
+    .ok_or_else(|| format!("라벨 {} 다음에 값이 없습니다.", cell_ref(row, col)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::workbook::{Cell, FileType};
+
+    // 양식형 견적서: 라벨 옆이 병합 셀이라 비어 있고, "금액" 라벨이 두 번 나옴
+    fn form() -> Workbook {
+        let rows = vec![
+            vec!["견 적 서", "", "", ""],
+            vec!["견적번호", "", "Q-2025-001", ""],
+            vec!["금액", "1,000", "", ""],
+            vec!["공급가액", "", "", ""],
+            vec!["", "", "", ""],
+            vec!["9,900,000", "", "금액", "2,000"],
+        ];
+        let rows = rows
+            .into_iter()
+            .map(|row| {
+                row.into_iter()
+                    .map(|s| if s.is_empty() { Cell::Empty } else { Cell::String(s.to_string()) })
+                    .collect()
+            })
+            .collect();
+        Workbook {
+            path: "form.xlsx".to_string(),
+            file_type: FileType::Xlsx,
+            sheets: vec![Sheet::new("견적서".to_string(), rows)],
+            pdf: None,
+        }
+    }
+
+    fn rule(json: &str) -> AnchorRule {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn hit(json: &str) -> Result<(String, String), String> {
+        let workbook = form();
+        let located = locate(&workbook, &rule(json), None)?;
+        let (anchor, value) = (located.anchor, located.value);
+        Ok((cell_ref(anchor.0, anchor.1), cell_ref(value.0, value.1)))
+    }
+
+    #[test]
+    fn parses_cell_references() {
+        assert_eq!(parse_cell_ref("B3"), Some((2, 1)));
+        assert_eq!(parse_cell_ref("$AA$10"), Some((9, 26)));
+        assert_eq!(parse_cell_ref("A0"), None);
+        assert_eq!(parse_cell_ref("12"), None);
+        assert_eq!(
+            parse_range("H40:A1"),
+            Some(CellRange { start_row: 0, start_col: 0, end_row: 39, end_col: 7 })
+        );
+        assert_eq!(parse_range("C2").map(|r| (r.start_row, r.end_col)), Some((1, 2)));
+        assert_eq!(parse_range("A1:B2:C3"), None);
+    }
+
+    #[test]
+    fn next_non_empty_cell_skips_merged_blanks() {
+        // 공백을 무시하고 라벨 비교
+        assert_eq!(hit(r#"{"text": "견적 번호"}"#), Ok(("A2".into(), "C2".into())));
+        assert_eq!(hit(r#"{"text": "공급가액", "next": "below"}"#), Ok(("A4".into(), "A6".into())));
+        assert!(hit(r#"{"text": "공급가액"}"#).unwrap_err().contains("A4"));
+    }
+
+    #[test]
+    fn offset_is_relative_to_label() {
+        assert_eq!(
+            hit(r#"{"text": "견적번호", "offset": {"cols": 1}}"#),
+            Ok(("A2".into(), "B2".into()))
+        );
+        assert_eq!(
+            hit(r#"{"text": "공급가액", "offset": {"rows": 2}}"#),
+            Ok(("A4".into(), "A6".into()))
+        );
+        assert!(hit(r#"{"text": "견적번호", "offset": {"cols": -1}}"#).is_err());
+    }
+
+    #[test]
+    fn nth_occurrence_and_range() {
+        assert_eq!(hit(r#"{"text": "금액"}"#), Ok(("A3".into(), "B3".into())));
+        assert_eq!(hit(r#"{"text": "금액", "occurrence": 2}"#), Ok(("C6".into(), "D6".into())));
+        assert_eq!(hit(r#"{"text": "금액", "range": "B4:D6"}"#), Ok(("C6".into(), "D6".into())));
+        assert!(hit(r#"{"text": "금액", "occurrence": 3}"#).is_err());
+        assert!(hit(r#"{"text": "금액", "range": "A1:"}"#).unwrap_err().contains("범위"));
+    }
+
+    #[test]
+    fn pattern_matches_cell_text() {
+        assert_eq!(hit(r#"{"pattern": "^견적\\s*번호$"}"#), Ok(("A2".into(), "C2".into())));
+        assert!(hit(r#"{"pattern": "("}"#).unwrap_err().contains("정규식"));
+        assert!(hit("{}").is_err());
+    }
+
+    #[test]
+    fn missing_label_or_sheet_is_reported() {
+        assert_eq!(hit(r#"{"text": "납기"}"#), Err("라벨을 찾을 수 없습니다: 납기".to_string()));
+        let err = hit(r#"{"text": "금액", "sheet": "Sheet2"}"#).unwrap_err();
+        assert_eq!(err, "시트를 찾을 수 없습니다: Sheet2");
+    }
+}
@@ -0,0 +1,202 @@
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::dates::{self, excel_serial_to_date, DateOrder};
+use crate::money;
+use crate::workbook::{format_number, Cell};
+
+// 컬럼 정의의 데이터 타입 (columns.data_type 과 같은 값)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum DataType {
+    #[default]
+    #[serde(alias = "text")]
+    String,
+    Number,
+    Date,
+    #[serde(alias = "bool")]
+    Boolean,
+    // 기간 -> 개월 수 (예: 무상 유지보수 기간)
+    #[serde(alias = "months")]
+    Period,
+}
+
+// order는 일/월 순서가 모호한 날짜에 적용할 기본 순서
+pub fn coerce(cell: &Cell, data_type: DataType, order: DateOrder) -> Result<Value, String> {
+    match data_type {
+        DataType::String => Ok(cell
+            .as_text()
+            .map(|s| Value::String(s.trim().to_string()))
+            .unwrap_or(Value::Null)),
+        DataType::Number => to_number(cell),
+        DataType::Date => to_date(cell, order),
+        DataType::Boolean => to_bool(cell),
+        DataType::Period => to_months(cell, order),
+    }
+}
+
+fn to_number(cell: &Cell) -> Result<Value, String> {
+    match cell {
+        Cell::Number(n) => Ok(number_value(*n)),
+        // 원/만원/억 단위, 통화 기호, 부가세 표기가 섞인 금액 문자열
+        Cell::String(s) => money::parse_amount(s).map(|amount| amount.to_value()),
+        other => Err(format!(
+            "숫자로 변환할 수 없습니다: {}",
+            other.as_text().unwrap_or_default()
+        )),
+    }
+}
+
+fn number_value(n: f64) -> Value {
+    serde_json::Number::from_f64(n)
+        .map(Value::Number)
+        .unwrap_or(Value::Null)
+}
+
+fn to_date(cell: &Cell, order: DateOrder) -> Result<Value, String> {
+    match cell {
+        Cell::Date(s) => Ok(Value::String(s.chars().take(10).collect())),
+        // 서식이 빠진 엑셀 날짜 일련번호
+        Cell::Number(n) => excel_serial_to_date(*n)
+            .map(|d| Value::String(d.format("%Y-%m-%d").to_string()))
+            .ok_or_else(|| format!("날짜로 변환할 수 없습니다: {}", format_number(*n))),
+        Cell::String(s) => dates::parse_date(s, order)
+            .map(|d| Value::String(d.format("%Y-%m-%d").to_string())),
+        other => Err(format!(
+            "날짜로 변환할 수 없습니다: {}",
+            other.as_text().unwrap_or_default()
+        )),
+    }
+}
+
+fn to_months(cell: &Cell, order: DateOrder) -> Result<Value, String> {
+    match cell {
+        Cell::Number(n) if *n >= 0.0 && n.fract() == 0.0 => Ok(Value::from(*n as u64)),
+        Cell::String(s) => dates::parse_period(s, order).map(|period| Value::from(period.months)),
+        other => Err(format!(
+            "기간으로 변환할 수 없습니다: {}",
+            other.as_text().unwrap_or_default()
+        )),
+    }
+}
+
+fn to_bool(cell: &Cell) -> Result<Value, String> {
+    match cell {
+        Cell::Bool(b) => Ok(Value::Bool(*b)),
+        Cell::Number(n) => Ok(Value::Bool(*n != 0.0)),
+        Cell::String(s) => match s.trim().to_lowercase().as_str() {
+            "true" | "y" | "yes" | "o" | "예" | "유" | "포함" => Ok(Value::Bool(true)),
+            "false" | "n" | "no" | "x" | "아니오" | "무" | "미포함" => Ok(Value::Bool(false)),
+            _ => Err(format!("참/거짓으로 변환할 수 없습니다: {}", s)),
+        },
+        other => Err(format!(
+            "참/거짓으로 변환할 수 없습니다: {}",
+            other.as_text().unwrap_or_default()
+        )),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::mapping::{apply_mapping, MappingResult, Severity, TemplateMapping};
+    use crate::workbook::{Cell, FileType, Sheet, Workbook};
+    use serde_json::{json, Value};
+
+    const COLUMNS: &str = r#"[
+        {"name": "계약일", "sourceColumn": "A", "dataType": "date"},
+        {"name": "무상기간", "sourceColumn": "B", "dataType": "period"},
+        {"name": "부가세포함", "sourceColumn": "C", "dataType": "boolean"}
+    ]"#;
+
+    fn text(value: &str) -> Cell {
+        Cell::String(value.to_string())
+    }
+
+    fn apply(rows: Vec<Vec<Cell>>, date_order: Option<&str>) -> MappingResult {
+        let workbook = Workbook {
+            path: "quote.xlsx".to_string(),
+            file_type: FileType::Xlsx,
+            sheets: vec![Sheet::new("견적".to_string(), rows)],
+            pdf: None,
+        };
+        let order = date_order.map(|o| format!(r#""dateOrder": "{}", "#, o)).unwrap_or_default();
+        let json = format!(r#"{{{}"columns": {}}}"#, order, COLUMNS);
+        let mapping = TemplateMapping::from_json(&json).unwrap();
+        apply_mapping(&workbook, &mapping).unwrap()
+    }
+
+    fn values(result: &MappingResult, row: usize) -> (Value, Value, Value) {
+        let values = &result.rows[row].values;
+        (values["계약일"].clone(), values["무상기간"].clone(), values["부가세포함"].clone())
+    }
+
+    #[test]
+    fn converts_dates_periods_and_booleans() {
+        let result = apply(
+            vec![
+                vec![text("2025년 6월 26일"), text("계약일로부터 12개월"), text("포함")],
+                // 서식이 빠진 엑셀 날짜 일련번호와 숫자 기간/참거짓
+                vec![Cell::Number(45834.0), Cell::Number(24.0), Cell::Number(0.0)],
+                vec![
+                    Cell::Date("2025-06-26T09:30:00".to_string()),
+                    text("2년 6개월"),
+                    Cell::Bool(true),
+                ],
+            ],
+            None,
+        );
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert_eq!(values(&result, 0), (json!("2025-06-26"), json!(12), json!(true)));
+        assert_eq!(values(&result, 1), (json!("2025-06-26"), json!(24), json!(false)));
+        assert_eq!(values(&result, 2), (json!("2025-06-26"), json!(30), json!(true)));
+        assert!(result.rows.iter().all(|row| row.valid));
+    }
+
+    #[test]
+    fn template_date_order_resolves_two_digit_dates() {
+        let rows = || vec![vec![text("25/06/26"), text("12"), text("Y")]];
+
+        // 일-월-연: 25일 6월 2026년
+        let result = apply(rows(), Some("dmy"));
+        assert!(result.diagnostics.is_empty(), "{:?}", result.diagnostics);
+        assert_eq!(values(&result, 0).0, json!("2026-06-25"));
+
+        // 기본(연-월-일)
+        assert_eq!(values(&apply(rows(), None), 0).0, json!("2025-06-26"));
+
+        // 월-일-연으로는 25월이 없어 연-월-일과 일-월-연 중 하나를 고를 수 없음
+        let result = apply(rows(), Some("mdy"));
+        assert_eq!(result.diagnostics.len(), 1);
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!(diagnostic.field, "계약일");
+        assert_eq!((diagnostic.row, diagnostic.column.as_deref()), (Some(1), Some("A")));
+        assert!(diagnostic.message.contains("모호한 날짜"), "{}", diagnostic.message);
+        assert_eq!(values(&result, 0).0, Value::Null);
+        assert!(!result.rows[0].valid);
+    }
+
+    #[test]
+    fn ambiguous_or_invalid_cells_are_reported_per_cell() {
+        let result = apply(
+            vec![
+                vec![text("03/04/2025"), text("12개월"), text("예")],
+                vec![text("2025-06-26"), text("미정"), text("글쎄")],
+            ],
+            None,
+        );
+        let reported: Vec<(usize, &str)> = result
+            .diagnostics
+            .iter()
+            .map(|d| (d.row.unwrap(), d.column.as_deref().unwrap()))
+            .collect();
+        assert_eq!(reported, [(1, "A"), (2, "B"), (2, "C")]);
+        assert!(result.diagnostics[0].message.contains("dateOrder"));
+        assert_eq!(values(&result, 0).1, json!(12));
+        assert!(result.rows.iter().all(|row| !row.valid));
+
+        // dateOrder를 지정하면 같은 값도 해석됨
+        let result = apply(vec![vec![text("03/04/2025"), text("12"), text("N")]], Some("mdy"));
+        assert_eq!(values(&result, 0), (json!("2025-03-04"), json!(12), json!(false)));
+    }
+}
@@ -0,0 +1,701 @@
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+use std::collections::HashMap;
+use std::path::Path;
+use tauri::command;
+
+use crate::dates::DateOrder;
+use crate::error::AppError;
+use crate::matcher::Fingerprint;
+use crate::money::{self, Amount, Currency};
+use crate::workbook::{load_workbook, Cell, FileType, Sheet, Workbook};
+
+pub mod anchor;
+pub mod coerce;
+pub mod pattern;
+pub mod region;
+
+pub use anchor::{AnchorHit, AnchorRule};
+pub use coerce::DataType;
+pub use pattern::{PatternChain, PatternMatch};
+pub use region::BoundingBox;
+
+// TemplateEditor.tsx 가 만드는 매핑 JSON
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateMapping {
+    #[serde(default)]
+    pub file_type: Option<String>,
+    // 매핑 전에 건너뛸 행 수 (헤더 등)
+    #[serde(default)]
+    pub skip_rows: usize,
+    // 대상 시트 이름. 없으면 첫 번째 시트
+    #[serde(default)]
+    pub sheet: Option<String>,
+    #[serde(default)]
+    pub columns: Vec<ColumnRule>,
+    // 일/월 순서가 모호한 날짜의 해석 순서 (ymd, dmy, mdy / 기본값 ymd)
+    #[serde(default)]
+    pub date_order: DateOrder,
+    // 자동 템플릿 감지용 파일 구조 (compute_fingerprint 결과)
+    #[serde(default)]
+    pub fingerprint: Option<Fingerprint>,
+    // PDF 파일에만 적용하는 설정
+    #[serde(default)]
+    pub pdf_settings: Option<PdfSettings>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfSettings {
+    // 필드 이름 -> 문서 텍스트에 대한 정규식 (또는 대체 체인)
+    #[serde(default)]
+    pub patterns: HashMap<String, PatternChain>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ColumnRule {
+    pub name: String,
+    // 엑셀/CSV의 열 문자 (A, B, ..., AA).
+    // PDF에서는 표 시트의 열 문자이거나, 열 문자가 아니면 정규식으로 사용
+    #[serde(default)]
+    pub source_column: Option<String>,
+    // 라벨 기준 추출 규칙. 지정하면 행마다가 아니라 문서에서 값 하나를 추출
+    #[serde(default)]
+    pub anchor: Option<AnchorRule>,
+    // 문서 텍스트에 대한 정규식 또는 대체 체인 (문서 단위 값)
+    #[serde(default)]
+    pub pattern: Option<PatternChain>,
+    // PDF 영역 (문서 단위 값)
+    #[serde(default)]
+    pub bbox: Option<BoundingBox>,
+    #[serde(default)]
+    pub required: bool,
+    #[serde(default)]
+    pub data_type: DataType,
+    #[serde(default)]
+    pub default_value: Option<String>,
+}
+
+impl TemplateMapping {
+    // 오류에는 JSON 안의 위치를 담아 어느 규칙이 잘못됐는지 보여 줌
+    pub fn from_json(json: &str) -> Result<Self, AppError> {
+        let mut deserializer = serde_json::Deserializer::from_str(json);
+        let mapping = serde_path_to_error::deserialize(&mut deserializer).map_err(|e| AppError::Template {
+            path: e.path().to_string(),
+            detail: e.inner().to_string(),
+        })?;
+        deserializer.end().map_err(|e| AppError::Template {
+            path: ".".to_string(),
+            detail: e.to_string(),
+        })?;
+        Ok(mapping)
+    }
+
+    // 규칙에 적용할 정규식. rule.pattern, PDF이면 pdfSettings.patterns,
+    // PDF 템플릿이면 열 문자가 아닌 sourceColumn 순으로 찾음
+    pub fn text_pattern(&self, rule: &ColumnRule, is_pdf: bool) -> Option<PatternChain> {
+        if let Some(pattern) = &rule.pattern {
+            return Some(pattern.clone());
+        }
+        if is_pdf {
+            let setting = self
+                .pdf_settings
+                .as_ref()
+                .and_then(|settings| settings.patterns.get(&rule.name));
+            if let Some(pattern) = setting {
+                return Some(pattern.clone());
+            }
+        }
+        if is_pdf || self.file_type.as_deref() == Some("pdf") {
+            return rule
+                .source_column
+                .as_deref()
+                .filter(|source| column_index(source).is_none())
+                .map(PatternChain::from);
+        }
+        None
+    }
+}
+
+impl ColumnRule {
+    // 행마다가 아니라 문서에서 값 하나를 추출하는 규칙
+    pub fn is_document_rule(&self) -> bool {
+        self.anchor.is_some() || self.pattern.is_some() || self.bbox.is_some()
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Severity {
+    Error,
+    Warning,
+}
+
+// 셀 단위 진단 정보 (row는 엑셀과 같은 1부터 시작하는 행 번호)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Diagnostic {
+    pub severity: Severity,
+    pub field: String,
+    pub row: Option<usize>,
+    pub column: Option<String>,
+    pub message: String,
+}
+
+impl Diagnostic {
+    pub fn error(field: &str, row: Option<usize>, column: Option<String>, message: String) -> Self {
+        Diagnostic {
+            severity: Severity::Error,
+            field: field.to_string(),
+            row,
+            column,
+            message,
+        }
+    }
+
+    pub fn warning(
+        field: &str,
+        row: Option<usize>,
+        column: Option<String>,
+        message: String,
+    ) -> Self {
+        Diagnostic {
+            severity: Severity::Warning,
+            field: field.to_string(),
+            row,
+            column,
+            message,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MappedRow {
+    // 원본 시트의 행 번호 (1부터)
+    pub source_row: usize,
+    pub values: Map<String, Value>,
+    // 필수값 누락이나 변환 오류가 없으면 true
+    pub valid: bool,
+}
+
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct MappingResult {
+    pub sheet: Option<String>,
+    pub rows: Vec<MappedRow>,
+    // anchor 규칙으로 추출한 문서 단위 값
+    pub fields: Map<String, Value>,
+    pub anchors: Vec<AnchorHit>,
+    // 정규식 규칙이 일치한 텍스트 위치
+    pub matches: Vec<PatternMatch>,
+    // 단위/통화/부가세 표기가 있던 금액 (number 타입)
+    pub amounts: Vec<AmountNote>,
+    pub diagnostics: Vec<Diagnostic>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AmountNote {
+    pub field: String,
+    pub row: Option<usize>,
+    pub column: Option<String>,
+    #[serde(flatten)]
+    pub amount: Amount,
+}
+
+// 열 문자(A, B, ..., Z, AA, ...)를 0부터 시작하는 열 번호로 변환
+pub fn column_index(letters: &str) -> Option<usize> {
+    let letters = letters.trim();
+    if letters.is_empty() || !letters.chars().all(|c| c.is_ascii_alphabetic()) {
+        return None;
+    }
+
+    let mut index = 0usize;
+    for c in letters.chars() {
+        let digit = (c.to_ascii_uppercase() as u8 - b'A') as usize + 1;
+        index = index.checked_mul(26)?.checked_add(digit)?;
+    }
+    Some(index - 1)
+}
+
+// 0부터 시작하는 열 번호를 열 문자로 변환
+pub fn column_letters(mut index: usize) -> String {
+    let mut letters = Vec::new();
+    loop {
+        letters.push((b'A' + (index % 26) as u8) as char);
+        if index < 26 {
+            break;
+        }
+        index = index / 26 - 1;
+    }
+    letters.iter().rev().collect()
+}
+
+pub fn select_sheet<'a>(workbook: &'a Workbook, name: Option<&str>) -> Result<&'a Sheet, String> {
+    match name {
+        Some(name) => workbook
+            .sheet(name)
+            .ok_or_else(|| format!("시트를 찾을 수 없습니다: {}", name)),
+        None => workbook
+            .sheets
+            .first()
+            .ok_or_else(|| "시트가 없는 파일입니다.".to_string()),
+    }
+}
+
+// 표 형식 매핑: skipRows 이후 각 행을 레코드 하나로 변환
+pub fn apply_mapping(workbook: &Workbook, mapping: &TemplateMapping) -> Result<MappingResult, String> {
+    let sheet = select_sheet(workbook, mapping.sheet.as_deref())?;
+    let mut result = MappingResult {
+        sheet: Some(sheet.name.clone()),
+        ..Default::default()
+    };
+
+    let is_pdf = workbook.file_type == FileType::Pdf;
+    apply_anchors(workbook, mapping, &mut result);
+    apply_text_rules(workbook, mapping, &mut result);
+
+    // 열 문자를 먼저 해석하고 잘못된 규칙은 진단으로 남김
+    let mut resolved = Vec::new();
+    // 원본 열이 지정되지 않은 필수 항목. 모든 행에서 값이 비므로 행을 무효로 표시
+    let mut unmapped_required = Vec::new();
+    for rule in &mapping.columns {
+        if rule.is_document_rule() || mapping.text_pattern(rule, is_pdf).is_some() {
+            continue;
+        }
+        let Some(source) = rule.source_column.as_deref().filter(|s| !s.trim().is_empty()) else {
+            if rule.required {
+                result.diagnostics.push(Diagnostic::error(
+                    &rule.name,
+                    None,
+                    None,
+                    "필수 항목에 원본 열이 지정되지 않았습니다.".to_string(),
+                ));
+                unmapped_required.push(rule);
+            }
+            continue;
+        };
+        match column_index(source) {
+            Some(col) => resolved.push((rule, col)),
+            None => result.diagnostics.push(Diagnostic::error(
+                &rule.name,
+                None,
+                Some(source.to_string()),
+                format!("열 문자를 해석할 수 없습니다: {}", source),
+            )),
+        }
+    }
+
+    if resolved.is_empty() {
+        return Ok(result);
+    }
+
+    for row in mapping.skip_rows..sheet.row_count {
+        // 매핑 대상 열이 모두 비어 있는 행은 건너뜀
+        if resolved.iter().all(|(_, col)| sheet.cell(row, *col).is_empty()) {
+            continue;
+        }
+
+        let source_row = row + 1;
+        let errors_before = result.diagnostics.len();
+        let mut values = Map::new();
+
+        for (rule, col) in &resolved {
+            let letters = column_letters(*col);
+            let value = map_cell(
+                rule,
+                sheet.cell(row, *col),
+                Some(source_row),
+                Some(letters),
+                mapping.date_order,
+                &mut result,
+            );
+            values.insert(rule.name.clone(), value.unwrap_or(Value::Null));
+        }
+        for rule in &unmapped_required {
+            values.insert(rule.name.clone(), Value::Null);
+        }
+
+        result.rows.push(MappedRow {
+            source_row,
+            values,
+            valid: unmapped_required.is_empty() && !has_errors(&result.diagnostics[errors_before..]),
+        });
+    }
+
+    Ok(result)
+}
+
+// 양식형 견적서: 라벨 위치를 기준으로 문서 단위 값을 추출
+fn apply_anchors(workbook: &Workbook, mapping: &TemplateMapping, result: &mut MappingResult) {
+    for rule in &mapping.columns {
+        let Some(anchor_rule) = &rule.anchor else {
+            continue;
+        };
+
+        let located = match anchor::locate(workbook, anchor_rule, mapping.sheet.as_deref()) {
+            Ok(located) => located,
+            Err(message) => {
+                missing_field(rule, message, result);
+                continue;
+            }
+        };
+
+        let (row, col) = located.value;
+        let value = map_cell(
+            rule,
+            located.sheet.cell(row, col),
+            Some(row + 1),
+            Some(column_letters(col)),
+            mapping.date_order,
+            result,
+        );
+        result.fields.insert(rule.name.clone(), value.unwrap_or(Value::Null));
+        result.anchors.push(AnchorHit {
+            field: rule.name.clone(),
+            sheet: located.sheet.name.clone(),
+            anchor_cell: anchor::cell_ref(located.anchor.0, located.anchor.1),
+            value_cell: anchor::cell_ref(row, col),
+        });
+    }
+}
+
+// 정규식/영역 규칙: PDF 또는 문서 전체 텍스트에서 값을 추출
+fn apply_text_rules(workbook: &Workbook, mapping: &TemplateMapping, result: &mut MappingResult) {
+    let is_pdf = workbook.file_type == FileType::Pdf;
+    let mut text: Option<String> = None;
+
+    for rule in &mapping.columns {
+        if rule.anchor.is_some() {
+            continue;
+        }
+        let extracted = if let Some(bbox) = &rule.bbox {
+            region::extract(workbook, bbox)
+        } else if let Some(chain) = mapping.text_pattern(rule, is_pdf) {
+            let text = text.get_or_insert_with(|| pattern::document_text(workbook));
+            pattern::find(text, &chain, &rule.name).map(|found| {
+                let value = found.value.clone();
+                result.matches.push(found);
+                value
+            })
+        } else {
+            continue;
+        };
+
+        match extracted {
+            Ok(value) => {
+                let cell = Cell::String(value);
+                let value = map_cell(rule, &cell, None, None, mapping.date_order, result);
+                result.fields.insert(rule.name.clone(), value.unwrap_or(Value::Null));
+            }
+            Err(message) => missing_field(rule, message, result),
+        }
+    }
+}
+
+// 문서 단위 값을 찾지 못함. 필수 항목이면 오류, 아니면 경고
+fn missing_field(rule: &ColumnRule, message: String, result: &mut MappingResult) {
+    let severity = if rule.required {
+        Severity::Error
+    } else {
+        Severity::Warning
+    };
+    result.diagnostics.push(Diagnostic {
+        severity,
+        field: rule.name.clone(),
+        row: None,
+        column: None,
+        message,
+    });
+    result.fields.insert(rule.name.clone(), Value::Null);
+}
+
+pub fn has_errors(diagnostics: &[Diagnostic]) -> bool {
+    diagnostics.iter().any(|d| d.severity == Severity::Error)
+}
+
+// 셀 하나를 규칙의 dataType으로 변환. 값이 없거나 변환에 실패하면 None
+pub fn map_cell(
+    rule: &ColumnRule,
+    cell: &Cell,
+    row: Option<usize>,
+    column: Option<String>,
+    order: DateOrder,
+    result: &mut MappingResult,
+) -> Option<Value> {
+    let default_cell;
+    let cell = match (&rule.default_value, cell.is_empty()) {
+        (Some(default), true) => {
+            default_cell = Cell::String(default.clone());
+            &default_cell
+        }
+        _ => cell,
+    };
+
+    if cell.is_empty() {
+        if rule.required {
+            result.diagnostics.push(Diagnostic::error(
+                &rule.name,
+                row,
+                column,
+                "필수 항목이 비어 있습니다.".to_string(),
+            ));
+        }
+        return None;
+    }
+
+    // 금액 문자열은 단위/통화/부가세 표기를 함께 기록
+    let coerced = match (rule.data_type, cell) {
+        (DataType::Number, Cell::String(text)) => money::parse_amount(text).map(|amount| {
+            let value = amount.to_value();
+            if amount.currency.is_some_and(|c| c != Currency::Krw) {
+                result.diagnostics.push(Diagnostic::warning(
+                    &rule.name,
+                    row,
+                    column.clone(),
+                    format!("원화가 아닌 금액입니다: {}", amount.raw),
+                ));
+            }
+            if !amount.is_plain() {
+                result.amounts.push(AmountNote {
+                    field: rule.name.clone(),
+                    row,
+                    column: column.clone(),
+                    amount,
+                });
+            }
+            value
+        }),
+        (data_type, cell) => coerce::coerce(cell, data_type, order),
+    };
+
+    match coerced {
+        Ok(value) => Some(value),
+        Err(message) => {
+            result.diagnostics.push(Diagnostic::error(&rule.name, row, column, message));
+            None
+        }
+    }
+}
+
+pub fn apply_template_file(path: &Path, template_json: &str) -> Result<MappingResult, AppError> {
+    let mapping = TemplateMapping::from_json(template_json)?;
+    let workbook = load_workbook(path)?;
+    apply_mapping(&workbook, &mapping).map_err(AppError::invalid)
+}
+
+#[command]
+pub async fn apply_template(file_path: String, template_json: String) -> Result<MappingResult, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        apply_template_file(Path::new(&file_path), &template_json)
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::money::Vat;
+    use serde_json::json;
+
+    fn workbook(rows: Vec<Vec<&str>>) -> Workbook {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|s| Cell::String(s.to_string())).collect())
+            .collect();
+        Workbook {
+            path: "quote.xlsx".to_string(),
+            file_type: FileType::Xlsx,
+            sheets: vec![Sheet::new("견적".to_string(), rows)],
+            pdf: None,
+        }
+    }
+
+    fn apply(rows: Vec<Vec<&str>>, mapping_json: &str) -> MappingResult {
+        let mapping = TemplateMapping::from_json(mapping_json).unwrap();
+        apply_mapping(&workbook(rows), &mapping).unwrap()
+    }
+
+    #[test]
+    fn converts_column_letters() {
+        assert_eq!(column_index("A"), Some(0));
+        assert_eq!(column_index("z"), Some(25));
+        assert_eq!(column_index("AA"), Some(26));
+        assert_eq!(column_index(" AZ "), Some(51));
+        assert_eq!(column_index("BA"), Some(52));
+        assert_eq!(column_index(""), None);
+        assert_eq!(column_index("A1"), None);
+        for index in [0, 25, 26, 51, 52, 701, 702] {
+            assert_eq!(column_index(&column_letters(index)), Some(index));
+        }
+        assert_eq!(column_letters(701), "ZZ");
+        assert_eq!(column_letters(702), "AAA");
+    }
+
+    #[test]
+    fn skips_header_rows_and_blank_rows() {
+        let result = apply(
+            vec![
+                vec!["견적서", ""],
+                vec!["품목", "수량"],
+                vec!["서버", "2"],
+                vec!["", ""],
+                vec!["스위치", "1,000"],
+            ],
+            r#"{"skipRows": 2, "columns": [
+                {"name": "주요제품", "sourceColumn": "A"},
+                {"name": "수량", "sourceColumn": "B", "dataType": "number"}
+            ]}"#,
+        );
+        assert_eq!(result.sheet.as_deref(), Some("견적"));
+        let rows: Vec<_> = result
+            .rows
+            .iter()
+            .map(|r| (r.source_row, r.values["주요제품"].clone(), r.values["수량"].clone()))
+            .collect();
+        assert_eq!(
+            rows,
+            vec![
+                (3, Value::from("서버"), Value::from(2)),
+                (5, Value::from("스위치"), Value::from(1000)),
+            ]
+        );
+        assert!(result.diagnostics.is_empty());
+    }
+
+    #[test]
+    fn reads_columns_past_z() {
+        let mut row = vec![""; 27];
+        row[0] = "Q-1";
+        row[26] = "비고";
+        let result = apply(
+            vec![row],
+            r#"{"columns": [
+                {"name": "견적ID", "sourceColumn": "A"},
+                {"name": "특이사항", "sourceColumn": "AA"},
+                {"name": "잘못된 열", "sourceColumn": "A1"}
+            ]}"#,
+        );
+        assert_eq!(result.rows[0].values["특이사항"], "비고");
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].field, "잘못된 열");
+        assert_eq!(result.diagnostics[0].column.as_deref(), Some("A1"));
+    }
+
+    #[test]
+    fn reports_missing_required_values() {
+        let result = apply(
+            vec![vec!["Q-1", "백신"], vec!["", "방화벽"]],
+            r#"{"columns": [
+                {"name": "견적ID", "sourceColumn": "A", "required": true},
+                {"name": "솔루션", "sourceColumn": "B"},
+                {"name": "벤더", "sourceColumn": "C", "defaultValue": "미정", "required": true}
+            ]}"#,
+        );
+        assert!(result.rows[0].valid);
+        assert_eq!(result.rows[0].values["벤더"], "미정");
+        assert!(!result.rows[1].valid);
+        assert_eq!(result.diagnostics.len(), 1);
+        let diagnostic = &result.diagnostics[0];
+        assert_eq!(diagnostic.severity, Severity::Error);
+        assert_eq!(diagnostic.field, "견적ID");
+        assert_eq!((diagnostic.row, diagnostic.column.as_deref()), (Some(2), Some("A")));
+    }
+
+    #[test]
+    fn required_rule_without_source_column_is_an_error() {
+        let result = apply(
+            vec![vec!["Q-1"]],
+            r#"{"columns": [
+                {"name": "견적ID", "sourceColumn": "A"},
+                {"name": "계약금액", "required": true},
+                {"name": "비고"}
+            ]}"#,
+        );
+        assert_eq!(result.diagnostics.len(), 1);
+        assert_eq!(result.diagnostics[0].field, "계약금액");
+        assert_eq!(result.diagnostics[0].row, None);
+        assert!(has_errors(&result.diagnostics));
+        assert!(!result.rows[0].valid);
+        assert_eq!(result.rows[0].values["계약금액"], Value::Null);
+        assert!(!result.rows[0].values.contains_key("비고"));
+    }
+
+    #[test]
+    fn template_json_errors_report_location() {
+        let error = TemplateMapping::from_json(
+            r#"{"columns": [{"name": "견적ID"}, {"name": "수량", "dataType": "bogus"}]}"#,
+        )
+        .unwrap_err();
+        assert_eq!(error.code(), "TEMPLATE_ERROR");
+        assert_eq!(error.context().path.as_deref(), Some("columns[1].dataType"));
+        assert!(error.detail().contains("bogus"), "{}", error.detail());
+
+        let error = TemplateMapping::from_json(r#"{"skipRows": 1"#).unwrap_err();
+        assert_eq!(error.code(), "TEMPLATE_ERROR");
+        assert!(error.detail().contains("EOF"), "{}", error.detail());
+
+        let error = TemplateMapping::from_json(r#"{"skipRows": 1} []"#).unwrap_err();
+        assert_eq!((error.code(), error.context().path), ("TEMPLATE_ERROR", Some(".".to_string())));
+
+        let error = apply_template_file(Path::new("missing.xlsx"), "{").unwrap_err();
+        assert_eq!(error.code(), "TEMPLATE_ERROR");
+    }
+
+    #[test]
+    fn records_amount_notes_and_rejects_ambiguous_amounts() {
+        let result = apply(
+            vec![
+                vec!["서버", "1억 2,000만원 (VAT 별도)"],
+                vec!["라이선스", "$1,000"],
+                vec!["설치", "1,000~2,000"],
+                vec!["기타", "500000"],
+            ],
+            r#"{"columns": [
+                {"name": "품목", "sourceColumn": "A"},
+                {"name": "계약금액", "sourceColumn": "B", "dataType": "number"}
+            ]}"#,
+        );
+        let amounts: Vec<&Value> = result.rows.iter().map(|row| &row.values["계약금액"]).collect();
+        assert_eq!(amounts, [&json!(120_000_000), &json!(1_000), &Value::Null, &json!(500_000)]);
+        let valid: Vec<bool> = result.rows.iter().map(|row| row.valid).collect();
+        assert_eq!(valid, [true, true, false, true]);
+
+        // 단위/통화/부가세 표기가 있던 금액만 기록 (숫자만 있는 금액은 제외)
+        let notes: Vec<_> = result
+            .amounts
+            .iter()
+            .map(|n| {
+                let amount = &n.amount;
+                (n.row, n.column.as_deref(), amount.unit.as_str(), amount.currency, amount.vat)
+            })
+            .collect();
+        assert_eq!(
+            notes,
+            [
+                (Some(1), Some("B"), "억원", Some(Currency::Krw), Vat::Excluded),
+                (Some(2), Some("B"), "원", Some(Currency::Usd), Vat::Unspecified),
+            ]
+        );
+        assert_eq!(result.amounts[0].field, "계약금액");
+        let note = serde_json::to_value(&result.amounts[0]).unwrap();
+        assert_eq!(note["raw"], "1억 2,000만원 (VAT 별도)");
+        assert_eq!(note["vat"], "excluded");
+
+        // 원화가 아닌 금액은 경고, 해석할 수 없는 금액은 행/열이 있는 오류
+        let diagnostics: Vec<(Severity, Option<usize>, Option<&str>)> = result
+            .diagnostics
+            .iter()
+            .map(|d| (d.severity, d.row, d.column.as_deref()))
+            .collect();
+        assert_eq!(
+            diagnostics,
+            [(Severity::Warning, Some(2), Some("B")), (Severity::Error, Some(3), Some("B"))]
+        );
+        assert!(result.diagnostics[0].message.contains("$1,000"));
+        assert_eq!(result.diagnostics[1].field, "계약금액");
+    }
+}
@@ -0,0 +1,393 @@
+use regex::{Regex, RegexBuilder};
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+use tauri::command;
+
+use super::TemplateMapping;
+use crate::error::AppError;
+use crate::workbook::{load_workbook, FileType, Workbook};
+
+// 정규식 추출 규칙. 문자열 하나 또는 옵션이 있는 객체로 지정
+// 예) "견적번호\\s*:?\\s*(\\S+)"
+//     { "regex": "(?P<금액>[\\d,]+)원", "nth": -1 }
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternRule {
+    pub regex: String,
+    // 값으로 쓸 캡처 그룹 (이름 또는 번호). 없으면 필드 이름과 같은 그룹,
+    // "value" 그룹, 첫 번째 그룹, 전체 일치 순으로 사용
+    #[serde(default)]
+    pub group: Option<GroupRef>,
+    // ^, $가 문서 전체가 아니라 줄 단위로 일치
+    #[serde(default)]
+    pub multiline: bool,
+    // .이 줄바꿈과도 일치
+    #[serde(default)]
+    pub dot_all: bool,
+    // 몇 번째 일치를 쓸지 (1부터, 음수는 뒤에서부터)
+    #[serde(default)]
+    pub nth: Option<i64>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum GroupRef {
+    Index(usize),
+    Name(String),
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PatternSpec {
+    Regex(String),
+    Rule(PatternRule),
+}
+
+// 대체 체인. 배열이면 앞에서부터 순서대로 시도해서 처음 일치한 결과를 사용.
+// 문자열 두 개짜리 배열이 [regex, group] 형태의 규칙 하나로 읽히지 않도록 배열을 먼저 시도
+#[derive(Debug, Clone, Deserialize)]
+#[serde(untagged)]
+pub enum PatternChain {
+    Many(Vec<PatternSpec>),
+    One(PatternSpec),
+}
+
+impl PatternSpec {
+    pub fn rule(&self) -> PatternRule {
+        match self {
+            PatternSpec::Regex(regex) => PatternRule {
+                regex: regex.clone(),
+                group: None,
+                multiline: false,
+                dot_all: false,
+                nth: None,
+            },
+            PatternSpec::Rule(rule) => rule.clone(),
+        }
+    }
+}
+
+impl PatternChain {
+    pub fn rules(&self) -> Vec<PatternRule> {
+        match self {
+            PatternChain::One(spec) => vec![spec.rule()],
+            PatternChain::Many(specs) => specs.iter().map(PatternSpec::rule).collect(),
+        }
+    }
+}
+
+impl From<&str> for PatternChain {
+    fn from(regex: &str) -> Self {
+        PatternChain::One(PatternSpec::Regex(regex.to_string()))
+    }
+}
+
+// 필드 값이 일치한 텍스트 위치. start/end는 문서 텍스트 기준 글자 위치 (끝 미포함)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternMatch {
+    pub field: String,
+    // 체인에서 일치한 패턴 순서 (0부터)
+    pub index: usize,
+    pub pattern: String,
+    pub start: usize,
+    pub end: usize,
+    // 1부터 시작하는 줄 번호
+    pub line: usize,
+    // 정규식 전체가 일치한 텍스트
+    pub matched: String,
+    pub value: String,
+}
+
+// 체인의 패턴 하나를 시도한 결과 (test_patterns 응답용)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternAttempt {
+    pub pattern: String,
+    pub matched: bool,
+    pub error: Option<String>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldTrace {
+    pub field: String,
+    pub attempts: Vec<PatternAttempt>,
+    pub matched: Option<PatternMatch>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PatternTestResult {
+    // 패턴을 적용한 문서 텍스트 (start/end 기준)
+    pub text: String,
+    pub fields: Vec<FieldTrace>,
+}
+
+// 정규식 규칙을 적용할 문서 텍스트.
+// PDF는 줄 단위 텍스트, 스프레드시트는 셀을 탭으로 연결한 행을 줄로 사용
+pub fn document_text(workbook: &Workbook) -> String {
+    if let Some(document) = &workbook.pdf {
+        return document.text();
+    }
+
+    workbook
+        .sheets
+        .iter()
+        .flat_map(|sheet| sheet.rows.iter())
+        .map(|row| {
+            row.iter()
+                .map(|cell| cell.as_text().unwrap_or_default())
+                .collect::<Vec<_>>()
+                .join("\t")
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+}
+
+fn compile(rule: &PatternRule) -> Result<Regex, String> {
+    RegexBuilder::new(&rule.regex)
+        .multi_line(rule.multiline)
+        .dot_matches_new_line(rule.dot_all)
+        .build()
+        .map_err(|e| format!("잘못된 정규식 '{}': {}", rule.regex, e))
+}
+
+// 값으로 쓸 캡처 그룹 번호
+fn group_index(regex: &Regex, rule: &PatternRule, field: &str) -> Result<usize, String> {
+    let named = |name: &str| regex.capture_names().position(|n| n == Some(name));
+
+    match &rule.group {
+        Some(GroupRef::Index(index)) if *index < regex.captures_len() => Ok(*index),
+        Some(GroupRef::Index(index)) => Err(format!("정규식에 {}번 그룹이 없습니다: {}", index, rule.regex)),
+        Some(GroupRef::Name(name)) => {
+            named(name).ok_or_else(|| format!("정규식에 '{}' 그룹이 없습니다: {}", name, rule.regex))
+        }
+        None => Ok(named(field)
+            .or_else(|| named("value"))
+            .unwrap_or(if regex.captures_len() > 1 { 1 } else { 0 })),
+    }
+}
+
+// 패턴 하나를 적용. 일치하지 않으면 None
+pub fn match_rule(
+    text: &str,
+    rule: &PatternRule,
+    field: &str,
+    index: usize,
+) -> Result<Option<PatternMatch>, String> {
+    let regex = compile(rule)?;
+    let group = group_index(&regex, rule, field)?;
+
+    let captures = match rule.nth.unwrap_or(1) {
+        0 => return Err("nth는 0이 될 수 없습니다. (1부터, 음수는 뒤에서부터)".to_string()),
+        nth if nth > 0 => regex.captures_iter(text).nth(nth as usize - 1),
+        nth => {
+            let all: Vec<_> = regex.captures_iter(text).collect();
+            let from_end = nth.unsigned_abs() as usize;
+            all.len().checked_sub(from_end).and_then(|i| all.into_iter().nth(i))
+        }
+    };
+    let Some(captures) = captures else {
+        return Ok(None);
+    };
+    // 선택한 그룹이 이번 일치에 참여하지 않았거나 값이 비어 있으면 일치하지 않은 것으로 봄
+    let Some(value) = captures.get(group).filter(|m| !m.as_str().trim().is_empty()) else {
+        return Ok(None);
+    };
+
+    // 앞뒤 공백을 뺀 값의 위치
+    let trimmed = value.as_str().trim();
+    let leading = value.as_str().len() - value.as_str().trim_start().len();
+    let start = value.start() + leading;
+    let end = start + trimmed.len();
+
+    Ok(Some(PatternMatch {
+        field: field.to_string(),
+        index,
+        pattern: rule.regex.clone(),
+        start: text[..start].chars().count(),
+        end: text[..end].chars().count(),
+        line: text[..start].matches('\n').count() + 1,
+        matched: captures[0].to_string(),
+        value: trimmed.to_string(),
+    }))
+}
+
+// 체인을 순서대로 시도해서 처음 일치한 결과. 잘못된 정규식은 바로 오류
+pub fn find(text: &str, chain: &PatternChain, field: &str) -> Result<PatternMatch, String> {
+    let rules = chain.rules();
+    for (index, rule) in rules.iter().enumerate() {
+        if let Some(found) = match_rule(text, rule, field, index)? {
+            return Ok(found);
+        }
+    }
+
+    let patterns: Vec<&str> = rules.iter().map(|rule| rule.regex.as_str()).collect();
+    Err(format!("패턴과 일치하는 텍스트가 없습니다: {}", patterns.join(" | ")))
+}
+
+// 체인의 모든 시도를 기록 (처음 일치한 뒤에는 중단)
+pub fn trace(text: &str, chain: &PatternChain, field: &str) -> FieldTrace {
+    let mut attempts = Vec::new();
+    let mut matched = None;
+
+    for (index, rule) in chain.rules().iter().enumerate() {
+        let result = match_rule(text, rule, field, index);
+        attempts.push(PatternAttempt {
+            pattern: rule.regex.clone(),
+            matched: matches!(result, Ok(Some(_))),
+            error: result.as_ref().err().cloned(),
+        });
+        if let Ok(Some(found)) = result {
+            matched = Some(found);
+            break;
+        }
+    }
+
+    FieldTrace {
+        field: field.to_string(),
+        attempts,
+        matched,
+    }
+}
+
+// 템플릿의 정규식 규칙을 샘플 문서(파일 또는 텍스트)에 적용해서 필드별 일치 위치를 반환
+pub fn test_patterns_in(
+    mapping: &TemplateMapping,
+    workbook: Option<&Workbook>,
+    text: Option<String>,
+) -> Result<PatternTestResult, AppError> {
+    let is_pdf = match workbook {
+        Some(workbook) => workbook.file_type == FileType::Pdf,
+        None => mapping.file_type.as_deref() == Some("pdf"),
+    };
+    let text = match (text, workbook) {
+        (Some(text), _) => text,
+        (None, Some(workbook)) => document_text(workbook),
+        (None, None) => return Err(AppError::invalid("샘플 파일 또는 텍스트가 필요합니다.")),
+    };
+
+    let fields = mapping
+        .columns
+        .iter()
+        .filter_map(|rule| {
+            let chain = mapping.text_pattern(rule, is_pdf)?;
+            Some(trace(&text, &chain, &rule.name))
+        })
+        .collect();
+
+    Ok(PatternTestResult { text, fields })
+}
+
+#[command]
+pub async fn test_patterns(
+    template_json: String,
+    file_path: Option<String>,
+    text: Option<String>,
+) -> Result<PatternTestResult, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        let mapping = TemplateMapping::from_json(&template_json)?;
+        let workbook = match &file_path {
+            Some(path) => Some(load_workbook(Path::new(path))?),
+            None => None,
+        };
+        test_patterns_in(&mapping, workbook.as_ref(), text)
+    })
+    .await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const TEXT: &str = "견적번호: Q-2025-001\n품목 서버 금액 1,000원\n품목 설치 금액 500원\n합계 1,500원";
+
+    fn chain(json: &str) -> PatternChain {
+        serde_json::from_str(json).unwrap()
+    }
+
+    fn value(json: &str, field: &str) -> Result<String, String> {
+        find(TEXT, &chain(json), field).map(|found| found.value)
+    }
+
+    #[test]
+    fn picks_capture_group() {
+        // 필드 이름과 같은 그룹 > "value" 그룹 > 첫 번째 그룹 > 전체 일치
+        assert_eq!(value(r#""(?P<견적번호>Q-[\\d-]+)""#, "견적번호").unwrap(), "Q-2025-001");
+        assert_eq!(value(r#""(품목) (?P<value>\\S+)""#, "품목").unwrap(), "서버");
+        assert_eq!(value(r#""견적번호:\\s*(\\S+)""#, "견적번호").unwrap(), "Q-2025-001");
+        assert_eq!(value(r#""Q-\\d+""#, "견적번호").unwrap(), "Q-2025");
+        assert_eq!(value(r#"{"regex": "(품목) (\\S+)", "group": 2}"#, "x").unwrap(), "서버");
+        assert_eq!(value(r#"{"regex": "(?P<a>\\S+)원", "group": "a"}"#, "x").unwrap(), "1,000");
+
+        let err = value(r#"{"regex": "(품목)", "group": "금액"}"#, "x").unwrap_err();
+        assert!(err.contains("'금액' 그룹이 없습니다"), "{err}");
+        assert!(value(r#"{"regex": "(품목)", "group": 3}"#, "x").is_err());
+    }
+
+    #[test]
+    fn reports_match_position() {
+        let found = find(TEXT, &chain(r#""금액 (?P<금액>[\\d,]+)원""#), "금액").unwrap();
+        assert_eq!(found.line, 2);
+        assert_eq!((found.matched.as_str(), found.value.as_str()), ("금액 1,000원", "1,000"));
+        let start = TEXT.find("1,000").unwrap();
+        assert_eq!(found.start, TEXT[..start].chars().count());
+        assert_eq!(found.end, found.start + 5);
+    }
+
+    #[test]
+    fn selects_nth_match() {
+        let amount = |nth: i64| value(&format!(r#"{{"regex": "([\\d,]+)원", "nth": {}}}"#, nth), "금액");
+        assert_eq!(amount(1).unwrap(), "1,000");
+        assert_eq!(amount(2).unwrap(), "500");
+        assert_eq!(amount(-1).unwrap(), "1,500");
+        assert_eq!(amount(-3).unwrap(), "1,000");
+        assert!(amount(4).is_err());
+        assert!(amount(-4).is_err());
+        assert!(amount(0).unwrap_err().contains("nth"));
+    }
+
+    #[test]
+    fn falls_back_through_chain() {
+        let fallback = chain(r#"["총액\\s*([\\d,]+)", {"regex": "합계\\s*([\\d,]+)"}, "([\\d,]+)원"]"#);
+        let found = find(TEXT, &fallback, "합계").unwrap();
+        assert_eq!((found.index, found.value.as_str()), (1, "1,500"));
+
+        // 처음 일치한 뒤에는 더 시도하지 않음
+        let traced = trace(TEXT, &fallback, "합계");
+        let attempts: Vec<bool> = traced.attempts.iter().map(|a| a.matched).collect();
+        assert_eq!(attempts, vec![false, true]);
+
+        // 잘못된 정규식은 뒤의 대체 패턴으로 넘어가지 않고 오류
+        let invalid = chain(r#"["(", "합계"]"#);
+        assert!(find(TEXT, &invalid, "합계").unwrap_err().contains("잘못된 정규식"));
+    }
+
+    #[test]
+    fn reports_no_match() {
+        let err = value(r#"["납기\\s*(\\S+)", "배송\\s*(\\S+)"]"#, "납기").unwrap_err();
+        assert_eq!(err, r"패턴과 일치하는 텍스트가 없습니다: 납기\s*(\S+) | 배송\s*(\S+)");
+        // 선택한 그룹이 비어 있으면 일치하지 않은 것으로 봄
+        assert!(value(r#""견적번호:(\\s*)Q""#, "x").is_err());
+    }
+
+    #[test]
+    fn multiline_and_dot_all_are_separate() {
+        assert!(value(r#""^품목 (\\S+)""#, "x").is_err());
+        assert_eq!(value(r#"{"regex": "^품목 (\\S+)", "multiline": true}"#, "x").unwrap(), "서버");
+
+        // .이 줄바꿈을 넘으면 마지막 "원"까지 일치
+        let span = |flags: &str| value(&format!(r#"{{"regex": "서버(.*)원"{}}}"#, flags), "x");
+        assert_eq!(span("").unwrap(), "금액 1,000");
+        assert_eq!(span(r#", "dotAll": true"#).unwrap(), "금액 1,000원\n품목 설치 금액 500원\n합계 1,500");
+        assert_eq!(span(r#", "multiline": true, "dotAll": false"#).unwrap(), "금액 1,000");
+        // multiline만으로는 .이 줄을 넘지 않음
+        assert_eq!(span(r#", "multiline": true"#).unwrap(), "금액 1,000");
+        let line = r#"{"regex": "^품목 (.*)$", "multiline": true}"#;
+        assert_eq!(value(line, "x").unwrap(), "서버 금액 1,000원");
+
+        let anchored = r#"{"regex": "^합계 (\\S+)$", "dotAll": true}"#;
+        assert!(value(anchored, "x").is_err());
+    }
+}
@@ -0,0 +1,56 @@
+use serde::Deserialize;
+
+use crate::pdf::{group_lines, line_text, TextRun};
+use crate::workbook::Workbook;
+
+// PDF 영역 추출 규칙. 좌표는 페이지 왼쪽 위 기준 pt 단위 (extract_pdf 결과와 동일)
+// 예) { "page": 1, "x0": 380, "y0": 90, "x1": 560, "y1": 110 }
+#[derive(Debug, Clone, Copy, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BoundingBox {
+    // 1부터 시작하는 페이지 번호 (기본값 1)
+    #[serde(default)]
+    pub page: Option<u32>,
+    pub x0: f64,
+    pub y0: f64,
+    pub x1: f64,
+    pub y1: f64,
+}
+
+impl BoundingBox {
+    // 조각의 중심이 영역 안에 있으면 포함
+    fn contains(&self, run: &TextRun) -> bool {
+        let center_x = run.x + run.width / 2.0;
+        let center_y = run.y - run.font_size / 2.0;
+        (self.x0.min(self.x1)..=self.x0.max(self.x1)).contains(&center_x)
+            && (self.y0.min(self.y1)..=self.y0.max(self.y1)).contains(&center_y)
+    }
+}
+
+// 영역 안의 텍스트를 줄 순서대로 연결
+pub fn extract(workbook: &Workbook, bbox: &BoundingBox) -> Result<String, String> {
+    let document = workbook
+        .pdf
+        .as_ref()
+        .ok_or_else(|| "영역(bbox) 규칙은 PDF 파일에만 사용할 수 있습니다.".to_string())?;
+
+    let number = bbox.page.unwrap_or(1);
+    let page = document
+        .pages
+        .iter()
+        .find(|page| page.number == number)
+        .ok_or_else(|| format!("페이지를 찾을 수 없습니다: {}", number))?;
+
+    let runs: Vec<TextRun> = page.runs.iter().filter(|run| bbox.contains(run)).cloned().collect();
+    let text = group_lines(&runs)
+        .iter()
+        .map(|line| line_text(line))
+        .collect::<Vec<_>>()
+        .join("\n");
+
+    if text.is_empty() {
+        Err(format!("{}쪽 지정 영역에 텍스트가 없습니다.", number))
+    } else {
+        Ok(text)
+    }
+}
@@ -0,0 +1,480 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+use std::path::Path;
+use tauri::{command, State};
+
+use crate::error::AppError;
+use crate::mapping::{column_index, ColumnRule, TemplateMapping};
+use crate::storage::templates::Template;
+use crate::storage::Database;
+use crate::text_encoding::normalize_key;
+use crate::workbook::{load_workbook, Cell, FileType, Sheet, Workbook};
+
+// 헤더 행을 찾을 때 살펴볼 앞쪽 행 수
+const HEADER_SCAN_ROWS: usize = 20;
+// 라벨 수집 범위와 라벨로 볼 최대 글자 수
+const LABEL_SCAN_ROWS: usize = 50;
+const LABEL_SCAN_COLS: usize = 30;
+const LABEL_MAX_CHARS: usize = 30;
+
+// 이 점수 이상이면 수동 확인 없이 자동 매핑
+pub const AUTO_MATCH_THRESHOLD: f64 = 0.8;
+const MEDIUM_THRESHOLD: f64 = 0.5;
+// 점수를 그대로 쓰기 위한 최소 근거(비교한 항목의 가중치 합).
+// 시트 이름만 일치하는 식의 약한 근거는 이 비율만큼 점수를 낮춤
+const MIN_EVIDENCE_WEIGHT: f64 = 0.5;
+
+// 파일 구조 요약 (템플릿의 fingerprint 항목에 그대로 저장 가능)
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Fingerprint {
+    #[serde(default)]
+    pub sheet_names: Vec<String>,
+    #[serde(default)]
+    pub header_tokens: Vec<String>,
+    #[serde(default)]
+    pub anchor_labels: Vec<String>,
+    #[serde(default)]
+    pub column_count: Option<usize>,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Confidence {
+    High,
+    Medium,
+    Low,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct ScoreBreakdown {
+    pub sheet_names: Option<f64>,
+    pub header_tokens: Option<f64>,
+    pub anchor_labels: Option<f64>,
+    pub column_count: Option<f64>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TemplateCandidate {
+    pub template_id: i64,
+    pub template_name: String,
+    // 0.0 ~ 1.0
+    pub score: f64,
+    pub confidence: Confidence,
+    pub breakdown: ScoreBreakdown,
+}
+
+fn text_of(cell: &Cell) -> Option<String> {
+    match cell {
+        Cell::String(s) if !s.trim().is_empty() => Some(normalize_key(s)),
+        _ => None,
+    }
+}
+
+// 문자열 셀이 가장 많은 앞쪽 행을 헤더로 간주 (개수가 같으면 위쪽 행)
+fn header_tokens(sheet: &Sheet) -> Vec<String> {
+    sheet
+        .rows
+        .iter()
+        .take(HEADER_SCAN_ROWS)
+        .map(|row| row.iter().filter_map(text_of).collect::<Vec<_>>())
+        .filter(|tokens| tokens.len() >= 2)
+        .rev()
+        .max_by_key(|tokens| tokens.len())
+        .unwrap_or_default()
+}
+
+fn labels(sheet: &Sheet) -> HashSet<String> {
+    sheet
+        .rows
+        .iter()
+        .take(LABEL_SCAN_ROWS)
+        .flat_map(|row| row.iter().take(LABEL_SCAN_COLS))
+        .filter_map(text_of)
+        .filter(|text| text.chars().count() <= LABEL_MAX_CHARS)
+        .collect()
+}
+
+pub fn fingerprint(workbook: &Workbook) -> Fingerprint {
+    let first = workbook.sheets.first();
+    let mut anchor_labels: Vec<String> = workbook
+        .sheets
+        .iter()
+        .flat_map(labels)
+        .collect::<HashSet<_>>()
+        .into_iter()
+        .collect();
+    anchor_labels.sort();
+
+    // CSV의 시트 이름은 파일 이름이므로 비교 대상에서 제외
+    let sheet_names = match workbook.file_type {
+        FileType::Csv => Vec::new(),
+        _ => workbook.sheets.iter().map(|s| s.name.clone()).collect(),
+    };
+
+    Fingerprint {
+        sheet_names,
+        header_tokens: first.map(header_tokens).unwrap_or_default(),
+        anchor_labels,
+        column_count: first.map(|s| s.column_count),
+    }
+}
+
+// 템플릿이 기대하는 구조. 저장된 fingerprint에 매핑 규칙에서 알 수 있는 정보를 더함
+fn expected_fingerprint(mapping: &TemplateMapping) -> Fingerprint {
+    let mut expected = mapping.fingerprint.clone().unwrap_or_default();
+
+    // fingerprint 없이 저장된 템플릿(TemplateEditor)은 열 규칙의 이름을 헤더로, 가장 오른쪽 열까지를 열 수로 추정
+    let tabular: Vec<(&ColumnRule, usize)> = mapping
+        .columns
+        .iter()
+        .filter(|rule| !rule.is_document_rule())
+        .filter_map(|rule| {
+            rule.source_column
+                .as_deref()
+                .and_then(column_index)
+                .map(|col| (rule, col))
+        })
+        .collect();
+    if expected.header_tokens.is_empty() {
+        expected.header_tokens = tabular.iter().map(|(rule, _)| rule.name.clone()).collect();
+    }
+    if expected.column_count.is_none() {
+        expected.column_count = tabular.iter().map(|(_, col)| col + 1).max();
+    }
+
+    for rule in &mapping.columns {
+        if let Some(text) = rule.anchor.as_ref().and_then(|a| a.text.as_ref()) {
+            expected.anchor_labels.push(text.clone());
+        }
+    }
+    if let Some(sheet) = &mapping.sheet {
+        expected.sheet_names.push(sheet.clone());
+    }
+
+    expected
+}
+
+// 기대값 중 파일에 있는 비율
+fn coverage(expected: &[String], actual: &HashSet<String>) -> Option<f64> {
+    let expected: HashSet<String> = expected.iter().map(|s| normalize_key(s)).collect();
+    if expected.is_empty() {
+        return None;
+    }
+    let found = expected.iter().filter(|token| actual.contains(*token)).count();
+    Some(found as f64 / expected.len() as f64)
+}
+
+fn is_compatible(mapping: &TemplateMapping, workbook: &Workbook) -> bool {
+    // PDF 템플릿은 PDF 파일에만, 스프레드시트 템플릿은 스프레드시트에만 적용
+    let is_pdf = workbook.file_type == FileType::Pdf;
+    match mapping.file_type.as_deref() {
+        Some("pdf") if !is_pdf => return false,
+        Some(file_type) if is_pdf && file_type != "pdf" => return false,
+        _ => {}
+    }
+
+    // 템플릿이 참조하는 가장 오른쪽 열이 대상 시트에 있어야 함
+    let max_col = mapping
+        .columns
+        .iter()
+        .filter(|rule| !rule.is_document_rule())
+        .filter_map(|rule| rule.source_column.as_deref().and_then(column_index))
+        .max();
+    let sheet = mapping
+        .sheet
+        .as_deref()
+        .and_then(|name| workbook.sheet(name))
+        .or(workbook.sheets.first());
+    match (max_col, sheet) {
+        (Some(col), Some(sheet)) => col < sheet.column_count,
+        _ => true,
+    }
+}
+
+pub fn score_template(
+    mapping: &TemplateMapping,
+    file: &Fingerprint,
+    workbook: &Workbook,
+) -> (f64, ScoreBreakdown) {
+    let expected = expected_fingerprint(mapping);
+
+    let sheet_names: HashSet<String> = file.sheet_names.iter().map(|s| normalize_key(s)).collect();
+    let header: HashSet<String> = file.header_tokens.iter().cloned().collect();
+    let all_labels: HashSet<String> = file.anchor_labels.iter().cloned().collect();
+
+    let breakdown = ScoreBreakdown {
+        sheet_names: coverage(&expected.sheet_names, &sheet_names),
+        header_tokens: coverage(&expected.header_tokens, &header),
+        anchor_labels: coverage(&expected.anchor_labels, &all_labels),
+        column_count: match (expected.column_count, file.column_count) {
+            (Some(expected), Some(actual)) if expected.max(actual) > 0 => {
+                let diff = expected.abs_diff(actual) as f64;
+                Some(1.0 - diff / expected.max(actual) as f64)
+            }
+            _ => None,
+        },
+    };
+
+    if !is_compatible(mapping, workbook) {
+        return (0.0, breakdown);
+    }
+
+    // 정보가 있는 항목만 가중 평균하고, 근거가 부족하면 그만큼 낮춤
+    let weighted = [
+        (breakdown.sheet_names, 0.15),
+        (breakdown.header_tokens, 0.4),
+        (breakdown.anchor_labels, 0.3),
+        (breakdown.column_count, 0.15),
+    ];
+    let total_weight: f64 = weighted.iter().filter(|(s, _)| s.is_some()).map(|(_, w)| w).sum();
+    let score = if total_weight > 0.0 {
+        weighted
+            .iter()
+            .filter_map(|(s, w)| s.map(|s| s * w))
+            .sum::<f64>()
+            / total_weight
+            * (total_weight / MIN_EVIDENCE_WEIGHT).min(1.0)
+    } else {
+        0.0
+    };
+
+    (score, breakdown)
+}
+
+fn confidence(score: f64) -> Confidence {
+    if score >= AUTO_MATCH_THRESHOLD {
+        Confidence::High
+    } else if score >= MEDIUM_THRESHOLD {
+        Confidence::Medium
+    } else {
+        Confidence::Low
+    }
+}
+
+// 매핑 JSON을 미리 파싱해 둔 템플릿 (여러 파일에 반복 적용할 때 한 번만 파싱)
+pub struct ParsedTemplate {
+    pub id: i64,
+    pub name: String,
+    pub mapping: TemplateMapping,
+}
+
+// 매핑 JSON이 깨진 템플릿은 후보에서 제외
+pub fn parse_templates(templates: &[Template]) -> Vec<ParsedTemplate> {
+    templates
+        .iter()
+        .filter_map(|template| match TemplateMapping::from_json(&template.mapping_data) {
+            Ok(mapping) => Some(ParsedTemplate {
+                id: template.id,
+                name: template.name.clone(),
+                mapping,
+            }),
+            Err(e) => {
+                log::warn!("템플릿 {}({})을(를) 건너뜁니다: {}", template.id, template.name, e);
+                None
+            }
+        })
+        .collect()
+}
+
+// 모든 템플릿과 비교해서 점수 내림차순으로 반환
+pub fn rank_templates(workbook: &Workbook, templates: &[ParsedTemplate]) -> Vec<TemplateCandidate> {
+    let file = fingerprint(workbook);
+
+    let mut candidates: Vec<TemplateCandidate> = templates
+        .iter()
+        .map(|template| {
+            let (score, breakdown) = score_template(&template.mapping, &file, workbook);
+            TemplateCandidate {
+                template_id: template.id,
+                template_name: template.name.clone(),
+                score,
+                confidence: confidence(score),
+                breakdown,
+            }
+        })
+        .collect();
+
+    candidates.sort_by(|a, b| b.score.total_cmp(&a.score));
+    candidates
+}
+
+#[command]
+pub async fn compute_fingerprint(file_path: String) -> Result<Fingerprint, AppError> {
+    tauri::async_runtime::spawn_blocking(move || {
+        load_workbook(Path::new(&file_path)).map(|workbook| fingerprint(&workbook))
+    })
+    .await?
+}
+
+#[command]
+pub async fn match_templates(
+    db: State<'_, Database>,
+    file_path: String,
+    limit: Option<usize>,
+) -> Result<Vec<TemplateCandidate>, AppError> {
+    let templates = parse_templates(&db.list_templates()?);
+    let mut candidates = tauri::async_runtime::spawn_blocking(move || {
+        load_workbook(Path::new(&file_path)).map(|workbook| rank_templates(&workbook, &templates))
+    })
+    .await??;
+
+    if let Some(limit) = limit {
+        candidates.truncate(limit);
+    }
+    Ok(candidates)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn workbook(sheet: &str, rows: Vec<Vec<&str>>) -> Workbook {
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(|s| Cell::String(s.to_string())).collect())
+            .collect();
+        Workbook {
+            path: "quote.xlsx".to_string(),
+            file_type: FileType::Xlsx,
+            sheets: vec![Sheet::new(sheet.to_string(), rows)],
+            pdf: None,
+        }
+    }
+
+    fn score(mapping_json: &str, workbook: &Workbook) -> f64 {
+        let mapping = TemplateMapping::from_json(mapping_json).unwrap();
+        score_template(&mapping, &fingerprint(workbook), workbook).0
+    }
+
+    #[test]
+    fn template_without_fingerprint_uses_column_rules() {
+        let book = workbook(
+            "견적",
+            vec![vec!["견적ID", "솔루션", "카테고리"], vec!["Q-1", "백신", "보안"]],
+        );
+        let mapping = r#"{
+            "fileType": "excel",
+            "skipRows": 1,
+            "columns": [
+                {"name": "견적ID", "sourceColumn": "A"},
+                {"name": "솔루션", "sourceColumn": "B"},
+                {"name": "카테고리", "sourceColumn": "C"}
+            ]
+        }"#;
+        let matched = score(mapping, &book);
+        assert!(matched >= AUTO_MATCH_THRESHOLD, "score {matched}");
+        assert_eq!(confidence(matched), Confidence::High);
+
+        // 헤더가 다른 파일은 열 수만 맞아서는 자동 매핑되지 않음
+        let other = workbook("견적", vec![vec!["품목", "수량", "금액"], vec!["서버", "1", "100"]]);
+        assert_ne!(confidence(score(mapping, &other)), Confidence::High);
+    }
+
+    #[test]
+    fn sheet_name_alone_is_not_enough_evidence() {
+        let book = workbook("Sheet1", vec![vec!["a", "b"], vec!["1", "2"]]);
+        let matched = score(r#"{"sheet": "Sheet1", "columns": []}"#, &book);
+        assert!(matched < MEDIUM_THRESHOLD, "score {matched}");
+        assert_eq!(confidence(matched), Confidence::Low);
+    }
+
+    const HEADERS: [&str; 3] = ["견적ID", "솔루션", "카테고리"];
+
+    fn template(id: i64, name: &str, mapping_data: &str) -> Template {
+        Template {
+            id,
+            name: name.to_string(),
+            mapping_data: mapping_data.to_string(),
+            created_at: None,
+        }
+    }
+
+    #[test]
+    fn ranks_templates_by_score() {
+        let book = workbook("견적", vec![HEADERS.to_vec(), vec!["Q-1", "백신", "보안"]]);
+        let templates = parse_templates(&[
+            template(1, "다른 양식", r#"{"columns": [{"name": "품목", "sourceColumn": "A"}, {"name": "금액", "sourceColumn": "C"}]}"#),
+            template(2, "깨진 매핑", "{"),
+            template(3, "PDF 양식", r#"{"fileType": "pdf", "columns": [{"name": "견적ID", "sourceColumn": "A"}]}"#),
+            template(
+                4,
+                "견적 양식",
+                r#"{"columns": [
+                    {"name": "견적ID", "sourceColumn": "A"},
+                    {"name": "솔루션", "sourceColumn": "B"},
+                    {"name": "카테고리", "sourceColumn": "C"}
+                ]}"#,
+            ),
+            template(5, "일부 일치", r#"{"columns": [{"name": "견적ID", "sourceColumn": "A"}, {"name": "단가", "sourceColumn": "C"}]}"#),
+        ]);
+        assert_eq!(templates.len(), 4);
+
+        let candidates = rank_templates(&book, &templates);
+        let ids: Vec<i64> = candidates.iter().map(|c| c.template_id).collect();
+        assert_eq!(ids, [4, 5, 1, 3]);
+        assert!(candidates.windows(2).all(|w| w[0].score > w[1].score), "{candidates:?}");
+        assert_eq!(candidates[0].confidence, Confidence::High);
+        assert_eq!(candidates[0].template_name, "견적 양식");
+        assert_eq!(candidates[3].score, 0.0);
+        assert_eq!(candidates[3].confidence, Confidence::Low);
+    }
+
+    #[test]
+    fn incompatible_templates_score_zero() {
+        let book = workbook("견적", vec![HEADERS.to_vec(), vec!["Q-1", "백신", "보안"]]);
+        let columns = r#"[
+            {"name": "견적ID", "sourceColumn": "A"},
+            {"name": "솔루션", "sourceColumn": "B"},
+            {"name": "카테고리", "sourceColumn": "C"}
+        ]"#;
+        let excel = format!(r#"{{"fileType": "excel", "columns": {columns}}}"#);
+        let pdf = format!(r#"{{"fileType": "pdf", "columns": {columns}}}"#);
+        assert!(score(&excel, &book) >= AUTO_MATCH_THRESHOLD);
+
+        // PDF 템플릿은 스프레드시트에, 스프레드시트 템플릿은 PDF에 적용하지 않음
+        assert_eq!(score(&pdf, &book), 0.0);
+        let mut pdf_book = book.clone();
+        pdf_book.file_type = FileType::Pdf;
+        assert_eq!(score(&excel, &pdf_book), 0.0);
+        assert!(score(&pdf, &pdf_book) >= AUTO_MATCH_THRESHOLD);
+
+        // 헤더가 모두 맞아도 시트 폭을 넘는 열을 참조하면 0점. 비교 내역은 그대로 남김
+        let wide = r#"{"columns": [
+            {"name": "견적ID", "sourceColumn": "A"},
+            {"name": "솔루션", "sourceColumn": "B"},
+            {"name": "카테고리", "sourceColumn": "C"},
+            {"name": "비고", "sourceColumn": "E"}
+        ]}"#;
+        let mapping = TemplateMapping::from_json(wide).unwrap();
+        let (matched, breakdown) = score_template(&mapping, &fingerprint(&book), &book);
+        assert_eq!(matched, 0.0);
+        assert_eq!(breakdown.header_tokens, Some(0.75));
+        assert_eq!(breakdown.column_count, Some(0.6));
+    }
+
+    #[test]
+    fn csv_sheet_name_is_not_fingerprinted() {
+        let mut book = workbook("견적_2025", vec![HEADERS.to_vec(), vec!["Q-1", "백신", "보안"]]);
+        assert_eq!(fingerprint(&book).sheet_names, ["견적_2025"]);
+
+        book.path = "견적_2025.csv".to_string();
+        book.file_type = FileType::Csv;
+        let file = fingerprint(&book);
+        assert!(file.sheet_names.is_empty());
+        assert_eq!(file.header_tokens, HEADERS.map(normalize_key));
+
+        // 파일 이름이 바뀌어도 같은 CSV 템플릿과의 점수는 그대로
+        let mapping = r#"{"sheet": "견적_2025", "columns": [
+            {"name": "견적ID", "sourceColumn": "A"},
+            {"name": "솔루션", "sourceColumn": "B"},
+            {"name": "카테고리", "sourceColumn": "C"}
+        ]}"#;
+        let matched = score(mapping, &book);
+        book.sheets[0].name = "견적_2026".to_string();
+        book.path = "견적_2026.csv".to_string();
+        assert_eq!(score(mapping, &book), matched);
+    }
+}
@@ -0,0 +1,374 @@
+use regex::Regex;
+use rust_decimal::prelude::ToPrimitive;
+use rust_decimal::Decimal;
+use serde::Serialize;
+use serde_json::Value;
+use std::str::FromStr;
+use std::sync::OnceLock;
+
+use crate::text_encoding::to_half_width;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum Currency {
+    Krw,
+    Usd,
+    Eur,
+    Jpy,
+}
+
+// 부가세 포함 여부 표기
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum Vat {
+    Included,
+    Excluded,
+    Unspecified,
+}
+
+// 정규화한 금액. value는 통화 기본 단위 (원) 기준의 정확한 값
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Amount {
+    pub value: Decimal,
+    // 원문에 쓰인 단위 (예: "원", "천원", "만원", "억원")
+    pub unit: String,
+    // 원문에 통화 표기가 없으면 None
+    pub currency: Option<Currency>,
+    pub vat: Vat,
+    pub raw: String,
+}
+
+impl Amount {
+    // 단위/통화/부가세 표기 없이 숫자만 있었는지
+    pub fn is_plain(&self) -> bool {
+        self.unit == "원" && self.currency.is_none() && self.vat == Vat::Unspecified
+    }
+
+    // JSON 값. 정수는 정수로, 소수는 합계/저장(SqlMoney)과 같이 정확한 decimal 문자열로
+    pub fn to_value(&self) -> Value {
+        decimal_value(self.value)
+    }
+}
+
+pub fn decimal_value(value: Decimal) -> Value {
+    let value = value.normalize();
+    match value.to_i64() {
+        Some(n) if value.fract().is_zero() => Value::from(n),
+        _ => Value::String(value.to_string()),
+    }
+}
+
+// 큰 단위 (이 단위 사이에서는 내림차순이어야 함)
+const BIG_UNITS: &[(char, u32)] = &[('조', 12), ('억', 8), ('만', 4)];
+// 큰 단위 앞에 붙는 작은 단위 (예: 3천만, 5백억, 12,300천원)
+const SMALL_UNITS: &[(char, u32)] = &[('천', 3), ('백', 2), ('십', 1)];
+
+const CURRENCY_MARKS: &[(&str, Currency)] = &[
+    ("KRW", Currency::Krw),
+    ("₩", Currency::Krw),
+    ("원", Currency::Krw),
+    ("US$", Currency::Usd),
+    ("USD", Currency::Usd),
+    ("$", Currency::Usd),
+    ("달러", Currency::Usd),
+    ("EUR", Currency::Eur),
+    ("€", Currency::Eur),
+    ("유로", Currency::Eur),
+    ("JPY", Currency::Jpy),
+    ("¥", Currency::Jpy),
+    ("엔", Currency::Jpy),
+];
+
+fn vat_regex() -> &'static Regex {
+    static VAT: OnceLock<Regex> = OnceLock::new();
+    VAT.get_or_init(|| {
+        Regex::new(
+            r"(?i)[(\[]?\s*(?:vat|부가세|부가가치세)\s*(별도|미포함|불포함|제외|excluded|excl\.?|포함|included|incl\.?)\s*[)\]]?",
+        )
+        .expect("valid VAT regex")
+    })
+}
+
+// 견적서의 금액 표기를 정확한 값으로 해석.
+// 예) "1,234,000원", "₩1.2억", "123만원", "12,300 천원", "1억 2,000만원 (VAT 별도)"
+// 범위, 여러 금액, 해석할 수 없는 문자가 섞인 값은 추측하지 않고 오류로 처리
+pub fn parse_amount(text: &str) -> Result<Amount, String> {
+    let raw = text.trim().to_string();
+    let mut rest = to_half_width(&raw);
+
+    // 부가세 표기
+    let mut vat = Vat::Unspecified;
+    for captures in vat_regex().captures_iter(&rest) {
+        let found = match captures[1].to_lowercase().as_str() {
+            "포함" | "included" | "incl" | "incl." => Vat::Included,
+            _ => Vat::Excluded,
+        };
+        if vat != Vat::Unspecified && vat != found {
+            return Err(format!("부가세 포함 여부가 모호합니다: {}", raw));
+        }
+        vat = found;
+    }
+    rest = vat_regex().replace_all(&rest, " ").to_string();
+
+    // 통화 표기
+    let mut currency = None;
+    for (mark, found) in CURRENCY_MARKS {
+        let upper = rest.to_uppercase();
+        if !upper.contains(mark) {
+            continue;
+        }
+        if currency.is_some_and(|c| c != *found) {
+            return Err(format!("통화가 여러 개 표기되어 있습니다: {}", raw));
+        }
+        currency = Some(*found);
+        rest = replace_ignore_case(&rest, mark);
+    }
+
+    // 음수 표기: -1,000 / △1,000 / (1,000)
+    let mut negative = false;
+    let mut body = rest.trim().to_string();
+    if let Some(inner) = body.strip_prefix('(').and_then(|b| b.strip_suffix(')')) {
+        negative = true;
+        body = inner.trim().to_string();
+    }
+    if let Some(stripped) = body
+        .strip_prefix('-')
+        .or_else(|| body.strip_prefix('△'))
+        .or_else(|| body.strip_prefix('▲'))
+    {
+        if negative {
+            return Err(format!("음수 표기가 중복되었습니다: {}", raw));
+        }
+        negative = true;
+        body = stripped.trim().to_string();
+    }
+
+    let (mut value, unit) = parse_korean_number(&body).map_err(|e| format!("{}: {}", e, raw))?;
+    if negative {
+        value = -value;
+    }
+    // 1.2억 -> 120000000 (소수 자릿수 정리)
+    let value = value.normalize();
+
+    Ok(Amount {
+        value,
+        unit,
+        currency,
+        vat,
+        raw,
+    })
+}
+
+fn replace_ignore_case(text: &str, mark: &str) -> String {
+    let upper = text.to_uppercase();
+    // 대소문자 변환으로 길이가 달라지는 문자가 있으면 그대로 치환
+    if upper.len() != text.len() {
+        return text.replace(mark, " ");
+    }
+    let mut result = String::new();
+    let mut last = 0;
+    for (start, _) in upper.match_indices(mark) {
+        result.push_str(&text[last..start]);
+        result.push(' ');
+        last = start + mark.len();
+    }
+    result.push_str(&text[last..]);
+    result
+}
+
+// "1억 2,000만", "12,300천", "1.2억", "2천백만" 같은 숫자+단위 묶음의 합.
+// 큰 단위(조/억/만) 사이의 작은 단위(천/백/십) 항은 더하고, 숫자 없는 단위는 1로 봄
+fn parse_korean_number(body: &str) -> Result<(Decimal, String), String> {
+    let chars: Vec<char> = body.chars().collect();
+    let overflow = || "금액이 너무 큽니다".to_string();
+    let scale = |value: Decimal, exponent: u32| {
+        value
+            .checked_mul(Decimal::from(10u64.pow(exponent)))
+            .ok_or_else(overflow)
+    };
+
+    let mut total = Decimal::ZERO;
+    // 현재 큰 단위 묶음에서 작은 단위까지 더한 값과 아직 단위가 붙지 않은 숫자
+    let mut group = Decimal::ZERO;
+    let mut group_started = false;
+    let mut pending: Option<Decimal> = None;
+    let mut small_limit = u32::MAX;
+    let mut last_big: Option<u32> = None;
+    // 첫 묶음에 쓰인 단위 (예: "천", "억")
+    let mut label = String::new();
+    let mut first_group = true;
+    let mut has_number = false;
+    let mut i = 0;
+
+    while i < chars.len() {
+        let c = chars[i];
+        if c.is_whitespace() {
+            i += 1;
+            continue;
+        }
+        if matches!(c, '~' | '-' | '/') {
+            return Err("금액 범위 또는 여러 금액은 해석할 수 없습니다".to_string());
+        }
+
+        if c.is_ascii_digit() || c == ',' || c == '.' {
+            if pending.is_some() {
+                return Err("금액이 여러 개이거나 단위가 올바르지 않습니다".to_string());
+            }
+            let start = i;
+            while i < chars.len() && (chars[i].is_ascii_digit() || chars[i] == ',' || chars[i] == '.') {
+                i += 1;
+            }
+            let number: String = chars[start..i].iter().collect();
+            pending = Some(parse_number(&number)?);
+            group_started = true;
+            has_number = true;
+            continue;
+        }
+
+        if let Some((_, e)) = SMALL_UNITS.iter().find(|(u, _)| *u == c) {
+            if *e >= small_limit {
+                return Err("단위 순서가 올바르지 않습니다".to_string());
+            }
+            small_limit = *e;
+            let value = scale(pending.take().unwrap_or(Decimal::ONE), *e)?;
+            group = group.checked_add(value).ok_or_else(overflow)?;
+            group_started = true;
+        } else if let Some((_, e)) = BIG_UNITS.iter().find(|(u, _)| *u == c) {
+            if last_big.is_some_and(|last| *e >= last) {
+                return Err("단위 순서가 올바르지 않습니다".to_string());
+            }
+            last_big = Some(*e);
+            let value = if group_started {
+                group
+                    .checked_add(pending.take().unwrap_or(Decimal::ZERO))
+                    .ok_or_else(overflow)?
+            } else {
+                Decimal::ONE
+            };
+            total = total.checked_add(scale(value, *e)?).ok_or_else(overflow)?;
+            group = Decimal::ZERO;
+            group_started = false;
+            small_limit = u32::MAX;
+        } else {
+            return Err(format!("해석할 수 없는 문자 '{}'", c));
+        }
+
+        if first_group {
+            label.push(c);
+            first_group = !BIG_UNITS.iter().any(|(u, _)| *u == c);
+        }
+        i += 1;
+    }
+
+    if !has_number {
+        return Err("숫자가 없습니다".to_string());
+    }
+    let rest = group
+        .checked_add(pending.unwrap_or(Decimal::ZERO))
+        .ok_or_else(overflow)?;
+    let total = total.checked_add(rest).ok_or_else(overflow)?;
+    Ok((total, format!("{}원", label)))
+}
+
+// 천 단위 구분 기호를 검사하고 정확한 값으로 변환. "1,23,000", "1.234.567" 등은 거부
+fn parse_number(number: &str) -> Result<Decimal, String> {
+    let (integer, fraction) = match number.split_once('.') {
+        Some((integer, fraction)) => (integer, Some(fraction)),
+        None => (number, None),
+    };
+    let invalid = || format!("숫자 형식이 모호합니다 '{}'", number);
+
+    if fraction.is_some_and(|f| f.is_empty() || !f.chars().all(|c| c.is_ascii_digit())) {
+        return Err(invalid());
+    }
+    if integer.contains(',') {
+        let mut groups = integer.split(',');
+        let first = groups.next().unwrap_or_default();
+        if first.is_empty() || first.len() > 3 || groups.any(|g| g.len() != 3) {
+            return Err(invalid());
+        }
+    }
+    if integer.is_empty() {
+        return Err(invalid());
+    }
+
+    let digits = integer.replace(',', "");
+    let normalized = match fraction {
+        Some(fraction) => format!("{}.{}", digits, fraction),
+        None => digits,
+    };
+    Decimal::from_str(&normalized).map_err(|_| invalid())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parses_korean_amounts() {
+        let cases: &[(&str, i64, &str, Option<Currency>, Vat)] = &[
+            ("1,234,000", 1_234_000, "원", None, Vat::Unspecified),
+            ("1,234,000원", 1_234_000, "원", Some(Currency::Krw), Vat::Unspecified),
+            ("₩1.2억", 120_000_000, "억원", Some(Currency::Krw), Vat::Unspecified),
+            ("123만원", 1_230_000, "만원", Some(Currency::Krw), Vat::Unspecified),
+            ("12,300 천원", 12_300_000, "천원", Some(Currency::Krw), Vat::Unspecified),
+            ("1억 2,000만원 (VAT 별도)", 120_000_000, "억원", Some(Currency::Krw), Vat::Excluded),
+            ("5,500,000 (부가세 포함)", 5_500_000, "원", None, Vat::Included),
+            ("2천백만", 21_000_000, "천백만원", None, Vat::Unspecified),
+            ("3천만원", 30_000_000, "천만원", Some(Currency::Krw), Vat::Unspecified),
+            ("5백억", 50_000_000_000, "백억원", None, Vat::Unspecified),
+            ("1억 천만", 110_000_000, "억원", None, Vat::Unspecified),
+            ("(1,000)", -1_000, "원", None, Vat::Unspecified),
+        ];
+        for (text, value, unit, currency, vat) in cases {
+            let amount = parse_amount(text).unwrap_or_else(|e| panic!("{}: {}", text, e));
+            assert_eq!(amount.value, Decimal::from(*value), "{}", text);
+            assert_eq!(amount.unit, *unit, "{}", text);
+            assert_eq!(amount.currency, *currency, "{}", text);
+            assert_eq!(amount.vat, *vat, "{}", text);
+        }
+    }
+
+    #[test]
+    fn rejects_ambiguous_amounts() {
+        let cases = [
+            "1만 2억",
+            "2백천",
+            "1억 1억",
+            "1,000~2,000",
+            "1,000 2,000",
+            "1,23,000",
+            "만원",
+            "10만원 (VAT 포함) (VAT 별도)",
+            "99,999,999,999,999,999,999조",
+        ];
+        for text in cases {
+            assert!(parse_amount(text).is_err(), "{}", text);
+        }
+    }
+
+    #[test]
+    fn mapped_values_keep_exact_decimals() {
+        use crate::storage::quotations::NewQuotation;
+        use crate::storage::Database;
+
+        let amount = parse_amount("1,234,567.10원").unwrap();
+        let value = amount.to_value();
+        assert_eq!(value, Value::from("1234567.1"));
+        assert_eq!(parse_amount("1,000원").unwrap().to_value(), Value::from(1_000));
+        assert_eq!(decimal_value(Decimal::from_str("0.30").unwrap()), Value::from("0.3"));
+
+        // 매핑 값 -> 견적 입력 -> DB 저장 -> 조회 -> 직렬화까지 값이 바뀌지 않음
+        let input: NewQuotation = serde_json::from_value(serde_json::json!({
+            "quotationId": "Q-1",
+            "contractAmount": value,
+        }))
+        .unwrap();
+        let db = Database::open_in_memory().unwrap();
+        let stored = db.create_quotation(&input).unwrap();
+        assert_eq!(stored.contract_amount, Some(Decimal::from_str("1234567.10").unwrap()));
+        let serialized = serde_json::to_value(&stored).unwrap();
+        assert_eq!(serialized["contractAmount"], "1234567.1");
+    }
+}
@@ -0,0 +1,502 @@
+use pdf_extract::{output_doc, Document, MediaBox, OutputDev, OutputError, Transform};
+use serde::Serialize;
+use std::fs;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+use tauri::command;
+
+use crate::error::{panic_message, AppError};
+use crate::workbook::{Cell, FileType, Sheet, Workbook};
+
+// 같은 줄로 볼 y 차이 (글자 크기 대비)
+const LINE_TOLERANCE: f64 = 0.5;
+// 이 간격보다 떨어진 글자는 별도 텍스트 조각(셀)으로 분리 (글자 크기 대비)
+const RUN_GAP: f64 = 1.0;
+// 이 간격보다 떨어지면 조각 안에 공백을 넣음 (글자 크기 대비)
+const SPACE_GAP: f64 = 0.15;
+// 같은 열로 볼 x 시작 위치 차이 (글자 크기 대비)
+const COLUMN_TOLERANCE: f64 = 2.0;
+
+// 위치 정보가 있는 텍스트 조각. 좌표는 페이지 왼쪽 위 기준 pt 단위 (y는 기준선)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TextRun {
+    pub text: String,
+    pub x: f64,
+    pub y: f64,
+    pub width: f64,
+    pub font_size: f64,
+}
+
+impl TextRun {
+    pub fn end_x(&self) -> f64 {
+        self.x + self.width
+    }
+}
+
+// 열 위치를 맞춰 재구성한 표
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfTable {
+    // 각 열의 시작 x 좌표
+    pub columns: Vec<f64>,
+    pub rows: Vec<Vec<String>>,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfPage {
+    // 1부터 시작
+    pub number: u32,
+    pub width: f64,
+    pub height: f64,
+    pub runs: Vec<TextRun>,
+    pub tables: Vec<PdfTable>,
+    // 줄 단위 텍스트 (조각 사이는 공백으로 연결)
+    pub text: String,
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PdfDocument {
+    pub path: String,
+    pub pages: Vec<PdfPage>,
+}
+
+impl PdfDocument {
+    // 전체 페이지 텍스트 (페이지 사이는 빈 줄)
+    pub fn text(&self) -> String {
+        self.pages
+            .iter()
+            .map(|p| p.text.as_str())
+            .collect::<Vec<_>>()
+            .join("\n\n")
+    }
+}
+
+// pdf-extract가 넘겨주는 글자를 위치 기준으로 조각/페이지로 모음
+#[derive(Default)]
+struct Collector {
+    pages: Vec<PdfPage>,
+    page_top: f64,
+    current: Option<TextRun>,
+}
+
+impl Collector {
+    fn flush_run(&mut self) {
+        if let Some(run) = self.current.take() {
+            if !run.text.trim().is_empty() {
+                if let Some(page) = self.pages.last_mut() {
+                    page.runs.push(run);
+                }
+            }
+        }
+    }
+}
+
+impl OutputDev for Collector {
+    fn begin_page(
+        &mut self,
+        page_num: u32,
+        media_box: &MediaBox,
+        _art_box: Option<(f64, f64, f64, f64)>,
+    ) -> Result<(), OutputError> {
+        self.page_top = media_box.ury;
+        self.pages.push(PdfPage {
+            number: page_num,
+            width: media_box.urx - media_box.llx,
+            height: media_box.ury - media_box.lly,
+            runs: Vec::new(),
+            tables: Vec::new(),
+            text: String::new(),
+        });
+        Ok(())
+    }
+
+    fn end_page(&mut self) -> Result<(), OutputError> {
+        self.flush_run();
+        Ok(())
+    }
+
+    fn output_character(
+        &mut self,
+        trm: &Transform,
+        width: f64,
+        _spacing: f64,
+        font_size: f64,
+        char: &str,
+    ) -> Result<(), OutputError> {
+        // 변환 행렬을 적용한 실제 글자 크기
+        let scaled_x = font_size * (trm.m11 + trm.m21);
+        let scaled_y = font_size * (trm.m12 + trm.m22);
+        let size = (scaled_x * scaled_y).abs().sqrt().max(1.0);
+        let x = trm.m31;
+        let y = self.page_top - trm.m32;
+        let advance = width * size;
+
+        if let Some(run) = &mut self.current {
+            let same_line = (run.y - y).abs() <= size * LINE_TOLERANCE;
+            let gap = x - run.end_x();
+            if same_line && gap > -size && gap <= size * RUN_GAP {
+                if gap > size * SPACE_GAP && !run.text.ends_with(' ') {
+                    run.text.push(' ');
+                }
+                run.text.push_str(char);
+                run.width = (x + advance - run.x).max(run.width);
+                return Ok(());
+            }
+        }
+
+        self.flush_run();
+        self.current = Some(TextRun {
+            text: char.to_string(),
+            x,
+            y,
+            width: advance,
+            font_size: size,
+        });
+        Ok(())
+    }
+
+    fn begin_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_word(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+
+    fn end_line(&mut self) -> Result<(), OutputError> {
+        Ok(())
+    }
+}
+
+// 기준선이 비슷한 조각끼리 줄로 묶고 각 줄은 x 순으로 정렬
+pub fn group_lines(runs: &[TextRun]) -> Vec<Vec<&TextRun>> {
+    let mut sorted: Vec<&TextRun> = runs.iter().collect();
+    sorted.sort_by(|a, b| a.y.total_cmp(&b.y).then(a.x.total_cmp(&b.x)));
+
+    let mut lines: Vec<Vec<&TextRun>> = Vec::new();
+    for run in sorted {
+        match lines.last_mut() {
+            Some(line) if (line[0].y - run.y).abs() <= run.font_size.max(line[0].font_size) * LINE_TOLERANCE => {
+                line.push(run)
+            }
+            _ => lines.push(vec![run]),
+        }
+    }
+    for line in &mut lines {
+        line.sort_by(|a, b| a.x.total_cmp(&b.x));
+    }
+    lines
+}
+
+pub fn line_text(line: &[&TextRun]) -> String {
+    line.iter()
+        .map(|r| r.text.trim())
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+// 조각이 두 개 이상인 줄이 연속되면 하나의 표로 보고 x 시작 위치로 열을 맞춤
+fn detect_tables(lines: &[Vec<&TextRun>]) -> Vec<PdfTable> {
+    let mut tables = Vec::new();
+    let mut block: Vec<&Vec<&TextRun>> = Vec::new();
+
+    for line in lines {
+        if line.len() >= 2 {
+            block.push(line);
+            continue;
+        }
+        if block.len() >= 2 {
+            tables.push(build_table(&block));
+        }
+        block.clear();
+    }
+    if block.len() >= 2 {
+        tables.push(build_table(&block));
+    }
+    tables
+}
+
+fn build_table(block: &[&Vec<&TextRun>]) -> PdfTable {
+    let size = block
+        .iter()
+        .flat_map(|line| line.iter().map(|r| r.font_size))
+        .sum::<f64>()
+        / block.iter().map(|line| line.len()).sum::<usize>().max(1) as f64;
+
+    let mut starts: Vec<f64> = block.iter().flat_map(|line| line.iter().map(|r| r.x)).collect();
+    starts.sort_by(|a, b| a.total_cmp(b));
+
+    let mut columns: Vec<f64> = Vec::new();
+    for x in starts {
+        match columns.last() {
+            Some(last) if x - last <= size * COLUMN_TOLERANCE => {}
+            _ => columns.push(x),
+        }
+    }
+
+    let rows = block
+        .iter()
+        .map(|line| {
+            let mut cells = vec![String::new(); columns.len()];
+            for run in line.iter() {
+                let col = column_for(&columns, run.x);
+                if !cells[col].is_empty() {
+                    cells[col].push(' ');
+                }
+                cells[col].push_str(run.text.trim());
+            }
+            cells
+        })
+        .collect();
+
+    PdfTable { columns, rows }
+}
+
+// 시작 위치가 x 이하인 가장 오른쪽 열
+fn column_for(columns: &[f64], x: f64) -> usize {
+    columns
+        .iter()
+        .rposition(|start| *start <= x + 0.5)
+        .unwrap_or(0)
+}
+
+pub fn load_pdf(path: &Path) -> Result<PdfDocument, AppError> {
+    fs::metadata(path).map_err(|e| AppError::io(e, path))?;
+    let parse_error = |e: &dyn std::fmt::Display| AppError::Parse {
+        path: path.display().to_string(),
+        detail: format!("PDF parse error: {}", e),
+    };
+    // pdf-extract는 손상된 PDF에서 오류 대신 패닉을 일으키기도 하므로 잡아서 해석 오류로 반환
+    let mut pages = catch_parser_panic(|| {
+        let document = Document::load(path).map_err(|e| parse_error(&e))?;
+        let mut collector = Collector::default();
+        output_doc(&document, &mut collector).map_err(|e| parse_error(&e))?;
+        Ok(collector.pages)
+    })
+    .unwrap_or_else(|message| Err(parse_error(&format!("parser panicked: {}", message))))?;
+
+    for page in &mut pages {
+        let lines = group_lines(&page.runs);
+        page.tables = detect_tables(&lines);
+        page.text = lines
+            .iter()
+            .map(|line| line_text(line))
+            .collect::<Vec<_>>()
+            .join("\n");
+    }
+
+    Ok(PdfDocument {
+        path: path.to_string_lossy().to_string(),
+        pages,
+    })
+}
+
+fn catch_parser_panic<T>(parse: impl FnOnce() -> T) -> Result<T, String> {
+    panic::catch_unwind(AssertUnwindSafe(parse)).map_err(|payload| panic_message(payload.as_ref()))
+}
+
+// 템플릿 매핑/감지에 쓰도록 PDF를 시트로 변환.
+// "PDF" 시트는 전체 줄을 담고 (표 안의 줄은 열 위치를 맞추고 나머지는 조각 순서대로)
+// 감지된 표는 "표 1", "표 2" ... 시트로 따로 추가해 열 문자로 매핑할 수 있게 한다.
+pub fn to_workbook(document: PdfDocument) -> Workbook {
+    let mut rows: Vec<Vec<Cell>> = Vec::new();
+
+    for page in &document.pages {
+        let lines = group_lines(&page.runs);
+        let mut block: Vec<&Vec<&TextRun>> = Vec::new();
+
+        for line in &lines {
+            if line.len() >= 2 {
+                block.push(line);
+                continue;
+            }
+            flush_block(&mut rows, &mut block);
+            rows.push(sequential_row(line));
+        }
+        flush_block(&mut rows, &mut block);
+    }
+
+    let mut sheets = vec![Sheet::new("PDF".to_string(), rows)];
+    let tables = document.pages.iter().flat_map(|page| page.tables.iter());
+    for (index, table) in tables.enumerate() {
+        let rows = table
+            .rows
+            .iter()
+            .map(|cells| cells.iter().cloned().map(text_cell).collect())
+            .collect();
+        sheets.push(Sheet::new(format!("표 {}", index + 1), rows));
+    }
+
+    Workbook {
+        path: document.path.clone(),
+        file_type: FileType::Pdf,
+        sheets,
+        pdf: Some(document),
+    }
+}
+
+fn flush_block(rows: &mut Vec<Vec<Cell>>, block: &mut Vec<&Vec<&TextRun>>) {
+    if block.len() >= 2 {
+        let table = build_table(block);
+        rows.extend(
+            table
+                .rows
+                .into_iter()
+                .map(|cells| cells.into_iter().map(text_cell).collect()),
+        );
+    } else {
+        rows.extend(block.iter().map(|line| sequential_row(line)));
+    }
+    block.clear();
+}
+
+fn sequential_row(line: &[&TextRun]) -> Vec<Cell> {
+    line.iter().map(|r| text_cell(r.text.trim().to_string())).collect()
+}
+
+fn text_cell(text: String) -> Cell {
+    if text.is_empty() {
+        Cell::Empty
+    } else {
+        Cell::String(text)
+    }
+}
+
+#[command]
+pub async fn extract_pdf(path: String) -> Result<PdfDocument, AppError> {
+    tauri::async_runtime::spawn_blocking(move || load_pdf(Path::new(&path))).await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // 글자 크기 10, 글자 폭 0.5 (5pt)인 글자를 페이지 위에서 y pt 아래, x 위치에 출력
+    fn glyphs(collector: &mut Collector, text: &str, x: f64, y: f64) {
+        for (i, c) in text.chars().enumerate() {
+            let trm = Transform::row_major(1.0, 0.0, 0.0, 1.0, x + i as f64 * 5.0, 800.0 - y);
+            collector.output_character(&trm, 0.5, 0.0, 10.0, &c.to_string()).unwrap();
+        }
+    }
+
+    fn run(text: &str, x: f64, y: f64) -> TextRun {
+        TextRun {
+            text: text.to_string(),
+            x,
+            y,
+            width: text.chars().count() as f64 * 5.0,
+            font_size: 10.0,
+        }
+    }
+
+    fn texts(lines: &[Vec<&TextRun>]) -> Vec<Vec<String>> {
+        lines
+            .iter()
+            .map(|line| line.iter().map(|r| r.text.clone()).collect())
+            .collect()
+    }
+
+    #[test]
+    fn collector_splits_runs_by_gap() {
+        let mut collector = Collector::default();
+        let media_box = MediaBox { llx: 0.0, lly: 0.0, urx: 595.0, ury: 800.0 };
+        collector.begin_page(1, &media_box, None).unwrap();
+        glyphs(&mut collector, "품목", 50.0, 100.0);
+        // 글자 크기의 0.15~1배 간격은 같은 조각 안의 공백
+        glyphs(&mut collector, "명", 65.0, 100.0);
+        // 글자 크기보다 멀면 별도 조각
+        glyphs(&mut collector, "수량", 150.0, 100.5);
+        glyphs(&mut collector, "서버", 50.0, 120.0);
+        collector.end_page().unwrap();
+
+        let page = &collector.pages[0];
+        assert_eq!((page.number, page.width, page.height), (1, 595.0, 800.0));
+        let runs: Vec<(&str, f64, f64)> = page.runs.iter().map(|r| (r.text.as_str(), r.x, r.y)).collect();
+        assert_eq!(runs, vec![("품목 명", 50.0, 100.0), ("수량", 150.0, 100.5), ("서버", 50.0, 120.0)]);
+        assert_eq!(page.runs[0].width, 20.0);
+        assert_eq!(page.runs[0].font_size, 10.0);
+    }
+
+    #[test]
+    fn groups_lines_top_to_bottom_and_left_to_right() {
+        let runs = vec![
+            run("합계", 50.0, 300.0),
+            run("단가", 250.0, 101.0),
+            run("품목", 50.0, 100.0),
+            run("수량", 150.0, 99.0),
+            run("견적서", 200.0, 40.0),
+        ];
+        let lines = group_lines(&runs);
+        assert_eq!(
+            texts(&lines),
+            vec![vec!["견적서"], vec!["품목", "수량", "단가"], vec!["합계"]]
+        );
+        assert_eq!(line_text(&lines[1]), "품목 수량 단가");
+    }
+
+    #[test]
+    fn reconstructs_table_columns() {
+        let runs = vec![
+            run("견적서", 50.0, 40.0),
+            run("품목", 50.0, 100.0),
+            run("수량", 150.0, 100.0),
+            run("금액", 250.0, 100.0),
+            run("서버", 51.0, 120.0),
+            run("2", 152.0, 120.0),
+            run("1,000", 248.0, 120.0),
+            // 수량 칸이 빈 행
+            run("설치비", 50.0, 140.0),
+            run("500", 250.0, 140.0),
+            run("합계 1,500", 50.0, 200.0),
+        ];
+        let lines = group_lines(&runs);
+        let tables = detect_tables(&lines);
+        assert_eq!(tables.len(), 1);
+        assert_eq!(tables[0].columns, vec![50.0, 150.0, 248.0]);
+        assert_eq!(
+            tables[0].rows,
+            vec![
+                vec!["품목", "수량", "금액"],
+                vec!["서버", "2", "1,000"],
+                vec!["설치비", "", "500"],
+            ]
+        );
+
+        // 한 조각짜리 줄은 표에 넣지 않고, 표 안의 줄은 열 위치를 맞춤
+        let workbook = to_workbook(PdfDocument {
+            path: "quote.pdf".to_string(),
+            pages: vec![PdfPage {
+                number: 1,
+                width: 595.0,
+                height: 842.0,
+                runs,
+                tables,
+                text: String::new(),
+            }],
+        });
+        let names: Vec<&str> = workbook.sheets.iter().map(|s| s.name.as_str()).collect();
+        assert_eq!(names, vec!["PDF", "표 1"]);
+        let sheet = &workbook.sheets[0];
+        assert_eq!(sheet.row_count, 5);
+        assert_eq!(sheet.cell(3, 1), &Cell::Empty);
+        assert_eq!(sheet.cell(3, 2), &Cell::String("500".into()));
+        assert_eq!(sheet.cell(4, 0), &Cell::String("합계 1,500".into()));
+    }
+
+    #[test]
+    fn parser_panic_becomes_parse_error() {
+        assert_eq!(catch_parser_panic(|| 1), Ok(1));
+        let message = catch_parser_panic::<()>(|| panic!("invalid xref")).unwrap_err();
+        assert_eq!(message, "invalid xref");
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("broken.pdf");
+        fs::write(&path, b"%PDF-1.4\nnot really a pdf").unwrap();
+        let err = load_pdf(&path).unwrap_err();
+        assert_eq!(err.code(), "PARSE_ERROR");
+        assert_eq!(err.context().path, Some(path.display().to_string()));
+    }
+}
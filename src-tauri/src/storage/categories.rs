@@ -0,0 +1,614 @@
+use regex::Regex;
+use rusqlite::{params, Connection, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashSet;
+use tauri::{command, State};
+
+use super::quotations::{Quotation, SELECT as SELECT_QUOTATIONS};
+use super::{not_found, Database};
+use crate::error::AppError;
+
+// 분류 규칙에 쓸 수 있는 견적 필드 (Quotation의 JSON 키)
+const RULE_FIELDS: &[&str] = &[
+    "quotationId",
+    "solution",
+    "category",
+    "partner",
+    "vendor",
+    "mainProduct",
+    "freeMaintenancePeriod",
+    "vendorContact",
+    "vendorEmail",
+    "partnerContact",
+    "partnerEmail",
+    "specialNotes",
+];
+
+// 자동 분류 규칙. 지정한 조건을 모두 만족하면 일치 (분류의 규칙 목록은 하나라도 일치하면 포함)
+// 예) { "field": "vendor", "equals": "삼성SDS" }
+//     { "field": "mainProduct", "regex": "(?i)^oracle" }
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct CategoryRule {
+    pub field: String,
+    // 앞뒤 공백을 제외하고 대소문자 구분 없이 같은 값
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub equals: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub contains: Option<String>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub regex: Option<String>,
+}
+
+impl CategoryRule {
+    fn validate(&self) -> Result<(), AppError> {
+        if !RULE_FIELDS.contains(&self.field.as_str()) {
+            return Err(AppError::invalid(format!("분류 규칙에 쓸 수 없는 필드입니다: {}", self.field)));
+        }
+        if self.equals.is_none() && self.contains.is_none() && self.regex.is_none() {
+            return Err(AppError::invalid(format!("분류 규칙에 조건이 없습니다: {}", self.field)));
+        }
+        if let Some(pattern) = &self.regex {
+            Regex::new(pattern).map_err(|e| AppError::invalid(format!("잘못된 정규식 '{}': {}", pattern, e)))?;
+        }
+        Ok(())
+    }
+
+    fn matches(&self, record: &Value, regex: Option<&Regex>) -> bool {
+        let Some(value) = record.get(&self.field).and_then(Value::as_str) else {
+            return false;
+        };
+        let value = value.trim();
+        let lower = value.to_lowercase();
+
+        let equals = self.equals.as_deref().map(|expected| lower == expected.trim().to_lowercase());
+        let contains = self.contains.as_deref().map(|part| lower.contains(&part.trim().to_lowercase()));
+        let regex = regex.map(|regex| regex.is_match(value));
+        [equals, contains, regex].iter().all(|result| result.unwrap_or(true))
+    }
+}
+
+// 분류 (parent_id로 계층 구성)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Category {
+    pub id: i64,
+    pub name: String,
+    pub parent_id: Option<i64>,
+    pub rules: Vec<CategoryRule>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewCategory {
+    pub name: String,
+    pub parent_id: Option<i64>,
+    #[serde(default)]
+    pub rules: Vec<CategoryRule>,
+}
+
+// 분류 규칙 적용 결과
+#[derive(Debug, Clone, Default, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RuleAssignment {
+    pub categories: usize,
+    pub assigned: usize,
+    pub removed: usize,
+}
+
+const SELECT: &str = "SELECT id, name, parent_id, rules, created_at FROM categories";
+
+// 분류와 모든 하위 분류의 id
+const SUBTREE: &str = "WITH RECURSIVE subtree(id) AS ( \
+    SELECT ?1 UNION SELECT c.id FROM categories c JOIN subtree s ON c.parent_id = s.id)";
+
+impl Category {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        // rules 컬럼은 JSON 배열로 저장
+        let rules: String = row.get("rules")?;
+        let rules = serde_json::from_str(&rules).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(3, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+        Ok(Category {
+            id: row.get("id")?,
+            name: row.get("name")?,
+            parent_id: row.get("parent_id")?,
+            rules,
+            created_at: row.get("created_at")?,
+        })
+    }
+
+    // 규칙 중 하나라도 일치하는지 검사하는 함수 (정규식은 한 번만 컴파일)
+    pub fn matcher(&self) -> impl Fn(&Quotation) -> bool + '_ {
+        let rules: Vec<(&CategoryRule, Option<Regex>)> = self
+            .rules
+            .iter()
+            .filter_map(|rule| match &rule.regex {
+                Some(pattern) => Regex::new(pattern).ok().map(|regex| (rule, Some(regex))),
+                None => Some((rule, None)),
+            })
+            .collect();
+
+        move |quotation| {
+            let record = serde_json::to_value(quotation).unwrap_or_default();
+            rules
+                .iter()
+                .any(|(rule, regex)| rule.matches(&record, regex.as_ref()))
+        }
+    }
+}
+
+fn validate(conn: &Connection, id: Option<i64>, category: &NewCategory) -> Result<(), AppError> {
+    if category.name.trim().is_empty() {
+        return Err(AppError::invalid("분류 이름을 입력하세요."));
+    }
+    for rule in &category.rules {
+        rule.validate()?;
+    }
+
+    let Some(parent_id) = category.parent_id else {
+        return Ok(());
+    };
+    let exists: bool = conn
+        .query_row("SELECT EXISTS (SELECT 1 FROM categories WHERE id = ?1)", [parent_id], |row| {
+            row.get(0)
+        })?;
+    if !exists {
+        return Err(not_found("상위 분류", parent_id));
+    }
+    // 자기 자신이나 하위 분류를 상위로 지정하면 순환
+    if let Some(id) = id {
+        let descendant: bool = conn
+            .query_row(
+                &format!("{} SELECT EXISTS (SELECT 1 FROM subtree WHERE id = ?2)", SUBTREE),
+                [id, parent_id],
+                |row| row.get(0),
+            )?;
+        if descendant {
+            return Err(AppError::invalid("하위 분류를 상위 분류로 지정할 수 없습니다."));
+        }
+    }
+    Ok(())
+}
+
+fn rules_json(category: &NewCategory) -> Result<String, AppError> {
+    serde_json::to_string(&category.rules).map_err(AppError::from)
+}
+
+impl Database {
+    pub fn list_categories(&self) -> Result<Vec<Category>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY id", SELECT))?;
+        let rows = stmt
+            .query_map([], Category::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    pub fn get_category(&self, id: i64) -> Result<Category, AppError> {
+        self.conn()
+            .query_row(&format!("{} WHERE id = ?1", SELECT), [id], Category::from_row)
+            .optional()?
+            .ok_or_else(|| not_found("분류", id))
+    }
+
+    pub fn create_category(&self, category: &NewCategory) -> Result<Category, AppError> {
+        let id = {
+            let conn = self.conn();
+            validate(&conn, None, category)?;
+            conn.execute(
+                "INSERT INTO categories (name, parent_id, rules) VALUES (?1, ?2, ?3)",
+                params![category.name.trim(), category.parent_id, rules_json(category)?],
+            )?;
+            conn.last_insert_rowid()
+        };
+        self.get_category(id)
+    }
+
+    pub fn update_category(&self, id: i64, category: &NewCategory) -> Result<Category, AppError> {
+        let changed = {
+            let conn = self.conn();
+            validate(&conn, Some(id), category)?;
+            conn.execute(
+                "UPDATE categories SET name = ?1, parent_id = ?2, rules = ?3 WHERE id = ?4",
+                params![category.name.trim(), category.parent_id, rules_json(category)?, id],
+            )?
+        };
+        if changed == 0 {
+            return Err(not_found("분류", id));
+        }
+        self.get_category(id)
+    }
+
+    // 하위 분류와 항목 소속도 함께 삭제됨 (ON DELETE CASCADE)
+    pub fn delete_category(&self, id: i64) -> Result<(), AppError> {
+        self.conn()
+            .execute("DELETE FROM categories WHERE id = ?1", [id])?;
+        Ok(())
+    }
+
+    // 분류에 속한 견적 항목. include_descendants이면 하위 분류의 항목도 포함
+    pub fn list_category_items(
+        &self,
+        category_id: i64,
+        include_descendants: bool,
+    ) -> Result<Vec<Quotation>, AppError> {
+        self.get_category(category_id)?;
+        let sql = if include_descendants {
+            format!(
+                "{} {} WHERE id IN (SELECT quotation_id FROM category_items \
+                 WHERE category_id IN (SELECT id FROM subtree)) ORDER BY id",
+                SUBTREE, SELECT_QUOTATIONS
+            )
+        } else {
+            format!(
+                "{} WHERE id IN (SELECT quotation_id FROM category_items WHERE category_id = ?1) \
+                 ORDER BY id",
+                SELECT_QUOTATIONS
+            )
+        };
+
+        let conn = self.conn();
+        let mut stmt = conn.prepare(&sql)?;
+        let rows = stmt
+            .query_map([category_id], Quotation::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    // 견적 항목이 속한 분류 목록
+    pub fn list_item_categories(&self, quotation_id: i64) -> Result<Vec<Category>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!(
+                "{} WHERE id IN (SELECT category_id FROM category_items WHERE quotation_id = ?1) \
+                 ORDER BY id",
+                SELECT
+            ))?;
+        let rows = stmt
+            .query_map([quotation_id], Category::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    // 직접 지정. 규칙으로 들어온 항목은 직접 지정으로 바꿔서 규칙 재적용 때 빠지지 않게 함
+    pub fn add_category_items(&self, category_id: i64, quotation_ids: &[i64]) -> Result<(), AppError> {
+        self.get_category(category_id)?;
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+        for quotation_id in quotation_ids {
+            tx.execute(
+                "INSERT INTO category_items (category_id, quotation_id, source) \
+                 VALUES (?1, ?2, 'manual') \
+                 ON CONFLICT (category_id, quotation_id) DO UPDATE SET source = 'manual'",
+                [category_id, *quotation_id],
+            )
+            .map_err(|e| match e.sqlite_error_code() {
+                Some(rusqlite::ErrorCode::ConstraintViolation) => not_found("견적", *quotation_id),
+                _ => AppError::from(e),
+            })?;
+        }
+        tx.commit().map_err(AppError::from)
+    }
+
+    pub fn remove_category_items(&self, category_id: i64, quotation_ids: &[i64]) -> Result<(), AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare("DELETE FROM category_items WHERE category_id = ?1 AND quotation_id = ?2")?;
+        for quotation_id in quotation_ids {
+            stmt.execute([category_id, *quotation_id])?;
+        }
+        Ok(())
+    }
+
+    // 규칙이 있는 분류(또는 지정한 분류)에 규칙을 다시 적용.
+    // 일치하는 항목은 추가하고, 규칙으로 들어왔지만 더 이상 일치하지 않는 항목은 뺀다.
+    // 직접 지정한 항목은 건드리지 않는다.
+    pub fn apply_category_rules(&self, category_id: Option<i64>) -> Result<RuleAssignment, AppError> {
+        let categories = match category_id {
+            Some(id) => vec![self.get_category(id)?],
+            None => self.list_categories()?,
+        };
+        let quotations = self.list_quotations(&Default::default())?;
+
+        let mut result = RuleAssignment::default();
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+
+        for category in categories.iter().filter(|c| !c.rules.is_empty()) {
+            result.categories += 1;
+            let matches = category.matcher();
+            let matched: HashSet<i64> = quotations
+                .iter()
+                .filter(|q| matches(q))
+                .map(|q| q.id)
+                .collect();
+
+            let existing: Vec<i64> = {
+                let mut stmt = tx
+                    .prepare(
+                        "SELECT quotation_id FROM category_items \
+                         WHERE category_id = ?1 AND source = 'rule'",
+                    )?;
+                let rows = stmt
+                    .query_map([category.id], |row| row.get(0))?;
+                rows.collect::<Result<_, _>>()?
+            };
+            for quotation_id in existing.iter().filter(|id| !matched.contains(id)) {
+                result.removed += tx
+                    .execute(
+                        "DELETE FROM category_items WHERE category_id = ?1 AND quotation_id = ?2",
+                        [category.id, *quotation_id],
+                    )?;
+            }
+            for quotation_id in &matched {
+                result.assigned += tx
+                    .execute(
+                        "INSERT OR IGNORE INTO category_items (category_id, quotation_id, source) \
+                         VALUES (?1, ?2, 'rule')",
+                        [category.id, *quotation_id],
+                    )?;
+            }
+        }
+
+        tx.commit()?;
+        Ok(result)
+    }
+
+    // 분류에 속한 항목 중 선택한 항목 (선택이 없으면 전체). 분류에 없는 id가 있으면 오류
+    pub fn select_category_items(
+        &self,
+        category_id: i64,
+        quotation_ids: Option<&[i64]>,
+        include_descendants: bool,
+    ) -> Result<Vec<Quotation>, AppError> {
+        let items = self.list_category_items(category_id, include_descendants)?;
+        let Some(selected) = quotation_ids else {
+            return Ok(items);
+        };
+
+        let members: HashSet<i64> = items.iter().map(|q| q.id).collect();
+        let outside: Vec<String> = selected
+            .iter()
+            .filter(|id| !members.contains(id))
+            .map(|id| id.to_string())
+            .collect();
+        if !outside.is_empty() {
+            return Err(AppError::invalid(format!("분류에 속하지 않은 항목입니다: id={}", outside.join(", "))));
+        }
+        let selected: HashSet<i64> = selected.iter().copied().collect();
+        Ok(items.into_iter().filter(|q| selected.contains(&q.id)).collect())
+    }
+}
+
+#[command]
+pub fn list_categories(db: State<'_, Database>) -> Result<Vec<Category>, AppError> {
+    db.list_categories()
+}
+
+#[command]
+pub fn get_category(db: State<'_, Database>, id: i64) -> Result<Category, AppError> {
+    db.get_category(id)
+}
+
+#[command]
+pub fn create_category(db: State<'_, Database>, category: NewCategory) -> Result<Category, AppError> {
+    db.create_category(&category)
+}
+
+#[command]
+pub fn update_category(
+    db: State<'_, Database>,
+    id: i64,
+    category: NewCategory,
+) -> Result<Category, AppError> {
+    db.update_category(id, &category)
+}
+
+#[command]
+pub fn delete_category(db: State<'_, Database>, id: i64) -> Result<(), AppError> {
+    db.delete_category(id)
+}
+
+#[command]
+pub fn list_category_items(
+    db: State<'_, Database>,
+    category_id: i64,
+    include_descendants: Option<bool>,
+) -> Result<Vec<Quotation>, AppError> {
+    db.list_category_items(category_id, include_descendants.unwrap_or(false))
+}
+
+#[command]
+pub fn list_item_categories(
+    db: State<'_, Database>,
+    quotation_id: i64,
+) -> Result<Vec<Category>, AppError> {
+    db.list_item_categories(quotation_id)
+}
+
+#[command]
+pub fn add_category_items(
+    db: State<'_, Database>,
+    category_id: i64,
+    quotation_ids: Vec<i64>,
+) -> Result<(), AppError> {
+    db.add_category_items(category_id, &quotation_ids)
+}
+
+#[command]
+pub fn remove_category_items(
+    db: State<'_, Database>,
+    category_id: i64,
+    quotation_ids: Vec<i64>,
+) -> Result<(), AppError> {
+    db.remove_category_items(category_id, &quotation_ids)
+}
+
+#[command]
+pub fn apply_category_rules(
+    db: State<'_, Database>,
+    category_id: Option<i64>,
+) -> Result<RuleAssignment, AppError> {
+    db.apply_category_rules(category_id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::analysis::totals::summarize;
+    use crate::storage::quotations::NewQuotation;
+    use rust_decimal::Decimal;
+
+    fn category(db: &Database, name: &str, parent_id: Option<i64>, rules: Vec<CategoryRule>) -> i64 {
+        db.create_category(&NewCategory { name: name.into(), parent_id, rules })
+            .unwrap()
+            .id
+    }
+
+    fn vendor_rule(vendor: &str) -> CategoryRule {
+        CategoryRule {
+            field: "vendor".into(),
+            equals: Some(vendor.into()),
+            contains: None,
+            regex: None,
+        }
+    }
+
+    fn quotation(db: &Database, vendor: &str, consumer: i64, contract: i64) -> i64 {
+        db.create_quotation(&NewQuotation {
+            quotation_id: format!("Q-{}", vendor),
+            vendor: Some(vendor.into()),
+            consumer_price: Some(Decimal::from(consumer)),
+            contract_amount: Some(Decimal::from(contract)),
+            ..Default::default()
+        })
+        .unwrap()
+        .id
+    }
+
+    fn item_ids(items: &[Quotation]) -> Vec<i64> {
+        items.iter().map(|q| q.id).collect()
+    }
+
+    fn sources(db: &Database, category_id: i64) -> Vec<(i64, String)> {
+        let conn = db.conn();
+        let mut stmt = conn
+            .prepare(
+                "SELECT quotation_id, source FROM category_items \
+                 WHERE category_id = ?1 ORDER BY quotation_id",
+            )
+            .unwrap();
+        let rows = stmt
+            .query_map([category_id], |row| Ok((row.get(0)?, row.get(1)?)))
+            .unwrap();
+        rows.collect::<Result<_, _>>().unwrap()
+    }
+
+    #[test]
+    fn cannot_reparent_under_own_subtree() {
+        let db = Database::open_in_memory().unwrap();
+        let root = category(&db, "소프트웨어", None, vec![]);
+        let child = category(&db, "DB", Some(root), vec![]);
+        let grandchild = category(&db, "오라클", Some(child), vec![]);
+
+        let reparent = |id: i64, name: &str, parent_id: i64| {
+            let category = NewCategory { name: name.into(), parent_id: Some(parent_id), rules: vec![] };
+            db.update_category(id, &category)
+        };
+        for parent_id in [root, grandchild] {
+            let err = reparent(root, "소프트웨어", parent_id).unwrap_err();
+            assert_eq!(err.code(), "INVALID_INPUT");
+        }
+        assert_eq!(reparent(root, "소프트웨어", 99).unwrap_err().code(), "NOT_FOUND");
+
+        // 형제나 다른 가지로 옮기는 것은 허용
+        let other = category(&db, "하드웨어", None, vec![]);
+        assert_eq!(reparent(grandchild, "오라클", other).unwrap().parent_id, Some(other));
+        assert_eq!(reparent(other, "하드웨어", child).unwrap().parent_id, Some(child));
+    }
+
+    #[test]
+    fn rules_assign_and_remove_items() {
+        let db = Database::open_in_memory().unwrap();
+        let a = quotation(&db, "가나상사", 1_000, 900);
+        let b = quotation(&db, "다라전자", 1_000, 800);
+        let id = category(&db, "가나", None, vec![vendor_rule(" 가나상사 ")]);
+        category(&db, "규칙 없음", None, vec![]);
+
+        let result = db.apply_category_rules(None).unwrap();
+        assert_eq!((result.categories, result.assigned, result.removed), (1, 1, 0));
+        assert_eq!(sources(&db, id), vec![(a, "rule".to_string())]);
+        // 다시 적용해도 변화 없음
+        let result = db.apply_category_rules(Some(id)).unwrap();
+        assert_eq!((result.assigned, result.removed), (0, 0));
+
+        let changed = NewCategory { name: "다라".into(), parent_id: None, rules: vec![vendor_rule("다라전자")] };
+        db.update_category(id, &changed).unwrap();
+        let result = db.apply_category_rules(Some(id)).unwrap();
+        assert_eq!((result.assigned, result.removed), (1, 1));
+        assert_eq!(sources(&db, id), vec![(b, "rule".to_string())]);
+
+        let rule = CategoryRule { field: "id".into(), ..vendor_rule("x") };
+        let invalid = NewCategory { name: "오류".into(), parent_id: None, rules: vec![rule] };
+        assert_eq!(db.create_category(&invalid).unwrap_err().code(), "INVALID_INPUT");
+    }
+
+    #[test]
+    fn manual_membership_survives_rule_changes() {
+        let db = Database::open_in_memory().unwrap();
+        let a = quotation(&db, "가나상사", 1_000, 900);
+        let b = quotation(&db, "다라전자", 1_000, 800);
+        let id = category(&db, "가나", None, vec![vendor_rule("가나상사")]);
+        db.apply_category_rules(Some(id)).unwrap();
+
+        // 규칙으로 들어온 항목을 직접 지정하면 manual로 바뀜
+        db.add_category_items(id, &[a, b]).unwrap();
+        assert_eq!(sources(&db, id), vec![(a, "manual".to_string()), (b, "manual".to_string())]);
+
+        let changed = NewCategory { name: "가나".into(), parent_id: None, rules: vec![vendor_rule("없음")] };
+        db.update_category(id, &changed).unwrap();
+        let result = db.apply_category_rules(Some(id)).unwrap();
+        assert_eq!((result.assigned, result.removed), (0, 0));
+        assert_eq!(item_ids(&db.list_category_items(id, false).unwrap()), vec![a, b]);
+
+        assert_eq!(db.add_category_items(id, &[99]).unwrap_err().code(), "NOT_FOUND");
+        db.remove_category_items(id, &[a]).unwrap();
+        assert_eq!(item_ids(&db.list_category_items(id, false).unwrap()), vec![b]);
+    }
+
+    #[test]
+    fn totals_cover_selected_subset() {
+        let db = Database::open_in_memory().unwrap();
+        let a = quotation(&db, "가나상사", 1_000, 900);
+        let b = quotation(&db, "다라전자", 2_000, 1_500);
+        let c = quotation(&db, "마바정보", 4_000, 3_000);
+        let root = category(&db, "전체", None, vec![]);
+        let child = category(&db, "하위", Some(root), vec![]);
+        db.add_category_items(root, &[a, b]).unwrap();
+        db.add_category_items(child, &[c]).unwrap();
+
+        let subset = db.select_category_items(root, Some(&[b]), false).unwrap();
+        let report = summarize(&subset, None);
+        assert_eq!(report.total.count, 1);
+        assert_eq!(report.total.contract_amount, Decimal::from(1_500));
+        assert_eq!(report.total.savings_rate, Some(Decimal::from(25)));
+
+        let all = db.select_category_items(root, None, true).unwrap();
+        assert_eq!(item_ids(&all), vec![a, b, c]);
+        let report = summarize(&all, None);
+        assert_eq!(report.total.savings_amount, Decimal::from(1_600));
+
+        // 하위 분류 항목은 include_descendants일 때만 선택 가능
+        let err = db.select_category_items(root, Some(&[a, c]), false).unwrap_err();
+        assert_eq!(err.code(), "INVALID_INPUT");
+        let subset = db.select_category_items(root, Some(&[a, c]), true).unwrap();
+        assert_eq!(summarize(&subset, None).total.count, 2);
+
+        // 분류를 삭제하면 하위 분류와 소속도 함께 삭제
+        db.delete_category(root).unwrap();
+        assert_eq!(db.get_category(child).unwrap_err().code(), "NOT_FOUND");
+        assert!(db.list_item_categories(c).unwrap().is_empty());
+    }
+}
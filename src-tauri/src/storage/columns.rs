@@ -0,0 +1,161 @@
+use rusqlite::{params, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+use super::{not_found, Database};
+use crate::error::AppError;
+
+// 컬럼 정의
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Column {
+    pub id: i64,
+    pub name: String,
+    pub data_type: String,
+    pub required: bool,
+    pub default_value: Option<String>,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewColumn {
+    pub name: String,
+    pub data_type: String,
+    #[serde(default)]
+    pub required: bool,
+    pub default_value: Option<String>,
+}
+
+const SELECT: &str = "SELECT id, name, data_type, required, default_value, created_at FROM columns";
+
+impl Column {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Column {
+            id: row.get("id")?,
+            name: row.get("name")?,
+            data_type: row.get("data_type")?,
+            required: row.get("required")?,
+            default_value: row.get("default_value")?,
+            created_at: row.get("created_at")?,
+        })
+    }
+}
+
+impl Database {
+    pub fn list_columns(&self) -> Result<Vec<Column>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY id", SELECT))?;
+        let rows = stmt
+            .query_map([], Column::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    pub fn get_column(&self, id: i64) -> Result<Column, AppError> {
+        self.conn()
+            .query_row(&format!("{} WHERE id = ?1", SELECT), [id], Column::from_row)
+            .optional()?
+            .ok_or_else(|| not_found("컬럼", id))
+    }
+
+    pub fn create_column(&self, column: &NewColumn) -> Result<Column, AppError> {
+        let id = {
+            let conn = self.conn();
+            conn.execute(
+                "INSERT INTO columns (name, data_type, required, default_value) VALUES (?1, ?2, ?3, ?4)",
+                params![column.name, column.data_type, column.required, column.default_value],
+            )?;
+            conn.last_insert_rowid()
+        };
+        self.get_column(id)
+    }
+
+    pub fn update_column(&self, id: i64, column: &NewColumn) -> Result<Column, AppError> {
+        let changed = self
+            .conn()
+            .execute(
+                "UPDATE columns SET name = ?1, data_type = ?2, required = ?3, default_value = ?4 WHERE id = ?5",
+                params![column.name, column.data_type, column.required, column.default_value, id],
+            )?;
+        if changed == 0 {
+            return Err(not_found("컬럼", id));
+        }
+        self.get_column(id)
+    }
+
+    pub fn delete_column(&self, id: i64) -> Result<(), AppError> {
+        self.conn()
+            .execute("DELETE FROM columns WHERE id = ?1", [id])?;
+        Ok(())
+    }
+}
+
+#[command]
+pub fn list_columns(db: State<'_, Database>) -> Result<Vec<Column>, AppError> {
+    db.list_columns()
+}
+
+#[command]
+pub fn get_column(db: State<'_, Database>, id: i64) -> Result<Column, AppError> {
+    db.get_column(id)
+}
+
+#[command]
+pub fn create_column(db: State<'_, Database>, column: NewColumn) -> Result<Column, AppError> {
+    db.create_column(&column)
+}
+
+#[command]
+pub fn update_column(db: State<'_, Database>, id: i64, column: NewColumn) -> Result<Column, AppError> {
+    db.update_column(id, &column)
+}
+
+#[command]
+pub fn delete_column(db: State<'_, Database>, id: i64) -> Result<(), AppError> {
+    db.delete_column(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_column(name: &str, required: bool) -> NewColumn {
+        NewColumn {
+            name: name.to_string(),
+            data_type: "string".to_string(),
+            required,
+            default_value: None,
+        }
+    }
+
+    #[test]
+    fn crud() {
+        let db = Database::open_in_memory().unwrap();
+        let seeded = db.list_columns().unwrap().len();
+
+        let created = db.create_column(&new_column("납기", false)).unwrap();
+        assert_eq!(created.name, "납기");
+        assert!(!created.required);
+        assert_eq!(db.get_column(created.id).unwrap().name, "납기");
+        assert_eq!(db.list_columns().unwrap().len(), seeded + 1);
+
+        let mut changed = new_column("납기일", true);
+        changed.default_value = Some("협의".into());
+        let updated = db.update_column(created.id, &changed).unwrap();
+        assert_eq!(updated.name, "납기일");
+        assert!(updated.required);
+        assert_eq!(updated.default_value.as_deref(), Some("협의"));
+
+        db.delete_column(created.id).unwrap();
+        assert_eq!(db.get_column(created.id).unwrap_err().code(), "NOT_FOUND");
+        assert_eq!(db.list_columns().unwrap().len(), seeded);
+    }
+
+    #[test]
+    fn update_missing_column_is_not_found() {
+        let db = Database::open_in_memory().unwrap();
+        let err = db.update_column(9999, &new_column("없음", false)).unwrap_err();
+        assert_eq!(err.code(), "NOT_FOUND");
+    }
+}
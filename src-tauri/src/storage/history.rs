@@ -0,0 +1,367 @@
+use rusqlite::{params, Connection, Row};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::collections::HashMap;
+use tauri::{command, State};
+
+use super::quotations::{NewQuotation, Quotation};
+use super::{not_found, Database};
+use crate::error::AppError;
+
+// 비교에서 제외하는 관리용 필드
+const SKIP_FIELDS: &[&str] = &["id", "version", "createdAt", "updatedAt"];
+
+// 필드 하나의 변경 내용 (필드 이름은 Quotation의 JSON 키)
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct FieldChange {
+    pub field: String,
+    pub before: Value,
+    pub after: Value,
+}
+
+// quotation_history 한 행. record는 수정 전 레코드, changes는 그 수정에서 바뀐 필드
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryEntry {
+    pub id: i64,
+    pub quotation_id: i64,
+    pub version: i64,
+    pub record: Quotation,
+    pub changes: Vec<FieldChange>,
+    pub source_file: Option<String>,
+    pub restored_from: Option<i64>,
+    pub created_at: Option<String>,
+}
+
+// 견적의 차수 하나. changes/sourceFile/restoredFrom은 이 차수를 만든 수정의 정보
+// (최초 등록이거나 이력이 남기 전의 차수는 비어 있음)
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotationVersion {
+    pub version: i64,
+    pub quotation: Quotation,
+    pub changes: Vec<FieldChange>,
+    pub source_file: Option<String>,
+    pub restored_from: Option<i64>,
+    pub current: bool,
+}
+
+const SELECT: &str = "SELECT id, quotation_id, version, data, changes, source_file, restored_from, \
+    created_at FROM quotation_history";
+
+fn json_column<T: serde::de::DeserializeOwned>(row: &Row, index: usize) -> rusqlite::Result<T> {
+    let text: String = row.get(index)?;
+    serde_json::from_str(&text).map_err(|e| {
+        rusqlite::Error::FromSqlConversionFailure(index, rusqlite::types::Type::Text, Box::new(e))
+    })
+}
+
+impl HistoryEntry {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(HistoryEntry {
+            id: row.get("id")?,
+            quotation_id: row.get("quotation_id")?,
+            version: row.get("version")?,
+            record: json_column(row, 3)?,
+            changes: json_column(row, 4)?,
+            source_file: row.get("source_file")?,
+            restored_from: row.get("restored_from")?,
+            created_at: row.get("created_at")?,
+        })
+    }
+}
+
+// 두 레코드에서 값이 다른 필드 (Quotation 필드 순서)
+pub fn diff(before: &Quotation, after: &Quotation) -> Vec<FieldChange> {
+    let before = serde_json::to_value(before).unwrap_or_default();
+    let after = serde_json::to_value(after).unwrap_or_default();
+    let (Value::Object(before), Value::Object(after)) = (before, after) else {
+        return Vec::new();
+    };
+
+    before
+        .into_iter()
+        .filter(|(field, _)| !SKIP_FIELDS.contains(&field.as_str()))
+        .filter_map(|(field, old)| {
+            let new = after.get(&field).cloned().unwrap_or(Value::Null);
+            (old != new).then_some(FieldChange {
+                field,
+                before: old,
+                after: new,
+            })
+        })
+        .collect()
+}
+
+// 수정 트랜잭션 안에서 수정 전 레코드와 변경 내용을 기록
+pub(crate) fn record(
+    conn: &Connection,
+    before: &Quotation,
+    after: &Quotation,
+    source_file: Option<&str>,
+    restored_from: Option<i64>,
+) -> Result<(), AppError> {
+    let data = serde_json::to_string(before)?;
+    let changes = serde_json::to_string(&diff(before, after))?;
+    conn.execute(
+        "INSERT INTO quotation_history (quotation_id, version, data, changes, source_file, \
+         restored_from) VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+        params![before.id, before.version, data, changes, source_file, restored_from],
+    )?;
+    Ok(())
+}
+
+impl Database {
+    pub fn list_quotation_history(&self, id: i64) -> Result<Vec<HistoryEntry>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!("{} WHERE quotation_id = ?1 ORDER BY version", SELECT))?;
+        let rows = stmt
+            .query_map([id], HistoryEntry::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    // 모든 견적의 이력을 견적 id별로 (차수 오름차순)
+    pub fn quotation_history_map(&self) -> Result<HashMap<i64, Vec<HistoryEntry>>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY quotation_id, version", SELECT))?;
+        let rows = stmt
+            .query_map([], HistoryEntry::from_row)?;
+
+        let mut map: HashMap<i64, Vec<HistoryEntry>> = HashMap::new();
+        for entry in rows {
+            let entry = entry?;
+            map.entry(entry.quotation_id).or_default().push(entry);
+        }
+        Ok(map)
+    }
+
+    // 이력에 남은 차수와 현재 차수를 오래된 순으로 반환
+    pub fn list_quotation_versions(&self, id: i64) -> Result<Vec<QuotationVersion>, AppError> {
+        let current = self.get_quotation(id)?;
+        let history = self.list_quotation_history(id)?;
+
+        let mut versions: Vec<QuotationVersion> = history
+            .iter()
+            .map(|entry| QuotationVersion {
+                version: entry.version,
+                quotation: entry.record.clone(),
+                changes: Vec::new(),
+                source_file: None,
+                restored_from: None,
+                current: false,
+            })
+            .collect();
+        versions.push(QuotationVersion {
+            version: current.version,
+            quotation: current,
+            changes: Vec::new(),
+            source_file: None,
+            restored_from: None,
+            current: true,
+        });
+
+        // 이력 행은 다음 차수를 만든 수정의 정보를 담고 있음
+        for entry in history {
+            if let Some(next) = versions.iter_mut().find(|v| v.version == entry.version + 1) {
+                next.changes = entry.changes;
+                next.source_file = entry.source_file;
+                next.restored_from = entry.restored_from;
+            }
+        }
+        Ok(versions)
+    }
+
+    // 특정 차수의 레코드 (현재 차수이면 현재 레코드)
+    pub fn get_quotation_version(&self, id: i64, version: i64) -> Result<Quotation, AppError> {
+        let current = self.get_quotation(id)?;
+        if current.version == version {
+            return Ok(current);
+        }
+        self.list_quotation_history(id)?
+            .into_iter()
+            .find(|entry| entry.version == version)
+            .map(|entry| entry.record)
+            .ok_or_else(|| not_found("견적 차수", version))
+    }
+
+    pub fn diff_quotation_versions(
+        &self,
+        id: i64,
+        from: i64,
+        to: i64,
+    ) -> Result<Vec<FieldChange>, AppError> {
+        let before = self.get_quotation_version(id, from)?;
+        let after = self.get_quotation_version(id, to)?;
+        Ok(diff(&before, &after))
+    }
+
+    // 이전 차수의 내용으로 새 차수를 만듦 (기존 이력은 그대로 유지)
+    pub fn restore_quotation_version(&self, id: i64, version: i64) -> Result<Quotation, AppError> {
+        let current = self.get_quotation(id)?;
+        if current.version == version {
+            return Err(AppError::invalid(format!("이미 현재 차수입니다: version={}", version)));
+        }
+        let snapshot = self.get_quotation_version(id, version)?;
+        self.revise_quotation(id, &NewQuotation::from(&snapshot), None, Some(version))
+    }
+}
+
+// 견적이 삭제되어도 이력은 남으므로 현재 레코드 없이도 조회 가능
+#[command]
+pub fn list_quotation_history(
+    db: State<'_, Database>,
+    id: i64,
+) -> Result<Vec<HistoryEntry>, AppError> {
+    db.list_quotation_history(id)
+}
+
+#[command]
+pub fn list_quotation_versions(
+    db: State<'_, Database>,
+    id: i64,
+) -> Result<Vec<QuotationVersion>, AppError> {
+    db.list_quotation_versions(id)
+}
+
+#[command]
+pub fn get_quotation_version(
+    db: State<'_, Database>,
+    id: i64,
+    version: i64,
+) -> Result<Quotation, AppError> {
+    db.get_quotation_version(id, version)
+}
+
+#[command]
+pub fn diff_quotation_versions(
+    db: State<'_, Database>,
+    id: i64,
+    from: i64,
+    to: i64,
+) -> Result<Vec<FieldChange>, AppError> {
+    db.diff_quotation_versions(id, from, to)
+}
+
+#[command]
+pub fn restore_quotation_version(
+    db: State<'_, Database>,
+    id: i64,
+    version: i64,
+) -> Result<Quotation, AppError> {
+    db.restore_quotation_version(id, version)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_decimal::Decimal;
+    use serde_json::json;
+
+    fn new_quotation(vendor: &str, contract: i64) -> NewQuotation {
+        NewQuotation {
+            quotation_id: "Q-1".into(),
+            vendor: Some(vendor.into()),
+            consumer_price: Some(Decimal::from(1_000)),
+            contract_amount: Some(Decimal::from(contract)),
+            ..Default::default()
+        }
+    }
+
+    fn fields(changes: &[FieldChange]) -> Vec<&str> {
+        changes.iter().map(|c| c.field.as_str()).collect()
+    }
+
+    #[test]
+    fn update_records_previous_version() {
+        let db = Database::open_in_memory().unwrap();
+        let created = db.create_quotation(&new_quotation("가나상사", 900)).unwrap();
+        assert!(db.list_quotation_history(created.id).unwrap().is_empty());
+
+        db.update_quotation(created.id, &new_quotation("가나상사", 800), Some("v2.xlsx"))
+            .unwrap();
+        let history = db.list_quotation_history(created.id).unwrap();
+        assert_eq!(history.len(), 1);
+        let entry = &history[0];
+        assert_eq!((entry.quotation_id, entry.version), (created.id, 1));
+        assert_eq!(entry.record.contract_amount, Some(Decimal::from(900)));
+        assert_eq!(entry.source_file.as_deref(), Some("v2.xlsx"));
+        assert_eq!(entry.restored_from, None);
+        assert_eq!(fields(&entry.changes), vec!["contractAmount", "savingsAmount"]);
+
+        // 이력은 트리거로 수정/삭제가 막혀 있음
+        let conn = db.conn();
+        assert!(conn.execute("UPDATE quotation_history SET version = 9", []).is_err());
+        assert!(conn.execute("DELETE FROM quotation_history", []).is_err());
+    }
+
+    #[test]
+    fn diff_lists_changed_fields() {
+        let db = Database::open_in_memory().unwrap();
+        let created = db.create_quotation(&new_quotation("가나상사", 900)).unwrap();
+        let mut changed = new_quotation("다라전자", 900);
+        changed.special_notes = Some("단가 조정".into());
+        db.update_quotation(created.id, &changed, None).unwrap();
+
+        let changes = db.diff_quotation_versions(created.id, 1, 2).unwrap();
+        let changed: Vec<(&str, &Value, &Value)> = changes
+            .iter()
+            .map(|c| (c.field.as_str(), &c.before, &c.after))
+            .collect();
+        assert_eq!(
+            changed,
+            vec![
+                ("vendor", &json!("가나상사"), &json!("다라전자")),
+                ("specialNotes", &Value::Null, &json!("단가 조정")),
+            ]
+        );
+        // 관리용 필드(차수, 수정 시각)는 비교하지 않고, 같은 차수끼리는 변경 없음
+        assert!(db.diff_quotation_versions(created.id, 2, 2).unwrap().is_empty());
+        let reverse = db.diff_quotation_versions(created.id, 2, 1).unwrap();
+        assert_eq!(reverse[0].after, json!("가나상사"));
+        let err = db.diff_quotation_versions(created.id, 1, 5).unwrap_err();
+        assert_eq!(err.code(), "NOT_FOUND");
+    }
+
+    #[test]
+    fn restore_creates_new_version() {
+        let db = Database::open_in_memory().unwrap();
+        let created = db.create_quotation(&new_quotation("가나상사", 900)).unwrap();
+        db.update_quotation(created.id, &new_quotation("다라전자", 800), Some("v2.xlsx"))
+            .unwrap();
+
+        let restored = db.restore_quotation_version(created.id, 1).unwrap();
+        assert_eq!(restored.version, 3);
+        assert_eq!(restored.vendor.as_deref(), Some("가나상사"));
+        assert_eq!(restored.contract_amount, Some(Decimal::from(900)));
+
+        // 기존 이력은 그대로 두고 되돌리기 전 차수를 새로 기록
+        let history = db.list_quotation_history(created.id).unwrap();
+        let recorded: Vec<(i64, Option<&str>, Option<i64>)> = history
+            .iter()
+            .map(|e| (e.version, e.record.vendor.as_deref(), e.restored_from))
+            .collect();
+        assert_eq!(recorded, vec![(1, Some("가나상사"), None), (2, Some("다라전자"), Some(1))]);
+
+        let versions = db.list_quotation_versions(created.id).unwrap();
+        let summary: Vec<(i64, bool, Option<&str>, Option<i64>)> = versions
+            .iter()
+            .map(|v| (v.version, v.current, v.source_file.as_deref(), v.restored_from))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (1, false, None, None),
+                (2, false, Some("v2.xlsx"), None),
+                (3, true, None, Some(1)),
+            ]
+        );
+        assert_eq!(fields(&versions[2].changes), vec!["vendor", "contractAmount", "savingsAmount"]);
+
+        let err = db.restore_quotation_version(created.id, 3).unwrap_err();
+        assert_eq!(err.code(), "INVALID_INPUT");
+        assert_eq!(db.restore_quotation_version(created.id, 7).unwrap_err().code(), "NOT_FOUND");
+    }
+}
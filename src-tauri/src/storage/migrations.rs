@@ -0,0 +1,579 @@
+use chrono::{Datelike, Duration, Months, NaiveDate};
+use regex::Regex;
+use rusqlite::{params, Connection, Transaction};
+
+use super::SqlMoney;
+use crate::error::AppError;
+
+// 순서대로 적용되는 스키마 마이그레이션.
+// 적용된 버전은 PRAGMA user_version 에 기록하며, 한 번 배포된 항목은 수정하지 말고
+// 새 버전을 목록 끝에 추가한다.
+pub struct Migration {
+    pub version: i64,
+    pub description: &'static str,
+    pub up: fn(&Transaction) -> rusqlite::Result<()>,
+}
+
+pub const MIGRATIONS: &[Migration] = &[
+    Migration {
+        version: 1,
+        description: "초기 스키마",
+        up: initial_schema,
+    },
+    Migration {
+        version: 2,
+        description: "quotations 절감액/차수 컬럼 보강",
+        up: quotation_savings_and_version,
+    },
+    Migration {
+        version: 3,
+        description: "quotations 금액 컬럼을 decimal 텍스트로 변경",
+        up: quotation_decimal_amounts,
+    },
+    Migration {
+        version: 4,
+        description: "무상 유지보수 기간 개월 수 컬럼 추가",
+        up: quotation_maintenance_months,
+    },
+    Migration {
+        version: 5,
+        description: "견적 변경 이력 테이블 추가",
+        up: quotation_history,
+    },
+    Migration {
+        version: 6,
+        description: "사용자 정의 분류와 분류별 견적 항목 테이블 추가",
+        up: categories,
+    },
+    Migration {
+        version: 7,
+        description: "quotations 견적일 컬럼 추가",
+        up: quotation_quote_date,
+    },
+];
+
+// 이 앱이 알고 있는 최신 스키마 버전
+pub fn latest_version() -> i64 {
+    MIGRATIONS.last().map(|m| m.version).unwrap_or(0)
+}
+
+pub fn current_version(conn: &Connection) -> Result<i64, AppError> {
+    conn.query_row("PRAGMA user_version", [], |row| row.get(0))
+        .map_err(AppError::from)
+}
+
+// 미적용 마이그레이션을 각각 하나의 트랜잭션으로 적용.
+// 실패하면 해당 마이그레이션은 롤백되고 기존 데이터는 그대로 남는다.
+pub fn migrate(conn: &mut Connection) -> Result<i64, AppError> {
+    let current = current_version(conn)?;
+    let latest = latest_version();
+
+    if current > latest {
+        return Err(AppError::invalid(format!(
+            "데이터베이스 스키마(v{})가 이 앱이 지원하는 버전(v{})보다 새롭습니다. 앱을 최신 버전으로 업데이트하세요.",
+            current, latest
+        )));
+    }
+
+    for migration in MIGRATIONS.iter().filter(|m| m.version > current) {
+        let tx = conn.transaction()?;
+        (migration.up)(&tx).map_err(|e| {
+            AppError::Database(format!(
+                "마이그레이션 v{} ({}) 실패: {}",
+                migration.version, migration.description, e
+            ))
+        })?;
+        // PRAGMA는 바인딩 파라미터를 받지 않으므로 정수를 직접 넣음
+        tx.execute_batch(&format!("PRAGMA user_version = {}", migration.version))?;
+        tx.commit()?;
+
+        log::info!(
+            "DB 마이그레이션 적용: v{} {}",
+            migration.version,
+            migration.description
+        );
+    }
+
+    Ok(latest)
+}
+
+fn has_column(tx: &Transaction, table: &str, column: &str) -> rusqlite::Result<bool> {
+    Ok(table_columns(tx, table)?.iter().any(|(name, _)| name == column))
+}
+
+// 테이블의 (컬럼 이름, 선언된 타입) 목록
+fn table_columns(tx: &Transaction, table: &str) -> rusqlite::Result<Vec<(String, String)>> {
+    let mut stmt = tx.prepare(&format!("PRAGMA table_info({})", table))?;
+    let columns = stmt.query_map([], |row| Ok((row.get::<_, String>("name")?, row.get::<_, String>("type")?)))?;
+    columns.collect()
+}
+
+// v1: shared/schema-sqlite.ts 와 같은 초기 스키마
+const V1_INITIAL_SCHEMA: &str = "
+    CREATE TABLE IF NOT EXISTS columns (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        name TEXT NOT NULL,
+        data_type TEXT NOT NULL,
+        required INTEGER NOT NULL DEFAULT 0,
+        default_value TEXT,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE TABLE IF NOT EXISTS templates (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        name TEXT NOT NULL,
+        mapping_data TEXT NOT NULL,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE TABLE IF NOT EXISTS quotations (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        quotation_id TEXT NOT NULL,
+        solution TEXT,
+        category TEXT,
+        partner TEXT,
+        vendor TEXT,
+        main_product TEXT,
+        quantity INTEGER,
+        consumer_price REAL,
+        contract_amount REAL,
+        savings_amount REAL,
+        free_maintenance_period TEXT,
+        vendor_contact TEXT,
+        vendor_email TEXT,
+        partner_contact TEXT,
+        partner_email TEXT,
+        special_notes TEXT,
+        version INTEGER NOT NULL DEFAULT 1,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+
+    CREATE TABLE IF NOT EXISTS uploaded_files (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        filename TEXT NOT NULL,
+        size INTEGER NOT NULL,
+        status TEXT NOT NULL,
+        template_id INTEGER,
+        uploaded_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        FOREIGN KEY (template_id) REFERENCES templates (id)
+    );
+
+    CREATE TABLE IF NOT EXISTS quotes (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        file_id INTEGER NOT NULL,
+        template_id INTEGER NOT NULL,
+        data TEXT NOT NULL,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT,
+        FOREIGN KEY (file_id) REFERENCES uploaded_files (id),
+        FOREIGN KEY (template_id) REFERENCES templates (id)
+    );
+";
+
+// Express 서버가 만든 기존 DB도 그대로 열 수 있도록 IF NOT EXISTS 사용
+fn initial_schema(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(V1_INITIAL_SCHEMA)
+}
+
+// 초기 버전 DB에는 savings_amount / version 컬럼이 없을 수 있음
+fn quotation_savings_and_version(tx: &Transaction) -> rusqlite::Result<()> {
+    if !has_column(tx, "quotations", "savings_amount")? {
+        tx.execute_batch("ALTER TABLE quotations ADD COLUMN savings_amount REAL")?;
+    }
+    if !has_column(tx, "quotations", "version")? {
+        tx.execute_batch("ALTER TABLE quotations ADD COLUMN version INTEGER NOT NULL DEFAULT 1")?;
+    }
+    Ok(())
+}
+
+const QUOTATION_COLUMNS: &str = "id, quotation_id, solution, category, partner, vendor, \
+    main_product, quantity, consumer_price, contract_amount, savings_amount, \
+    free_maintenance_period, vendor_contact, vendor_email, partner_contact, partner_email, \
+    special_notes, version, created_at, updated_at";
+
+// v3: REAL 컬럼은 합계에서 원 단위 오차가 생기므로 TEXT(decimal 문자열)로 재생성
+const V3_QUOTATIONS: &str = "
+    CREATE TABLE quotations_v3 (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        quotation_id TEXT NOT NULL,
+        solution TEXT,
+        category TEXT,
+        partner TEXT,
+        vendor TEXT,
+        main_product TEXT,
+        quantity INTEGER,
+        consumer_price TEXT,
+        contract_amount TEXT,
+        savings_amount TEXT,
+        free_maintenance_period TEXT,
+        vendor_contact TEXT,
+        vendor_email TEXT,
+        partner_contact TEXT,
+        partner_email TEXT,
+        special_notes TEXT,
+        version INTEGER NOT NULL DEFAULT 1,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        updated_at TEXT DEFAULT CURRENT_TIMESTAMP
+    );
+";
+
+fn quotation_decimal_amounts(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(V3_QUOTATIONS)?;
+
+    // 이 스키마에 없는 컬럼(직접 추가한 컬럼 등)도 버리지 않고 같은 타입으로 옮김
+    let known: Vec<&str> = QUOTATION_COLUMNS.split(',').map(str::trim).collect();
+    let mut columns: Vec<String> = known.iter().map(|name| name.to_string()).collect();
+    for (name, declared) in table_columns(tx, "quotations")? {
+        if known.contains(&name.as_str()) {
+            continue;
+        }
+        log::warn!("v3 마이그레이션: 알 수 없는 quotations 컬럼 '{}'을(를) 그대로 옮깁니다", name);
+        let quoted = format!("\"{}\"", name.replace('"', "\"\""));
+        tx.execute_batch(&format!("ALTER TABLE quotations_v3 ADD COLUMN {} {}", quoted, declared))?;
+        columns.push(quoted);
+    }
+
+    tx.execute_batch(&format!(
+        "INSERT INTO quotations_v3 ({cols}) SELECT {cols} FROM quotations;
+         DROP TABLE quotations;
+         ALTER TABLE quotations_v3 RENAME TO quotations;",
+        cols = columns.join(", ")
+    ))?;
+
+    // TEXT 컬럼으로 옮기면서 "1234000.0" 형태가 된 값을 정규화.
+    // 숫자로 해석할 수 없는 값은 지우지 않고 그대로 둔다.
+    for column in ["consumer_price", "contract_amount", "savings_amount"] {
+        let amounts = {
+            let mut stmt = tx.prepare(&format!(
+                "SELECT id, {} FROM quotations WHERE {} IS NOT NULL",
+                column, column
+            ))?;
+            let rows = stmt.query_map([], |row| {
+                Ok((row.get::<_, i64>(0)?, row.get::<_, SqlMoney>(1).ok()))
+            })?;
+            rows.collect::<rusqlite::Result<Vec<_>>>()?
+        };
+
+        let mut update = tx.prepare(&format!("UPDATE quotations SET {} = ?1 WHERE id = ?2", column))?;
+        for (id, amount) in amounts {
+            if let Some(amount) = amount {
+                update.execute(params![amount, id])?;
+            }
+        }
+    }
+    Ok(())
+}
+
+// 자유 형식의 free_maintenance_period를 조회/정렬할 수 있게 개월 수로 저장
+fn quotation_maintenance_months(tx: &Transaction) -> rusqlite::Result<()> {
+    if !has_column(tx, "quotations", "free_maintenance_months")? {
+        tx.execute_batch("ALTER TABLE quotations ADD COLUMN free_maintenance_months INTEGER")?;
+    }
+
+    let periods = {
+        let mut stmt = tx.prepare(
+            "SELECT id, free_maintenance_period FROM quotations \
+             WHERE free_maintenance_period IS NOT NULL",
+        )?;
+        let rows = stmt.query_map([], |row| Ok((row.get::<_, i64>(0)?, row.get::<_, String>(1)?)))?;
+        rows.collect::<rusqlite::Result<Vec<_>>>()?
+    };
+
+    let parser = V4PeriodParser::new();
+    let mut update =
+        tx.prepare("UPDATE quotations SET free_maintenance_months = ?1 WHERE id = ?2")?;
+    for (id, period) in periods {
+        if let Some(months) = parser.months(&period) {
+            update.execute(params![months, id])?;
+        }
+    }
+    Ok(())
+}
+
+// v4 작성 시점의 dates::parse_period(연-월-일 순서)를 그대로 옮긴 사본.
+// 앱의 해석기가 바뀌어도 마이그레이션 결과가 달라지지 않도록 이 코드는 수정하지 않는다.
+struct V4PeriodParser {
+    unit: Regex,
+    korean: Regex,
+    separated: Regex,
+    trailer: Regex,
+}
+
+impl V4PeriodParser {
+    fn new() -> Self {
+        let regex = |pattern: &str| Regex::new(pattern).expect("valid v4 regex");
+        V4PeriodParser {
+            unit: regex(r"(?i)(\d+(?:\.\d+)?)\s*(개월|달|months?|mos?|년|years?|yrs?)"),
+            korean: regex(r"^(\d{2,4})\s*년\s*(\d{1,2})\s*월\s*(\d{1,2})\s*일?"),
+            separated: regex(r"^(\d{1,4})\s*[./-]\s*(\d{1,4})\s*[./-]\s*(\d{1,4})\.?"),
+            trailer: regex(r"^\s*(\(?[월화수목금토일](요일)?\)?)?\s*([T ]?\d{1,2}:\d{2}(:\d{2}(\.\d+)?)?)?\s*$"),
+        }
+    }
+
+    fn months(&self, text: &str) -> Option<u32> {
+        let normalized = half_width(text.trim());
+        if let Ok(months) = normalized.parse::<u32>() {
+            return Some(months);
+        }
+
+        if let Some((start, end)) = normalized.split_once('~') {
+            let start = self.date(start)?;
+            let end = self.date(end)?;
+            let next = end.succ_opt()?;
+            let diff = (next.year() - start.year()) * 12 + next.month() as i32 - start.month() as i32;
+            let months = u32::try_from(diff).ok()?;
+            return (start.checked_add_months(Months::new(months))? == next).then_some(months);
+        }
+
+        let mut expressions: Vec<f64> = Vec::new();
+        let mut last_end: Option<usize> = None;
+        for captures in self.unit.captures_iter(&normalized) {
+            let whole = captures.get(0)?;
+            let value: f64 = captures[1].parse().ok()?;
+            let months = match captures[2].to_lowercase().as_str() {
+                "년" | "year" | "years" | "yr" | "yrs" => value * 12.0,
+                _ => value,
+            };
+            let joined = last_end.is_some_and(|end| normalized[end..whole.start()].trim().is_empty());
+            match expressions.last_mut() {
+                Some(total) if joined => *total += months,
+                _ => expressions.push(months),
+            }
+            last_end = Some(whole.end());
+        }
+
+        let first = expressions.first().copied()?;
+        if expressions.iter().any(|months| (months - first).abs() > f64::EPSILON) {
+            return None;
+        }
+        if first.fract() != 0.0 || first < 0.0 {
+            return None;
+        }
+        Some(first as u32)
+    }
+
+    fn date(&self, text: &str) -> Option<NaiveDate> {
+        let normalized = half_width(text.trim());
+        let trailer_ok = |end: usize| self.trailer.is_match(&normalized[end..]);
+
+        if let Some(captures) = self.korean.captures(&normalized) {
+            if !trailer_ok(captures[0].len()) {
+                return None;
+            }
+            return ymd(&captures[1], &captures[2], &captures[3]);
+        }
+        if let Some(captures) = self.separated.captures(&normalized) {
+            if !trailer_ok(captures[0].len()) {
+                return None;
+            }
+            let (a, b, c) = (&captures[1], &captures[2], &captures[3]);
+            if a.len() == 4 {
+                return ymd(a, b, c);
+            }
+            if c.len() <= 2 {
+                if let Some(date) = ymd(a, b, c) {
+                    return Some(date);
+                }
+            }
+            // 연-월-일로 해석되지 않으면 일-월-연 / 월-일-연 중 하나만 가능할 때 사용
+            return match (ymd(c, b, a), ymd(c, a, b)) {
+                (Some(date), None) | (None, Some(date)) => Some(date),
+                _ => None,
+            };
+        }
+        if normalized.chars().all(|c| c.is_ascii_digit()) {
+            if normalized.len() == 8 {
+                return ymd(&normalized[..4], &normalized[4..6], &normalized[6..]);
+            }
+            if normalized.len() <= 5 {
+                let serial: f64 = normalized.parse().ok()?;
+                if !(1.0..2_958_466.0).contains(&serial) {
+                    return None;
+                }
+                return NaiveDate::from_ymd_opt(1899, 12, 30)?
+                    .checked_add_signed(Duration::days(serial.floor() as i64));
+            }
+        }
+        None
+    }
+}
+
+fn ymd(y: &str, m: &str, d: &str) -> Option<NaiveDate> {
+    let value: i32 = y.parse().ok()?;
+    let year = match y.len() {
+        4 => value,
+        1 | 2 if value < 70 => 2000 + value,
+        1 | 2 => 1900 + value,
+        _ => return None,
+    };
+    NaiveDate::from_ymd_opt(year, m.parse().ok()?, d.parse().ok()?)
+}
+
+fn half_width(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{3000}' => ' ',
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+// v5: 견적을 수정할 때마다 수정 전 레코드를 남기는 이력 테이블.
+// 견적을 삭제해도 이력은 남도록 외래 키를 두지 않고, 트리거로 수정/삭제를 막는다.
+const V5_QUOTATION_HISTORY: &str = "
+    CREATE TABLE IF NOT EXISTS quotation_history (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        quotation_id INTEGER NOT NULL,
+        version INTEGER NOT NULL,
+        data TEXT NOT NULL,
+        changes TEXT NOT NULL,
+        source_file TEXT,
+        restored_from INTEGER,
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        UNIQUE (quotation_id, version)
+    );
+
+    CREATE TRIGGER IF NOT EXISTS quotation_history_no_update
+    BEFORE UPDATE ON quotation_history
+    BEGIN
+        SELECT RAISE(ABORT, '견적 이력은 수정할 수 없습니다');
+    END;
+
+    CREATE TRIGGER IF NOT EXISTS quotation_history_no_delete
+    BEFORE DELETE ON quotation_history
+    BEGIN
+        SELECT RAISE(ABORT, '견적 이력은 삭제할 수 없습니다');
+    END;
+";
+
+fn quotation_history(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(V5_QUOTATION_HISTORY)
+}
+
+// v6: 계층형 사용자 분류. 항목은 여러 분류에 속할 수 있고,
+// source는 직접 지정('manual')인지 분류 규칙으로 지정('rule')되었는지 구분
+const V6_CATEGORIES: &str = "
+    CREATE TABLE IF NOT EXISTS categories (
+        id INTEGER PRIMARY KEY AUTOINCREMENT,
+        name TEXT NOT NULL,
+        parent_id INTEGER,
+        rules TEXT NOT NULL DEFAULT '[]',
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        FOREIGN KEY (parent_id) REFERENCES categories (id) ON DELETE CASCADE
+    );
+
+    CREATE TABLE IF NOT EXISTS category_items (
+        category_id INTEGER NOT NULL,
+        quotation_id INTEGER NOT NULL,
+        source TEXT NOT NULL DEFAULT 'manual',
+        created_at TEXT DEFAULT CURRENT_TIMESTAMP,
+        PRIMARY KEY (category_id, quotation_id),
+        FOREIGN KEY (category_id) REFERENCES categories (id) ON DELETE CASCADE,
+        FOREIGN KEY (quotation_id) REFERENCES quotations (id) ON DELETE CASCADE
+    );
+
+    CREATE INDEX IF NOT EXISTS idx_category_items_quotation ON category_items (quotation_id);
+";
+
+fn categories(tx: &Transaction) -> rusqlite::Result<()> {
+    tx.execute_batch(V6_CATEGORIES)
+}
+
+// v7: 추이를 등록일이 아니라 견적(계약)일 기준으로 묶기 위한 날짜 컬럼
+fn quotation_quote_date(tx: &Transaction) -> rusqlite::Result<()> {
+    if !has_column(tx, "quotations", "quote_date")? {
+        tx.execute_batch("ALTER TABLE quotations ADD COLUMN quote_date TEXT")?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // Express 서버가 만들던 스키마 (user_version = 0, 절감액/차수 컬럼 없음, REAL 금액)
+    const V0_SCHEMA: &str = "
+        CREATE TABLE columns (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL,
+            data_type TEXT NOT NULL, required INTEGER NOT NULL DEFAULT 0, default_value TEXT,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP);
+        CREATE TABLE templates (id INTEGER PRIMARY KEY AUTOINCREMENT, name TEXT NOT NULL,
+            mapping_data TEXT NOT NULL, created_at TEXT DEFAULT CURRENT_TIMESTAMP);
+        CREATE TABLE quotations (id INTEGER PRIMARY KEY AUTOINCREMENT, quotation_id TEXT NOT NULL,
+            solution TEXT, category TEXT, partner TEXT, vendor TEXT, main_product TEXT,
+            quantity INTEGER, consumer_price REAL, contract_amount REAL,
+            free_maintenance_period TEXT, vendor_contact TEXT, vendor_email TEXT,
+            partner_contact TEXT, partner_email TEXT, special_notes TEXT,
+            created_at TEXT DEFAULT CURRENT_TIMESTAMP, updated_at TEXT DEFAULT CURRENT_TIMESTAMP,
+            memo TEXT);
+        INSERT INTO templates (name, mapping_data) VALUES ('기본', '{}');
+        INSERT INTO quotations (quotation_id, vendor, consumer_price, contract_amount,
+            free_maintenance_period, memo)
+        VALUES ('Q-1', '가나상사', 1500000.0, 1234000.0, '계약일로부터 1년', '확인 필요'),
+               ('Q-2', '다라전자', NULL, 990.5, '2025.01.01 ~ 2025.12.31', NULL),
+               ('Q-3', '마바정보', 10, 7, '협의', NULL);
+    ";
+
+    fn quotation(conn: &Connection, quotation_id: &str) -> (Option<String>, Option<String>, Option<i64>, i64, Option<String>) {
+        conn.query_row(
+            "SELECT consumer_price, contract_amount, free_maintenance_months, version, memo \
+             FROM quotations WHERE quotation_id = ?1",
+            [quotation_id],
+            |row| Ok((row.get(0)?, row.get(1)?, row.get(2)?, row.get(3)?, row.get(4)?)),
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn migrates_v0_database_to_latest() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(V0_SCHEMA).unwrap();
+        assert_eq!(current_version(&conn).unwrap(), 0);
+
+        assert_eq!(migrate(&mut conn).unwrap(), latest_version());
+        assert_eq!(current_version(&conn).unwrap(), latest_version());
+
+        assert_eq!(
+            quotation(&conn, "Q-1"),
+            (Some("1500000".into()), Some("1234000".into()), Some(12), 1, Some("확인 필요".into()))
+        );
+        assert_eq!(quotation(&conn, "Q-2"), (None, Some("990.5".into()), Some(12), 1, None));
+        assert_eq!(quotation(&conn, "Q-3"), (Some("10".into()), Some("7".into()), None, 1, None));
+
+        let template: String = conn
+            .query_row("SELECT name FROM templates", [], |row| row.get(0))
+            .unwrap();
+        assert_eq!(template, "기본");
+        for table in ["quotation_history", "categories", "category_items"] {
+            let count: i64 = conn
+                .query_row(&format!("SELECT COUNT(*) FROM {}", table), [], |row| row.get(0))
+                .unwrap();
+            assert_eq!(count, 0);
+        }
+
+        // 이미 최신이면 아무것도 하지 않음
+        assert_eq!(migrate(&mut conn).unwrap(), latest_version());
+    }
+
+    #[test]
+    fn refuses_newer_schema() {
+        let mut conn = Connection::open_in_memory().unwrap();
+        conn.execute_batch(&format!("PRAGMA user_version = {}", latest_version() + 1))
+            .unwrap();
+        assert_eq!(migrate(&mut conn).unwrap_err().code(), "INVALID_INPUT");
+    }
+
+    #[test]
+    fn v4_period_parser_is_frozen() {
+        let parser = V4PeriodParser::new();
+        assert_eq!(parser.months("12"), Some(12));
+        assert_eq!(parser.months("2년 6개월"), Some(30));
+        assert_eq!(parser.months("1년(12개월)"), Some(12));
+        assert_eq!(parser.months("３６ months"), Some(36));
+        assert_eq!(parser.months("2025.01.01 ~ 2025.12.31"), Some(12));
+        assert_eq!(parser.months("2025년 1월 1일 ~ 2025년 6월 30일"), Some(6));
+        assert_eq!(parser.months("1년 또는 6개월"), None);
+        assert_eq!(parser.months("협의"), None);
+    }
+}
@@ -0,0 +1,174 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::Connection;
+use rust_decimal::prelude::FromPrimitive;
+use rust_decimal::Decimal;
+use std::fs;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::{Mutex, MutexGuard};
+use tauri::{AppHandle, Manager, Runtime};
+
+use crate::error::AppError;
+
+pub mod categories;
+pub mod columns;
+pub mod history;
+pub mod migrations;
+pub mod quotations;
+pub mod quotes;
+pub mod templates;
+pub mod uploaded_files;
+
+// 앱 데이터 디렉터리 아래 DB 파일 이름 (Express 서버의 app-data/quote-manager.db와 동일)
+pub const DB_FILE_NAME: &str = "quote-manager.db";
+
+// 기본 컬럼 정의 (컬럼 테이블이 비어 있을 때만 추가)
+const DEFAULT_COLUMNS: &[(&str, &str, bool)] = &[
+    ("견적ID", "string", true),
+    ("솔루션", "string", false),
+    ("카테고리", "string", false),
+    ("파트너사", "string", false),
+    ("벤더", "string", false),
+    ("주요제품", "string", false),
+    ("수량", "number", false),
+    ("소비자가격", "number", false),
+    ("계약금액", "number", false),
+    ("절감액", "number", false),
+];
+
+// Tauri 상태로 관리되는 로컬 SQLite 연결
+pub struct Database {
+    conn: Mutex<Connection>,
+}
+
+impl Database {
+    pub fn open(path: &Path) -> Result<Self, AppError> {
+        let conn = Connection::open(path)?;
+        Self::init(conn)
+    }
+
+    pub fn open_in_memory() -> Result<Self, AppError> {
+        let conn = Connection::open_in_memory()?;
+        Self::init(conn)
+    }
+
+    // 앱 데이터 디렉터리에 DB 파일을 만들고 연결
+    pub fn open_in_app_dir<R: Runtime>(app: &AppHandle<R>) -> Result<Self, AppError> {
+        let dir = app.path().app_data_dir()?;
+        fs::create_dir_all(&dir)?;
+        Self::open(&dir.join(DB_FILE_NAME))
+    }
+
+    fn init(mut conn: Connection) -> Result<Self, AppError> {
+        conn.execute_batch("PRAGMA foreign_keys = ON;")?;
+        migrations::migrate(&mut conn)?;
+        seed_default_columns(&conn)?;
+
+        Ok(Database {
+            conn: Mutex::new(conn),
+        })
+    }
+
+    pub fn conn(&self) -> MutexGuard<'_, Connection> {
+        // 다른 스레드가 패닉으로 락을 놓쳐도 연결 자체는 계속 사용 가능
+        self.conn.lock().unwrap_or_else(|e| e.into_inner())
+    }
+}
+
+fn seed_default_columns(conn: &Connection) -> Result<(), AppError> {
+    let count: i64 = conn
+        .query_row("SELECT COUNT(*) FROM columns", [], |row| row.get(0))?;
+    if count > 0 {
+        return Ok(());
+    }
+
+    let mut stmt = conn
+        .prepare("INSERT INTO columns (name, data_type, required) VALUES (?1, ?2, ?3)")?;
+    for (name, data_type, required) in DEFAULT_COLUMNS {
+        stmt.execute((name, data_type, required))?;
+    }
+    Ok(())
+}
+
+// 단건 조회 결과가 없을 때 사용하는 공통 오류 메시지
+pub(crate) fn not_found(table: &str, id: i64) -> AppError {
+    AppError::not_found(table, id)
+}
+
+// 금액 컬럼 값. 부동소수점 오차를 피하려고 decimal 텍스트로 저장하고,
+// v3 이전 버전이나 Express 서버가 숫자로 쓴 값도 읽을 수 있게 한다.
+pub(crate) struct SqlMoney(pub Decimal);
+
+impl FromSql for SqlMoney {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        let decimal = match value {
+            ValueRef::Integer(n) => Some(Decimal::from(n)),
+            ValueRef::Real(f) => Decimal::from_f64(f),
+            ValueRef::Text(bytes) => {
+                let text = String::from_utf8_lossy(bytes);
+                let text = text.trim();
+                let parsed = Decimal::from_str(text).or_else(|_| Decimal::from_scientific(text));
+                match parsed {
+                    Ok(decimal) => Some(decimal),
+                    Err(_) => {
+                        let message = format!("금액 형식이 아닙니다: {}", text);
+                        return Err(FromSqlError::Other(message.into()));
+                    }
+                }
+            }
+            _ => None,
+        };
+        decimal
+            .map(|d| SqlMoney(d.normalize()))
+            .ok_or(FromSqlError::InvalidType)
+    }
+}
+
+impl ToSql for SqlMoney {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.0.normalize().to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn round_trip(conn: &Connection, value: &str) -> (String, Decimal) {
+        let money = SqlMoney(Decimal::from_str(value).unwrap());
+        conn.query_row("SELECT ?1, ?1", [&money], |row| {
+            Ok((row.get::<_, String>(0)?, row.get::<_, SqlMoney>(1)?.0))
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn money_round_trips_as_exact_text() {
+        let conn = Connection::open_in_memory().unwrap();
+        for (value, stored) in [
+            ("0.1", "0.1"),
+            ("1234567890123.45", "1234567890123.45"),
+            ("-990.5", "-990.5"),
+            // 뒤쪽 0은 정규화해서 저장
+            ("1500.00", "1500"),
+            ("0.000", "0"),
+        ] {
+            let (text, read) = round_trip(&conn, value);
+            assert_eq!(text, stored);
+            assert_eq!(read, Decimal::from_str(value).unwrap());
+        }
+    }
+
+    #[test]
+    fn money_reads_legacy_numeric_values() {
+        let conn = Connection::open_in_memory().unwrap();
+        let read = |sql: &str| conn.query_row(sql, [], |row| row.get::<_, SqlMoney>(0)).map(|m| m.0);
+        assert_eq!(read("SELECT 1234000").unwrap(), Decimal::from(1_234_000));
+        assert_eq!(read("SELECT 990.5").unwrap(), Decimal::from_str("990.5").unwrap());
+        assert_eq!(read("SELECT ' 1234000.0 '").unwrap(), Decimal::from(1_234_000));
+        assert_eq!(read("SELECT '1.5e3'").unwrap(), Decimal::from(1_500));
+        assert!(read("SELECT '백만원'").is_err());
+        let null = conn.query_row("SELECT NULL", [], |row| row.get::<_, Option<SqlMoney>>(0));
+        assert!(null.unwrap().is_none());
+    }
+}
@@ -0,0 +1,471 @@
+use rusqlite::{params, params_from_iter, Connection, OptionalExtension, Row};
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+use super::{history, not_found, Database, SqlMoney};
+use crate::dates::{parse_period, DateOrder};
+use crate::error::AppError;
+
+// 견적 데이터 (한 행 = 견적 품목 하나)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quotation {
+    pub id: i64,
+    pub quotation_id: String,
+    pub solution: Option<String>,
+    pub category: Option<String>,
+    pub partner: Option<String>,
+    pub vendor: Option<String>,
+    pub main_product: Option<String>,
+    pub quantity: Option<i64>,
+    pub consumer_price: Option<Decimal>,
+    pub contract_amount: Option<Decimal>,
+    pub savings_amount: Option<Decimal>,
+    pub free_maintenance_period: Option<String>,
+    // free_maintenance_period를 해석한 개월 수 (해석할 수 없으면 None)
+    pub free_maintenance_months: Option<i64>,
+    pub vendor_contact: Option<String>,
+    pub vendor_email: Option<String>,
+    pub partner_contact: Option<String>,
+    pub partner_email: Option<String>,
+    pub special_notes: Option<String>,
+    // 견적일 또는 계약일. 추이 집계 기준 (없으면 등록일)
+    pub quote_date: Option<String>,
+    pub version: i64,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewQuotation {
+    pub quotation_id: String,
+    pub solution: Option<String>,
+    pub category: Option<String>,
+    pub partner: Option<String>,
+    pub vendor: Option<String>,
+    pub main_product: Option<String>,
+    pub quantity: Option<i64>,
+    pub consumer_price: Option<Decimal>,
+    pub contract_amount: Option<Decimal>,
+    pub savings_amount: Option<Decimal>,
+    pub free_maintenance_period: Option<String>,
+    pub vendor_contact: Option<String>,
+    pub vendor_email: Option<String>,
+    pub partner_contact: Option<String>,
+    pub partner_email: Option<String>,
+    pub special_notes: Option<String>,
+    pub quote_date: Option<String>,
+}
+
+// 소비자가격 - 계약금액 (절감 분석의 소비자가격 대비 할인과 같은 값)
+fn computed_savings(consumer_price: Option<Decimal>, contract_amount: Option<Decimal>) -> Option<Decimal> {
+    match (consumer_price, contract_amount) {
+        (Some(consumer), Some(contract)) => Some(consumer - contract),
+        _ => None,
+    }
+}
+
+impl Quotation {
+    // 합계/추이/내보내기에 쓰는 절감액. 입력된 savings_amount가 아니라 계산한 값만 사용
+    pub fn savings(&self) -> Option<Decimal> {
+        computed_savings(self.consumer_price, self.contract_amount)
+    }
+}
+
+impl NewQuotation {
+    // 저장할 절감액. 계산할 수 있으면 입력값 대신 계산한 값
+    pub fn savings_amount(&self) -> Option<Decimal> {
+        computed_savings(self.consumer_price, self.contract_amount).or(self.savings_amount)
+    }
+
+    pub fn free_maintenance_months(&self) -> Option<i64> {
+        let period = self.free_maintenance_period.as_deref()?;
+        parse_period(period, DateOrder::Ymd)
+            .ok()
+            .map(|period| i64::from(period.months))
+    }
+}
+
+// 이전 차수로 되돌릴 때 사용
+impl From<&Quotation> for NewQuotation {
+    fn from(q: &Quotation) -> Self {
+        NewQuotation {
+            quotation_id: q.quotation_id.clone(),
+            solution: q.solution.clone(),
+            category: q.category.clone(),
+            partner: q.partner.clone(),
+            vendor: q.vendor.clone(),
+            main_product: q.main_product.clone(),
+            quantity: q.quantity,
+            consumer_price: q.consumer_price,
+            contract_amount: q.contract_amount,
+            savings_amount: q.savings_amount,
+            free_maintenance_period: q.free_maintenance_period.clone(),
+            vendor_contact: q.vendor_contact.clone(),
+            vendor_email: q.vendor_email.clone(),
+            partner_contact: q.partner_contact.clone(),
+            partner_email: q.partner_email.clone(),
+            special_notes: q.special_notes.clone(),
+            quote_date: q.quote_date.clone(),
+        }
+    }
+}
+
+// 데이터 관리 탭의 필터 조건 (지정한 항목만 일치 비교)
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QuotationFilter {
+    pub category: Option<String>,
+    pub partner: Option<String>,
+    pub vendor: Option<String>,
+    pub solution: Option<String>,
+    // 무상 유지보수 개월 수 범위 (포함)
+    pub min_free_maintenance_months: Option<i64>,
+    pub max_free_maintenance_months: Option<i64>,
+}
+
+pub(crate) const SELECT: &str = "SELECT id, quotation_id, solution, category, partner, vendor, main_product, \
+    quantity, consumer_price, contract_amount, savings_amount, free_maintenance_period, \
+    free_maintenance_months, vendor_contact, vendor_email, partner_contact, partner_email, special_notes, quote_date, \
+    version, created_at, updated_at FROM quotations";
+
+impl Quotation {
+    pub(crate) fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Quotation {
+            id: row.get("id")?,
+            quotation_id: row.get("quotation_id")?,
+            solution: row.get("solution")?,
+            category: row.get("category")?,
+            partner: row.get("partner")?,
+            vendor: row.get("vendor")?,
+            main_product: row.get("main_product")?,
+            quantity: row.get("quantity")?,
+            consumer_price: money(row, "consumer_price")?,
+            contract_amount: money(row, "contract_amount")?,
+            savings_amount: money(row, "savings_amount")?,
+            free_maintenance_period: row.get("free_maintenance_period")?,
+            free_maintenance_months: row.get("free_maintenance_months")?,
+            vendor_contact: row.get("vendor_contact")?,
+            vendor_email: row.get("vendor_email")?,
+            partner_contact: row.get("partner_contact")?,
+            partner_email: row.get("partner_email")?,
+            special_notes: row.get("special_notes")?,
+            quote_date: row.get("quote_date")?,
+            version: row.get("version")?,
+            created_at: row.get("created_at")?,
+            updated_at: row.get("updated_at")?,
+        })
+    }
+}
+
+pub(crate) fn select_quotation(conn: &Connection, id: i64) -> Result<Quotation, AppError> {
+    conn.query_row(&format!("{} WHERE id = ?1", SELECT), [id], Quotation::from_row)
+        .optional()?
+        .ok_or_else(|| not_found("견적", id))
+}
+
+fn money(row: &Row, column: &str) -> rusqlite::Result<Option<Decimal>> {
+    Ok(row.get::<_, Option<SqlMoney>>(column)?.map(|m| m.0))
+}
+
+impl QuotationFilter {
+    // WHERE 절과 바인딩 값 생성
+    fn to_sql(&self) -> (String, Vec<String>) {
+        let mut clauses = Vec::new();
+        let mut values = Vec::new();
+        let fields = [
+            ("category", &self.category),
+            ("partner", &self.partner),
+            ("vendor", &self.vendor),
+            ("solution", &self.solution),
+        ];
+        for (column, value) in fields {
+            if let Some(value) = value {
+                values.push(value.clone());
+                clauses.push(format!("{} = ?{}", column, values.len()));
+            }
+        }
+        let ranges = [
+            (">=", self.min_free_maintenance_months),
+            ("<=", self.max_free_maintenance_months),
+        ];
+        for (op, months) in ranges {
+            if let Some(months) = months {
+                values.push(months.to_string());
+                clauses.push(format!("free_maintenance_months {} ?{}", op, values.len()));
+            }
+        }
+
+        if clauses.is_empty() {
+            (String::new(), values)
+        } else {
+            (format!(" WHERE {}", clauses.join(" AND ")), values)
+        }
+    }
+}
+
+impl Database {
+    pub fn list_quotations(&self, filter: &QuotationFilter) -> Result<Vec<Quotation>, AppError> {
+        let (where_clause, values) = filter.to_sql();
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!("{}{} ORDER BY id", SELECT, where_clause))?;
+        let rows = stmt
+            .query_map(params_from_iter(values), Quotation::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    pub fn get_quotation(&self, id: i64) -> Result<Quotation, AppError> {
+        select_quotation(&self.conn(), id)
+    }
+
+    // 견적번호(quotation_id)가 같은 품목 행들
+    pub fn list_quotation_lines(&self, quotation_ids: &[String]) -> Result<Vec<Quotation>, AppError> {
+        if quotation_ids.is_empty() {
+            return Ok(Vec::new());
+        }
+        let placeholders: Vec<String> = (1..=quotation_ids.len()).map(|i| format!("?{}", i)).collect();
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!(
+                "{} WHERE quotation_id IN ({}) ORDER BY id",
+                SELECT,
+                placeholders.join(", ")
+            ))?;
+        let rows = stmt
+            .query_map(params_from_iter(quotation_ids), Quotation::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    pub fn create_quotation(&self, q: &NewQuotation) -> Result<Quotation, AppError> {
+        let id = {
+            let conn = self.conn();
+            conn.execute(
+                "INSERT INTO quotations (quotation_id, solution, category, partner, vendor, \
+                 main_product, quantity, consumer_price, contract_amount, savings_amount, \
+                 free_maintenance_period, vendor_contact, vendor_email, partner_contact, \
+                 partner_email, special_notes, free_maintenance_months, quote_date) \
+                 VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18)",
+                params![
+                    q.quotation_id,
+                    q.solution,
+                    q.category,
+                    q.partner,
+                    q.vendor,
+                    q.main_product,
+                    q.quantity,
+                    q.consumer_price.map(SqlMoney),
+                    q.contract_amount.map(SqlMoney),
+                    q.savings_amount().map(SqlMoney),
+                    q.free_maintenance_period,
+                    q.vendor_contact,
+                    q.vendor_email,
+                    q.partner_contact,
+                    q.partner_email,
+                    q.special_notes,
+                    q.free_maintenance_months(),
+                    q.quote_date,
+                ],
+            )?;
+            conn.last_insert_rowid()
+        };
+        self.get_quotation(id)
+    }
+
+    // 수정할 때마다 차수(version)를 1 올리고 수정 전 레코드를 이력으로 남김
+    pub fn update_quotation(
+        &self,
+        id: i64,
+        q: &NewQuotation,
+        source_file: Option<&str>,
+    ) -> Result<Quotation, AppError> {
+        self.revise_quotation(id, q, source_file, None)
+    }
+
+    // 이력 기록과 수정은 한 트랜잭션으로 처리
+    pub(crate) fn revise_quotation(
+        &self,
+        id: i64,
+        q: &NewQuotation,
+        source_file: Option<&str>,
+        restored_from: Option<i64>,
+    ) -> Result<Quotation, AppError> {
+        let mut conn = self.conn();
+        let tx = conn.transaction()?;
+
+        let before = select_quotation(&tx, id)?;
+        tx.execute(
+            "UPDATE quotations SET quotation_id = ?1, solution = ?2, category = ?3, \
+             partner = ?4, vendor = ?5, main_product = ?6, quantity = ?7, \
+             consumer_price = ?8, contract_amount = ?9, savings_amount = ?10, \
+             free_maintenance_period = ?11, vendor_contact = ?12, vendor_email = ?13, \
+             partner_contact = ?14, partner_email = ?15, special_notes = ?16, \
+             free_maintenance_months = ?17, quote_date = ?18, \
+             version = version + 1, updated_at = CURRENT_TIMESTAMP \
+             WHERE id = ?19",
+            params![
+                q.quotation_id,
+                q.solution,
+                q.category,
+                q.partner,
+                q.vendor,
+                q.main_product,
+                q.quantity,
+                q.consumer_price.map(SqlMoney),
+                q.contract_amount.map(SqlMoney),
+                q.savings_amount().map(SqlMoney),
+                q.free_maintenance_period,
+                q.vendor_contact,
+                q.vendor_email,
+                q.partner_contact,
+                q.partner_email,
+                q.special_notes,
+                q.free_maintenance_months(),
+                q.quote_date,
+                id,
+            ],
+        )?;
+        let after = select_quotation(&tx, id)?;
+        history::record(&tx, &before, &after, source_file, restored_from)?;
+
+        tx.commit()?;
+        Ok(after)
+    }
+
+    pub fn delete_quotation(&self, id: i64) -> Result<(), AppError> {
+        self.conn()
+            .execute("DELETE FROM quotations WHERE id = ?1", [id])?;
+        Ok(())
+    }
+}
+
+#[command]
+pub fn list_quotations(
+    db: State<'_, Database>,
+    filter: Option<QuotationFilter>,
+) -> Result<Vec<Quotation>, AppError> {
+    db.list_quotations(&filter.unwrap_or_default())
+}
+
+#[command]
+pub fn get_quotation(db: State<'_, Database>, id: i64) -> Result<Quotation, AppError> {
+    db.get_quotation(id)
+}
+
+#[command]
+pub fn create_quotation(
+    db: State<'_, Database>,
+    quotation: NewQuotation,
+) -> Result<Quotation, AppError> {
+    db.create_quotation(&quotation)
+}
+
+#[command]
+pub fn update_quotation(
+    db: State<'_, Database>,
+    id: i64,
+    quotation: NewQuotation,
+    source_file: Option<String>,
+) -> Result<Quotation, AppError> {
+    db.update_quotation(id, &quotation, source_file.as_deref())
+}
+
+#[command]
+pub fn delete_quotation(db: State<'_, Database>, id: i64) -> Result<(), AppError> {
+    db.delete_quotation(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn new_quotation(quotation_id: &str, vendor: &str) -> NewQuotation {
+        NewQuotation {
+            quotation_id: quotation_id.to_string(),
+            vendor: Some(vendor.to_string()),
+            consumer_price: Some(Decimal::from(1_000)),
+            contract_amount: Some(Decimal::from(800)),
+            free_maintenance_period: Some("1년".into()),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn crud() {
+        let db = Database::open_in_memory().unwrap();
+
+        let created = db.create_quotation(&new_quotation("Q-1", "가나상사")).unwrap();
+        assert_eq!(created.version, 1);
+        assert_eq!(created.savings_amount, Some(Decimal::from(200)));
+        assert_eq!(created.free_maintenance_months, Some(12));
+        assert_eq!(db.get_quotation(created.id).unwrap().vendor.as_deref(), Some("가나상사"));
+
+        let mut changed = new_quotation("Q-1", "다라전자");
+        changed.contract_amount = Some(Decimal::new(7505, 1));
+        let updated = db.update_quotation(created.id, &changed, None).unwrap();
+        assert_eq!(updated.version, 2);
+        assert_eq!(updated.vendor.as_deref(), Some("다라전자"));
+        assert_eq!(updated.savings_amount, Some(Decimal::new(2495, 1)));
+        assert_eq!(db.update_quotation(9999, &changed, None).unwrap_err().code(), "NOT_FOUND");
+
+        db.delete_quotation(created.id).unwrap();
+        assert_eq!(db.get_quotation(created.id).unwrap_err().code(), "NOT_FOUND");
+    }
+
+    #[test]
+    fn list_applies_filter() {
+        let db = Database::open_in_memory().unwrap();
+        db.create_quotation(&new_quotation("Q-1", "가나상사")).unwrap();
+        let mut long = new_quotation("Q-2", "다라전자");
+        long.free_maintenance_period = Some("3년".into());
+        db.create_quotation(&long).unwrap();
+
+        assert_eq!(db.list_quotations(&QuotationFilter::default()).unwrap().len(), 2);
+        let filter = QuotationFilter {
+            vendor: Some("다라전자".into()),
+            ..Default::default()
+        };
+        assert_eq!(db.list_quotations(&filter).unwrap()[0].quotation_id, "Q-2");
+        let filter = QuotationFilter {
+            min_free_maintenance_months: Some(24),
+            ..Default::default()
+        };
+        let found = db.list_quotations(&filter).unwrap();
+        assert_eq!(found.len(), 1);
+        assert_eq!(found[0].quotation_id, "Q-2");
+    }
+
+    #[test]
+    fn delete_cascades_to_category_items() {
+        let db = Database::open_in_memory().unwrap();
+        let quotation = db.create_quotation(&new_quotation("Q-1", "가나상사")).unwrap();
+        let count = |db: &Database| -> i64 {
+            db.conn()
+                .query_row("SELECT COUNT(*) FROM category_items", [], |row| row.get(0))
+                .unwrap()
+        };
+        {
+            let conn = db.conn();
+            conn.execute("INSERT INTO categories (name) VALUES ('네트워크')", []).unwrap();
+            let category = conn.last_insert_rowid();
+            conn.execute(
+                "INSERT INTO category_items (category_id, quotation_id) VALUES (?1, ?2)",
+                [category, quotation.id],
+            )
+            .unwrap();
+            // 없는 견적을 가리키는 항목은 거부
+            assert!(conn
+                .execute(
+                    "INSERT INTO category_items (category_id, quotation_id) VALUES (?1, 9999)",
+                    [category],
+                )
+                .is_err());
+        }
+        assert_eq!(count(&db), 1);
+
+        db.delete_quotation(quotation.id).unwrap();
+        assert_eq!(count(&db), 0);
+    }
+}
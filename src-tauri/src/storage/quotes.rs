@@ -0,0 +1,126 @@
+use rusqlite::{params, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use tauri::{command, State};
+
+use super::{not_found, Database};
+use crate::error::AppError;
+
+// 템플릿 매핑 결과 (파일 하나에서 추출한 데이터)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Quote {
+    pub id: i64,
+    pub file_id: i64,
+    pub template_id: i64,
+    pub data: Value,
+    pub created_at: Option<String>,
+    pub updated_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewQuote {
+    pub file_id: i64,
+    pub template_id: i64,
+    pub data: Value,
+}
+
+const SELECT: &str = "SELECT id, file_id, template_id, data, created_at, updated_at FROM quotes";
+
+impl Quote {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        // data 컬럼은 JSON 문자열로 저장
+        let data: String = row.get("data")?;
+        let data = serde_json::from_str(&data).map_err(|e| {
+            rusqlite::Error::FromSqlConversionFailure(3, rusqlite::types::Type::Text, Box::new(e))
+        })?;
+
+        Ok(Quote {
+            id: row.get("id")?,
+            file_id: row.get("file_id")?,
+            template_id: row.get("template_id")?,
+            data,
+            created_at: row.get("created_at")?,
+            updated_at: row.get("updated_at")?,
+        })
+    }
+}
+
+impl Database {
+    pub fn list_quotes(&self, file_id: Option<i64>) -> Result<Vec<Quote>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!(
+                "{} WHERE ?1 IS NULL OR file_id = ?1 ORDER BY id",
+                SELECT
+            ))?;
+        let rows = stmt
+            .query_map([file_id], Quote::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    pub fn get_quote(&self, id: i64) -> Result<Quote, AppError> {
+        self.conn()
+            .query_row(&format!("{} WHERE id = ?1", SELECT), [id], Quote::from_row)
+            .optional()?
+            .ok_or_else(|| not_found("견적 매핑", id))
+    }
+
+    pub fn create_quote(&self, quote: &NewQuote) -> Result<Quote, AppError> {
+        let id = {
+            let conn = self.conn();
+            conn.execute(
+                "INSERT INTO quotes (file_id, template_id, data) VALUES (?1, ?2, ?3)",
+                params![quote.file_id, quote.template_id, quote.data.to_string()],
+            )?;
+            conn.last_insert_rowid()
+        };
+        self.get_quote(id)
+    }
+
+    pub fn update_quote(&self, id: i64, quote: &NewQuote) -> Result<Quote, AppError> {
+        let changed = self
+            .conn()
+            .execute(
+                "UPDATE quotes SET file_id = ?1, template_id = ?2, data = ?3, \
+                 updated_at = CURRENT_TIMESTAMP WHERE id = ?4",
+                params![quote.file_id, quote.template_id, quote.data.to_string(), id],
+            )?;
+        if changed == 0 {
+            return Err(not_found("견적 매핑", id));
+        }
+        self.get_quote(id)
+    }
+
+    pub fn delete_quote(&self, id: i64) -> Result<(), AppError> {
+        self.conn()
+            .execute("DELETE FROM quotes WHERE id = ?1", [id])?;
+        Ok(())
+    }
+}
+
+#[command]
+pub fn list_quotes(db: State<'_, Database>, file_id: Option<i64>) -> Result<Vec<Quote>, AppError> {
+    db.list_quotes(file_id)
+}
+
+#[command]
+pub fn get_quote(db: State<'_, Database>, id: i64) -> Result<Quote, AppError> {
+    db.get_quote(id)
+}
+
+#[command]
+pub fn create_quote(db: State<'_, Database>, quote: NewQuote) -> Result<Quote, AppError> {
+    db.create_quote(&quote)
+}
+
+#[command]
+pub fn update_quote(db: State<'_, Database>, id: i64, quote: NewQuote) -> Result<Quote, AppError> {
+    db.update_quote(id, &quote)
+}
+
+#[command]
+pub fn delete_quote(db: State<'_, Database>, id: i64) -> Result<(), AppError> {
+    db.delete_quote(id)
+}
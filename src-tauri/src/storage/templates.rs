@@ -0,0 +1,163 @@
+use rusqlite::{params, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+use super::{not_found, Database};
+use crate::error::AppError;
+
+// 매핑 템플릿 - mapping_data는 TemplateEditor가 만드는 JSON 문자열
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Template {
+    pub id: i64,
+    pub name: String,
+    pub mapping_data: String,
+    pub created_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewTemplate {
+    pub name: String,
+    pub mapping_data: String,
+}
+
+const SELECT: &str = "SELECT id, name, mapping_data, created_at FROM templates";
+
+impl Template {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(Template {
+            id: row.get("id")?,
+            name: row.get("name")?,
+            mapping_data: row.get("mapping_data")?,
+            created_at: row.get("created_at")?,
+        })
+    }
+}
+
+impl Database {
+    pub fn list_templates(&self) -> Result<Vec<Template>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY id", SELECT))?;
+        let rows = stmt
+            .query_map([], Template::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    pub fn get_template(&self, id: i64) -> Result<Template, AppError> {
+        self.conn()
+            .query_row(&format!("{} WHERE id = ?1", SELECT), [id], Template::from_row)
+            .optional()?
+            .ok_or_else(|| not_found("템플릿", id))
+    }
+
+    pub fn create_template(&self, template: &NewTemplate) -> Result<Template, AppError> {
+        let id = {
+            let conn = self.conn();
+            conn.execute(
+                "INSERT INTO templates (name, mapping_data) VALUES (?1, ?2)",
+                params![template.name, template.mapping_data],
+            )?;
+            conn.last_insert_rowid()
+        };
+        self.get_template(id)
+    }
+
+    pub fn update_template(&self, id: i64, template: &NewTemplate) -> Result<Template, AppError> {
+        let changed = self
+            .conn()
+            .execute(
+                "UPDATE templates SET name = ?1, mapping_data = ?2 WHERE id = ?3",
+                params![template.name, template.mapping_data, id],
+            )?;
+        if changed == 0 {
+            return Err(not_found("템플릿", id));
+        }
+        self.get_template(id)
+    }
+
+    pub fn delete_template(&self, id: i64) -> Result<(), AppError> {
+        self.conn()
+            .execute("DELETE FROM templates WHERE id = ?1", [id])?;
+        Ok(())
+    }
+}
+
+#[command]
+pub fn list_templates(db: State<'_, Database>) -> Result<Vec<Template>, AppError> {
+    db.list_templates()
+}
+
+#[command]
+pub fn get_template(db: State<'_, Database>, id: i64) -> Result<Template, AppError> {
+    db.get_template(id)
+}
+
+#[command]
+pub fn create_template(db: State<'_, Database>, template: NewTemplate) -> Result<Template, AppError> {
+    db.create_template(&template)
+}
+
+#[command]
+pub fn update_template(
+    db: State<'_, Database>,
+    id: i64,
+    template: NewTemplate,
+) -> Result<Template, AppError> {
+    db.update_template(id, &template)
+}
+
+#[command]
+pub fn delete_template(db: State<'_, Database>, id: i64) -> Result<(), AppError> {
+    db.delete_template(id)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::storage::uploaded_files::{FileStatus, NewUploadedFile};
+
+    fn new_template(name: &str) -> NewTemplate {
+        NewTemplate {
+            name: name.to_string(),
+            mapping_data: r#"{"mappings":[]}"#.to_string(),
+        }
+    }
+
+    #[test]
+    fn crud() {
+        let db = Database::open_in_memory().unwrap();
+
+        let created = db.create_template(&new_template("A사 양식")).unwrap();
+        assert_eq!(db.get_template(created.id).unwrap().mapping_data, r#"{"mappings":[]}"#);
+        assert_eq!(db.list_templates().unwrap().len(), 1);
+
+        let updated = db.update_template(created.id, &new_template("A사 양식 v2")).unwrap();
+        assert_eq!(updated.name, "A사 양식 v2");
+        assert_eq!(db.update_template(9999, &new_template("없음")).unwrap_err().code(), "NOT_FOUND");
+
+        db.delete_template(created.id).unwrap();
+        assert_eq!(db.get_template(created.id).unwrap_err().code(), "NOT_FOUND");
+        assert!(db.list_templates().unwrap().is_empty());
+    }
+
+    #[test]
+    fn referenced_template_cannot_be_deleted() {
+        // foreign_keys = ON 이므로 업로드 파일이 가리키는 템플릿은 지울 수 없음
+        let db = Database::open_in_memory().unwrap();
+        let template = db.create_template(&new_template("A사 양식")).unwrap();
+        let file = db
+            .create_uploaded_file(&NewUploadedFile {
+                filename: "quote.xlsx".into(),
+                size: 10,
+                status: FileStatus::Pending,
+                template_id: Some(template.id),
+            })
+            .unwrap();
+
+        assert_eq!(db.delete_template(template.id).unwrap_err().code(), "DATABASE_ERROR");
+        db.delete_uploaded_file(file.id).unwrap();
+        db.delete_template(template.id).unwrap();
+    }
+}
@@ -0,0 +1,169 @@
+use rusqlite::types::{FromSql, FromSqlError, FromSqlResult, ToSql, ToSqlOutput, ValueRef};
+use rusqlite::{params, OptionalExtension, Row};
+use serde::{Deserialize, Serialize};
+use tauri::{command, State};
+
+use super::{not_found, Database};
+use crate::error::AppError;
+
+// 업로드 파일 처리 상태
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileStatus {
+    Pending,
+    Processing,
+    Completed,
+    Failed,
+}
+
+impl FileStatus {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            FileStatus::Pending => "pending",
+            FileStatus::Processing => "processing",
+            FileStatus::Completed => "completed",
+            FileStatus::Failed => "failed",
+        }
+    }
+}
+
+impl ToSql for FileStatus {
+    fn to_sql(&self) -> rusqlite::Result<ToSqlOutput<'_>> {
+        Ok(ToSqlOutput::from(self.as_str()))
+    }
+}
+
+impl FromSql for FileStatus {
+    fn column_result(value: ValueRef<'_>) -> FromSqlResult<Self> {
+        match value.as_str()? {
+            "pending" => Ok(FileStatus::Pending),
+            "processing" => Ok(FileStatus::Processing),
+            "completed" => Ok(FileStatus::Completed),
+            "failed" => Ok(FileStatus::Failed),
+            other => Err(FromSqlError::Other(
+                format!("알 수 없는 파일 상태: {}", other).into(),
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct UploadedFile {
+    pub id: i64,
+    pub filename: String,
+    pub size: i64,
+    pub status: FileStatus,
+    pub template_id: Option<i64>,
+    pub uploaded_at: Option<String>,
+}
+
+#[derive(Debug, Clone, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct NewUploadedFile {
+    pub filename: String,
+    pub size: i64,
+    pub status: FileStatus,
+    pub template_id: Option<i64>,
+}
+
+const SELECT: &str =
+    "SELECT id, filename, size, status, template_id, uploaded_at FROM uploaded_files";
+
+impl UploadedFile {
+    fn from_row(row: &Row) -> rusqlite::Result<Self> {
+        Ok(UploadedFile {
+            id: row.get("id")?,
+            filename: row.get("filename")?,
+            size: row.get("size")?,
+            status: row.get("status")?,
+            template_id: row.get("template_id")?,
+            uploaded_at: row.get("uploaded_at")?,
+        })
+    }
+}
+
+impl Database {
+    pub fn list_uploaded_files(&self) -> Result<Vec<UploadedFile>, AppError> {
+        let conn = self.conn();
+        let mut stmt = conn
+            .prepare(&format!("{} ORDER BY id", SELECT))?;
+        let rows = stmt
+            .query_map([], UploadedFile::from_row)?;
+        rows.collect::<Result<_, _>>().map_err(AppError::from)
+    }
+
+    pub fn get_uploaded_file(&self, id: i64) -> Result<UploadedFile, AppError> {
+        self.conn()
+            .query_row(&format!("{} WHERE id = ?1", SELECT), [id], UploadedFile::from_row)
+            .optional()?
+            .ok_or_else(|| not_found("업로드 파일", id))
+    }
+
+    pub fn create_uploaded_file(&self, file: &NewUploadedFile) -> Result<UploadedFile, AppError> {
+        let id = {
+            let conn = self.conn();
+            conn.execute(
+                "INSERT INTO uploaded_files (filename, size, status, template_id) VALUES (?1, ?2, ?3, ?4)",
+                params![file.filename, file.size, file.status, file.template_id],
+            )?;
+            conn.last_insert_rowid()
+        };
+        self.get_uploaded_file(id)
+    }
+
+    pub fn update_uploaded_file(
+        &self,
+        id: i64,
+        file: &NewUploadedFile,
+    ) -> Result<UploadedFile, AppError> {
+        let changed = self
+            .conn()
+            .execute(
+                "UPDATE uploaded_files SET filename = ?1, size = ?2, status = ?3, template_id = ?4 WHERE id = ?5",
+                params![file.filename, file.size, file.status, file.template_id, id],
+            )?;
+        if changed == 0 {
+            return Err(not_found("업로드 파일", id));
+        }
+        self.get_uploaded_file(id)
+    }
+
+    pub fn delete_uploaded_file(&self, id: i64) -> Result<(), AppError> {
+        self.conn()
+            .execute("DELETE FROM uploaded_files WHERE id = ?1", [id])?;
+        Ok(())
+    }
+}
+
+#[command]
+pub fn list_uploaded_files(db: State<'_, Database>) -> Result<Vec<UploadedFile>, AppError> {
+    db.list_uploaded_files()
+}
+
+#[command]
+pub fn get_uploaded_file(db: State<'_, Database>, id: i64) -> Result<UploadedFile, AppError> {
+    db.get_uploaded_file(id)
+}
+
+#[command]
+pub fn create_uploaded_file(
+    db: State<'_, Database>,
+    file: NewUploadedFile,
+) -> Result<UploadedFile, AppError> {
+    db.create_uploaded_file(&file)
+}
+
+#[command]
+pub fn update_uploaded_file(
+    db: State<'_, Database>,
+    id: i64,
+    file: NewUploadedFile,
+) -> Result<UploadedFile, AppError> {
+    db.update_uploaded_file(id, &file)
+}
+
+#[command]
+pub fn delete_uploaded_file(db: State<'_, Database>, id: i64) -> Result<(), AppError> {
+    db.delete_uploaded_file(id)
+}
@@ -0,0 +1,231 @@
+use encoding_rs::{Encoding, EUC_KR, UTF_16BE, UTF_16LE, UTF_8};
+use serde::Serialize;
+
+// 디코딩 결과와 감지된 인코딩
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DecodedText {
+    pub content: String,
+    // WHATWG 인코딩 이름 (예: UTF-8, UTF-16LE, EUC-KR)
+    pub encoding: String,
+    pub bom: bool,
+    // 디코딩할 수 없는 바이트가 U+FFFD로 대체되었는지 여부
+    pub had_errors: bool,
+}
+
+// UTF-16 추정 시 검사할 앞부분 크기
+const SNIFF_LEN: usize = 4096;
+
+// 인코딩 이름 해석. WHATWG 라벨 외에 윈도우에서 쓰는 cp949 계열 이름도 허용
+pub fn encoding_for_label(label: &str) -> Option<&'static Encoding> {
+    let label = label.trim().to_lowercase();
+    match label.as_str() {
+        "cp949" | "ms949" | "uhc" | "euckr" => Some(EUC_KR),
+        "utf8" => Some(UTF_8),
+        "utf16" | "utf16le" => Some(UTF_16LE),
+        "utf16be" => Some(UTF_16BE),
+        _ => Encoding::for_label(label.as_bytes()),
+    }
+}
+
+// BOM -> UTF-16 추정 -> UTF-8 검사 -> CP949(EUC-KR) 순으로 인코딩 감지
+pub fn detect_encoding(bytes: &[u8]) -> (&'static Encoding, usize) {
+    if let Some((encoding, bom_len)) = Encoding::for_bom(bytes) {
+        return (encoding, bom_len);
+    }
+
+    if let Some(encoding) = sniff_utf16(bytes) {
+        return (encoding, 0);
+    }
+
+    if std::str::from_utf8(bytes).is_ok() {
+        return (UTF_8, 0);
+    }
+
+    // encoding_rs의 EUC-KR은 CP949(확장 완성형)를 모두 포함
+    (EUC_KR, 0)
+}
+
+// BOM 없는 UTF-16은 ASCII 문자의 0 바이트 위치로 판단
+fn sniff_utf16(bytes: &[u8]) -> Option<&'static Encoding> {
+    let sample = &bytes[..bytes.len().min(SNIFF_LEN)];
+    if sample.len() < 4 {
+        return None;
+    }
+
+    let pairs = sample.len() / 2;
+    let even_zeros = sample.iter().step_by(2).filter(|b| **b == 0).count();
+    let odd_zeros = sample.iter().skip(1).step_by(2).filter(|b| **b == 0).count();
+
+    if odd_zeros * 10 >= pairs * 3 && even_zeros * 4 < odd_zeros {
+        Some(UTF_16LE)
+    } else if even_zeros * 10 >= pairs * 3 && odd_zeros * 4 < even_zeros {
+        Some(UTF_16BE)
+    } else {
+        None
+    }
+}
+
+// encoding을 지정하면 그대로 사용하고, 없으면 자동 감지
+pub fn decode_bytes(bytes: &[u8], encoding: Option<&str>) -> Result<DecodedText, String> {
+    let (encoding, bom_len) = match encoding {
+        Some(label) => {
+            let encoding = encoding_for_label(label)
+                .ok_or_else(|| format!("지원하지 않는 인코딩: {}", label))?;
+            // 지정한 인코딩과 같은 BOM이면 제거
+            let bom_len = match Encoding::for_bom(bytes) {
+                Some((bom_encoding, len)) if bom_encoding == encoding => len,
+                _ => 0,
+            };
+            (encoding, bom_len)
+        }
+        None => detect_encoding(bytes),
+    };
+
+    let (content, had_errors) = encoding.decode_without_bom_handling(&bytes[bom_len..]);
+
+    Ok(DecodedText {
+        content: content.into_owned(),
+        encoding: encoding.name().to_string(),
+        bom: bom_len > 0,
+        had_errors,
+    })
+}
+
+// 전각 숫자/기호를 반각으로 변환 (금액/날짜 해석 전 정규화)
+pub fn to_half_width(text: &str) -> String {
+    text.chars()
+        .map(|c| match c {
+            '\u{3000}' => ' ',
+            '\u{FFE6}' => '₩',
+            '\u{FFE5}' => '¥',
+            '\u{2212}' => '-',
+            '\u{FF01}'..='\u{FF5E}' => char::from_u32(c as u32 - 0xFEE0).unwrap_or(c),
+            _ => c,
+        })
+        .collect()
+}
+
+// 이름/라벨 비교용 키: 전각 -> 반각, 공백과 구분 기호 제거, 소문자.
+// 헤더 매칭, 앵커 라벨, 내보내기 열 이름, 비교표 제품 키가 모두 이 함수로 비교함
+pub fn normalize_key(text: &str) -> String {
+    to_half_width(text)
+        .chars()
+        .filter(|c| !c.is_whitespace() && !matches!(c, '-' | '_' | '.' | ',' | '/' | '(' | ')' | '[' | ']'))
+        .flat_map(|c| c.to_lowercase())
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn utf16le(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(|u| u.to_le_bytes()).collect()
+    }
+
+    fn utf16be(text: &str) -> Vec<u8> {
+        text.encode_utf16().flat_map(|u| u.to_be_bytes()).collect()
+    }
+
+    fn decode(bytes: &[u8]) -> DecodedText {
+        decode_bytes(bytes, None).unwrap()
+    }
+
+    #[test]
+    fn strips_utf8_bom() {
+        let mut bytes = b"\xEF\xBB\xBF".to_vec();
+        bytes.extend_from_slice("품목,금액".as_bytes());
+        let decoded = decode(&bytes);
+        assert_eq!(decoded.content, "품목,금액");
+        assert_eq!(decoded.encoding, "UTF-8");
+        assert!(decoded.bom);
+        assert!(!decoded.had_errors);
+    }
+
+    #[test]
+    fn detects_utf16_by_bom() {
+        let mut le = vec![0xFF, 0xFE];
+        le.extend(utf16le("견적서"));
+        let decoded = decode(&le);
+        assert_eq!((decoded.content.as_str(), decoded.encoding.as_str(), decoded.bom), ("견적서", "UTF-16LE", true));
+
+        let mut be = vec![0xFE, 0xFF];
+        be.extend(utf16be("견적서"));
+        let decoded = decode(&be);
+        assert_eq!((decoded.content.as_str(), decoded.encoding.as_str(), decoded.bom), ("견적서", "UTF-16BE", true));
+    }
+
+    #[test]
+    fn sniffs_utf16_without_bom() {
+        let decoded = decode(&utf16le("name,price\r\n서버,1000"));
+        assert_eq!(decoded.encoding, "UTF-16LE");
+        assert_eq!(decoded.content, "name,price\r\n서버,1000");
+        assert!(!decoded.bom);
+
+        let decoded = decode(&utf16be("name,price"));
+        assert_eq!(decoded.encoding, "UTF-16BE");
+        assert_eq!(decoded.content, "name,price");
+
+        // 4바이트 미만은 추정하지 않음
+        assert_eq!(decode(b"a\0").encoding, "UTF-8");
+    }
+
+    #[test]
+    fn prefers_utf8_then_falls_back_to_cp949() {
+        let decoded = decode("서버,1,000원".as_bytes());
+        assert_eq!(decoded.encoding, "UTF-8");
+        assert_eq!(decoded.content, "서버,1,000원");
+
+        // "똠방각하" - CP949 확장 완성형 글자 포함
+        let (cp949, _, _) = EUC_KR.encode("견적 똠방각하");
+        let decoded = decode(&cp949);
+        assert_eq!(decoded.encoding, "EUC-KR");
+        assert_eq!(decoded.content, "견적 똠방각하");
+        assert!(!decoded.had_errors);
+    }
+
+    #[test]
+    fn invalid_sequences_set_had_errors() {
+        // 선행 바이트 뒤에 잘못된 후행 바이트
+        let decoded = decode(&[0xB0, 0xA1, 0xB0, 0x0A]);
+        assert_eq!(decoded.encoding, "EUC-KR");
+        assert!(decoded.had_errors);
+        assert!(decoded.content.starts_with('가'));
+        assert!(decoded.content.contains('\u{FFFD}'));
+
+        let decoded = decode_bytes(&[0x61, 0xFF, 0x62], Some("utf-8")).unwrap();
+        assert!(decoded.had_errors);
+        assert_eq!(decoded.content, "a\u{FFFD}b");
+    }
+
+    #[test]
+    fn explicit_encoding_overrides_detection() {
+        let (cp949, _, _) = EUC_KR.encode("금액");
+        let decoded = decode_bytes(&cp949, Some("cp949")).unwrap();
+        assert_eq!((decoded.content.as_str(), decoded.encoding.as_str()), ("금액", "EUC-KR"));
+
+        // 지정한 인코딩과 같은 BOM만 제거
+        let decoded = decode_bytes(b"\xEF\xBB\xBFabc", Some("utf8")).unwrap();
+        assert_eq!(decoded.content, "abc");
+        assert!(decoded.bom);
+        let decoded = decode_bytes(b"\xEF\xBB\xBFabc", Some("cp949")).unwrap();
+        assert!(!decoded.bom);
+
+        assert!(decode_bytes(b"abc", Some("klingon")).is_err());
+    }
+
+    #[test]
+    fn converts_full_width_characters() {
+        assert_eq!(to_half_width("１２，３００　원"), "12,300 원");
+        assert_eq!(to_half_width("￦１０"), "₩10");
+    }
+
+    #[test]
+    fn normalizes_keys() {
+        assert_eq!(normalize_key(" Server-A (v2) "), "serverav2");
+        assert_eq!(normalize_key("ＳＥＲＶＥＲ　Ａ　（Ｖ２）"), "serverav2");
+        assert_eq!(normalize_key("무상 유지보수 기간"), "무상유지보수기간");
+        assert_eq!(normalize_key("견적_ID"), normalize_key("견적 id"));
+    }
+}
@@ -0,0 +1,374 @@
+use calamine::{open_workbook_auto, Data, Reader};
+use serde::Serialize;
+use std::fs;
+use std::path::Path;
+use tauri::command;
+
+use crate::error::AppError;
+use crate::pdf::{self, PdfDocument};
+use crate::text_encoding::decode_bytes;
+
+// 지원하는 스프레드시트 형식
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FileType {
+    Xlsx,
+    Xls,
+    Csv,
+    // PDF는 텍스트 위치로 재구성한 시트 하나로 다룸
+    Pdf,
+}
+
+impl FileType {
+    pub fn from_path(path: &Path) -> Option<FileType> {
+        let ext = path.extension()?.to_str()?.to_lowercase();
+        match ext.as_str() {
+            "xlsx" | "xlsm" => Some(FileType::Xlsx),
+            "xls" => Some(FileType::Xls),
+            "csv" => Some(FileType::Csv),
+            "pdf" => Some(FileType::Pdf),
+            _ => None,
+        }
+    }
+}
+
+// 셀 값 - 문자열/숫자/날짜/불리언/오류를 구분해서 전달
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "type", content = "value", rename_all = "lowercase")]
+pub enum Cell {
+    Empty,
+    String(String),
+    Number(f64),
+    // ISO 8601 형식 (예: 2025-06-26, 2025-06-26T09:30:00)
+    Date(String),
+    Bool(bool),
+    // 엑셀 오류 코드 (예: #N/A, #DIV/0!)
+    Error(String),
+}
+
+impl Cell {
+    pub fn is_empty(&self) -> bool {
+        match self {
+            Cell::Empty => true,
+            Cell::String(s) => s.trim().is_empty(),
+            _ => false,
+        }
+    }
+
+    // 매핑/검색용 텍스트 표현
+    pub fn as_text(&self) -> Option<String> {
+        match self {
+            Cell::Empty => None,
+            Cell::String(s) => Some(s.clone()),
+            Cell::Number(n) => Some(format_number(*n)),
+            Cell::Date(d) => Some(d.clone()),
+            Cell::Bool(b) => Some(b.to_string()),
+            Cell::Error(e) => Some(e.clone()),
+        }
+    }
+}
+
+// 시트 - rows[r][c]는 A1 기준 0부터 시작하는 셀 좌표
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Sheet {
+    pub name: String,
+    pub row_count: usize,
+    pub column_count: usize,
+    pub rows: Vec<Vec<Cell>>,
+}
+
+impl Sheet {
+    pub fn new(name: String, rows: Vec<Vec<Cell>>) -> Self {
+        let column_count = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+        Sheet {
+            name,
+            row_count: rows.len(),
+            column_count,
+            rows,
+        }
+    }
+
+    pub fn cell(&self, row: usize, col: usize) -> &Cell {
+        self.rows
+            .get(row)
+            .and_then(|r| r.get(col))
+            .unwrap_or(&Cell::Empty)
+    }
+}
+
+#[derive(Debug, Clone, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct Workbook {
+    pub path: String,
+    pub file_type: FileType,
+    pub sheets: Vec<Sheet>,
+    // PDF 원본 텍스트 조각 (영역/정규식 규칙용, 응답에는 포함하지 않음)
+    #[serde(skip)]
+    pub pdf: Option<PdfDocument>,
+}
+
+impl Workbook {
+    pub fn sheet(&self, name: &str) -> Option<&Sheet> {
+        self.sheets.iter().find(|s| s.name == name)
+    }
+}
+
+// 파일 확장자에 따라 엑셀, CSV 또는 PDF로 파싱
+pub fn load_workbook(path: &Path) -> Result<Workbook, AppError> {
+    let file_type = FileType::from_path(path)
+        .ok_or_else(|| AppError::invalid(format!("지원하지 않는 파일 형식: {}", path.display())))?;
+    // 파서 오류와 구분되도록 파일이 없거나 열 수 없는 경우를 먼저 확인
+    fs::metadata(path).map_err(|e| AppError::io(e, path))?;
+
+    let sheets = match file_type {
+        FileType::Csv => vec![parse_csv(path)?],
+        FileType::Xlsx | FileType::Xls => parse_excel(path)?,
+        FileType::Pdf => return Ok(pdf::to_workbook(pdf::load_pdf(path)?)),
+    };
+
+    Ok(Workbook {
+        path: path.to_string_lossy().to_string(),
+        file_type,
+        sheets,
+        pdf: None,
+    })
+}
+
+fn parse_error(path: &Path, e: impl ToString) -> AppError {
+    AppError::Parse {
+        path: path.display().to_string(),
+        detail: e.to_string(),
+    }
+}
+
+fn parse_excel(path: &Path) -> Result<Vec<Sheet>, AppError> {
+    let mut workbook = open_workbook_auto(path).map_err(|e| parse_error(path, e))?;
+    let mut sheets = Vec::new();
+
+    for name in workbook.sheet_names() {
+        let range = workbook.worksheet_range(&name).map_err(|e| parse_error(path, e))?;
+
+        // 범위가 A1이 아닌 곳에서 시작할 수 있으므로 앞쪽을 빈 셀로 채움
+        let (start_row, start_col) = range.start().unwrap_or((0, 0));
+        let mut rows: Vec<Vec<Cell>> = vec![Vec::new(); start_row as usize];
+        for row in range.rows() {
+            let mut cells = vec![Cell::Empty; start_col as usize];
+            cells.extend(row.iter().map(convert_cell));
+            rows.push(cells);
+        }
+
+        sheets.push(Sheet::new(name, rows));
+    }
+
+    Ok(sheets)
+}
+
+fn convert_cell(data: &Data) -> Cell {
+    match data {
+        Data::Empty => Cell::Empty,
+        Data::String(s) => Cell::String(s.clone()),
+        Data::Int(i) => Cell::Number(*i as f64),
+        Data::Float(f) => Cell::Number(*f),
+        Data::Bool(b) => Cell::Bool(*b),
+        Data::DateTime(dt) => match dt.as_datetime() {
+            Some(value) if dt.is_datetime() => Cell::Date(format_datetime(value)),
+            _ => Cell::Number(dt.as_f64()),
+        },
+        Data::DateTimeIso(s) => Cell::Date(s.clone()),
+        Data::DurationIso(s) => Cell::String(s.clone()),
+        Data::Error(e) => Cell::Error(e.to_string()),
+    }
+}
+
+fn format_datetime(value: chrono::NaiveDateTime) -> String {
+    if value.time() == chrono::NaiveTime::MIN {
+        value.format("%Y-%m-%d").to_string()
+    } else {
+        value.format("%Y-%m-%dT%H:%M:%S").to_string()
+    }
+}
+
+fn parse_csv(path: &Path) -> Result<Sheet, AppError> {
+    // 거래처 CSV는 CP949인 경우가 많으므로 인코딩을 감지해서 디코딩
+    let bytes = fs::read(path).map_err(|e| AppError::io(e, path))?;
+    let decoded = decode_bytes(&bytes, None).map_err(|detail| AppError::Parse {
+        path: path.display().to_string(),
+        detail,
+    })?;
+
+    let mut reader = csv::ReaderBuilder::new()
+        .has_headers(false)
+        .flexible(true)
+        .from_reader(decoded.content.as_bytes());
+
+    let mut rows = Vec::new();
+    for record in reader.records() {
+        let record = record.map_err(|e| parse_error(path, e))?;
+        rows.push(record.iter().map(infer_cell).collect());
+    }
+
+    let name = path
+        .file_stem()
+        .map(|s| s.to_string_lossy().to_string())
+        .unwrap_or_else(|| "Sheet1".to_string());
+
+    Ok(Sheet::new(name, rows))
+}
+
+// CSV는 타입 정보가 없으므로 값 모양으로 추론
+fn infer_cell(raw: &str) -> Cell {
+    let value = raw.trim();
+    if value.is_empty() {
+        return Cell::Empty;
+    }
+
+    match value.to_uppercase().as_str() {
+        "TRUE" => return Cell::Bool(true),
+        "FALSE" => return Cell::Bool(false),
+        _ => {}
+    }
+
+    // "00123" 같은 코드 값은 숫자로 바꾸지 않음
+    let digits = value.trim_start_matches('-');
+    let leading_zero = digits.len() > 1 && digits.starts_with('0') && !digits.starts_with("0.");
+    if !leading_zero {
+        if let Ok(n) = value.parse::<f64>() {
+            if n.is_finite() {
+                return Cell::Number(n);
+            }
+        }
+    }
+
+    Cell::String(raw.to_string())
+}
+
+pub fn format_number(n: f64) -> String {
+    if n.fract() == 0.0 && n.abs() < 1e15 {
+        format!("{}", n as i64)
+    } else {
+        n.to_string()
+    }
+}
+
+#[command]
+pub async fn parse_workbook(path: String) -> Result<Workbook, AppError> {
+    // 큰 파일 파싱이 UI 스레드를 막지 않도록 블로킹 스레드에서 실행
+    tauri::async_runtime::spawn_blocking(move || load_workbook(Path::new(&path))).await?
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rust_xlsxwriter::{ExcelDateTime, Format};
+    use serde_json::json;
+
+    #[test]
+    fn infer_cell_keeps_leading_zero_codes_as_text() {
+        assert_eq!(infer_cell("00123"), Cell::String("00123".to_string()));
+        assert_eq!(infer_cell("-007"), Cell::String("-007".to_string()));
+        assert_eq!(infer_cell("0"), Cell::Number(0.0));
+        assert_eq!(infer_cell("0.5"), Cell::Number(0.5));
+        assert_eq!(infer_cell(" 1200000 "), Cell::Number(1200000.0));
+        assert_eq!(infer_cell("1,234"), Cell::String("1,234".to_string()));
+        assert_eq!(infer_cell("true"), Cell::Bool(true));
+        assert_eq!(infer_cell("FALSE"), Cell::Bool(false));
+        assert_eq!(infer_cell("   "), Cell::Empty);
+        assert_eq!(infer_cell("NaN"), Cell::String("NaN".to_string()));
+    }
+
+    #[test]
+    fn cell_serializes_with_type_tag() {
+        assert_eq!(serde_json::to_value(Cell::Empty).unwrap(), json!({ "type": "empty" }));
+        assert_eq!(
+            serde_json::to_value(Cell::Number(1.5)).unwrap(),
+            json!({ "type": "number", "value": 1.5 })
+        );
+        assert_eq!(
+            serde_json::to_value(Cell::Date("2025-06-26".to_string())).unwrap(),
+            json!({ "type": "date", "value": "2025-06-26" })
+        );
+        assert_eq!(
+            serde_json::to_value(Cell::String("00123".to_string())).unwrap(),
+            json!({ "type": "string", "value": "00123" })
+        );
+    }
+
+    #[test]
+    fn loads_xlsx_with_types_and_offset_range() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quote.xlsx");
+
+        let mut book = rust_xlsxwriter::Workbook::new();
+        let sheet = book.add_worksheet().set_name("견적").unwrap();
+        // B2부터 시작하는 범위
+        sheet.write_string(1, 1, "품목").unwrap();
+        sheet.write_string(1, 2, "금액").unwrap();
+        sheet.write_string(2, 1, "00123").unwrap();
+        sheet.write_number(2, 2, 1200000).unwrap();
+        let date = ExcelDateTime::from_ymd(2025, 6, 26).unwrap();
+        sheet
+            .write_datetime_with_format(3, 1, &date, &Format::new().set_num_format("yyyy-mm-dd"))
+            .unwrap();
+        sheet.write_boolean(3, 2, true).unwrap();
+        book.save(&path).unwrap();
+
+        let workbook = load_workbook(&path).unwrap();
+        assert_eq!(workbook.file_type, FileType::Xlsx);
+        let sheet = workbook.sheet("견적").unwrap();
+        assert_eq!(sheet.row_count, 4);
+        assert_eq!(sheet.column_count, 3);
+        assert_eq!(sheet.cell(0, 0), &Cell::Empty);
+        assert_eq!(sheet.cell(1, 1), &Cell::String("품목".to_string()));
+        assert_eq!(sheet.cell(2, 1), &Cell::String("00123".to_string()));
+        assert_eq!(sheet.cell(2, 2), &Cell::Number(1200000.0));
+        assert_eq!(sheet.cell(3, 1), &Cell::Date("2025-06-26".to_string()));
+        assert_eq!(sheet.cell(3, 2), &Cell::Bool(true));
+    }
+
+    #[test]
+    fn loads_xls() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("quote.xls");
+        fs::write(&path, include_bytes!("../tests/fixtures/quote.xls")).unwrap();
+
+        let workbook = load_workbook(&path).unwrap();
+        assert_eq!(workbook.file_type, FileType::Xls);
+        let sheet = workbook.sheet("견적").unwrap();
+        assert_eq!(sheet.row_count, 3);
+        assert_eq!(sheet.cell(0, 1), &Cell::String("금액".to_string()));
+        assert_eq!(sheet.cell(1, 1), &Cell::Number(1200000.0));
+        assert_eq!(sheet.cell(1, 2), &Cell::String("00123".to_string()));
+        assert_eq!(sheet.cell(2, 1), &Cell::Number(35000.5));
+        assert_eq!(sheet.cell(2, 2), &Cell::Bool(true));
+    }
+
+    #[test]
+    fn loads_cp949_csv() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("견적.csv");
+        let (bytes, _, _) = encoding_rs::EUC_KR.encode("품목,수량,코드\r\n서버,2,00123\r\n\"메모, 쉼표\",,\r\n");
+        fs::write(&path, &bytes).unwrap();
+
+        let workbook = load_workbook(&path).unwrap();
+        assert_eq!(workbook.file_type, FileType::Csv);
+        let sheet = &workbook.sheets[0];
+        assert_eq!(sheet.name, "견적");
+        assert_eq!(sheet.row_count, 3);
+        assert_eq!(sheet.cell(0, 0), &Cell::String("품목".to_string()));
+        assert_eq!(sheet.cell(1, 1), &Cell::Number(2.0));
+        assert_eq!(sheet.cell(1, 2), &Cell::String("00123".to_string()));
+        assert_eq!(sheet.cell(2, 0), &Cell::String("메모, 쉼표".to_string()));
+        assert_eq!(sheet.cell(2, 1), &Cell::Empty);
+    }
+
+    #[test]
+    fn rejects_unsupported_and_missing_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let docx = dir.path().join("quote.docx");
+        fs::write(&docx, b"").unwrap();
+        assert_eq!(load_workbook(&docx).unwrap_err().code(), "INVALID_INPUT");
+        let missing = dir.path().join("missing.xlsx");
+        assert_eq!(load_workbook(&missing).unwrap_err().code(), "FILE_NOT_FOUND");
+    }
+}
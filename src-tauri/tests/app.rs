@@ -0,0 +1,81 @@
+use app_lib::commands::DownloadRegistry;
+use app_lib::storage::Database;
+use serde::de::DeserializeOwned;
+use serde_json::{json, Value};
+use tauri::ipc::{CallbackFn, InvokeBody};
+use tauri::test::{mock_builder, mock_context, noop_assets, MockRuntime, INVOKE_KEY};
+use tauri::webview::InvokeRequest;
+use tauri::{App, Manager, WebviewWindow, WebviewWindowBuilder};
+
+// 실제 진입점과 같은 빌더로 앱을 만들고 웹뷰 하나를 띄움.
+// mock_context에는 앱 식별자가 없으므로 DB는 메모리에 미리 등록해 둔다.
+fn mock_app() -> (App<MockRuntime>, WebviewWindow<MockRuntime>) {
+    let app = app_lib::builder(mock_builder())
+        .manage(Database::open_in_memory().unwrap())
+        .build(mock_context(noop_assets()))
+        .unwrap();
+    let webview = WebviewWindowBuilder::new(&app, "main", Default::default())
+        .build()
+        .unwrap();
+    (app, webview)
+}
+
+fn invoke<T: DeserializeOwned>(
+    webview: &WebviewWindow<MockRuntime>,
+    cmd: &str,
+    args: Value,
+) -> Result<T, Value> {
+    tauri::test::get_ipc_response(
+        webview,
+        InvokeRequest {
+            cmd: cmd.into(),
+            callback: CallbackFn(0),
+            error: CallbackFn(1),
+            url: "tauri://localhost".parse().unwrap(),
+            body: InvokeBody::Json(args),
+            headers: Default::default(),
+            invoke_key: INVOKE_KEY.to_string(),
+        },
+    )
+    .map(|body| body.deserialize::<T>().unwrap())
+}
+
+#[test]
+fn registers_commands() {
+    let (_app, webview) = mock_app();
+    let version: String = invoke(&webview, "get_version", json!({})).unwrap();
+    assert_eq!(version, env!("CARGO_PKG_VERSION"));
+}
+
+#[test]
+fn commands_use_managed_database() {
+    let (_app, webview) = mock_app();
+
+    // 기본 컬럼은 DB를 열 때 채워짐
+    let columns: Vec<Value> = invoke(&webview, "list_columns", json!({})).unwrap();
+    assert_eq!(columns[0]["name"], "견적ID");
+
+    let error = invoke::<Value>(&webview, "get_column", json!({ "id": 9999 })).unwrap_err();
+    assert_eq!(error["code"], "NOT_FOUND");
+}
+
+#[test]
+fn commands_resolve_managed_state() {
+    let (app, webview) = mock_app();
+
+    // 커맨드가 받은 State<Database>가 앱에 등록된 DB와 같은지 확인
+    let column: Value = invoke(
+        &webview,
+        "create_column",
+        json!({ "column": { "name": "납기", "dataType": "string", "defaultValue": null } }),
+    )
+    .unwrap();
+    let id = column["id"].as_i64().unwrap();
+    let stored = app.state::<Database>().get_column(id).unwrap();
+    assert_eq!(stored.name, "납기");
+
+    // 등록한 상태는 DB와 다운로드 레지스트리뿐
+    assert!(app.try_state::<DownloadRegistry>().is_some());
+    let cancelled: bool = invoke(&webview, "cancel_download", json!({ "id": "unknown" })).unwrap();
+    assert!(!cancelled);
+}